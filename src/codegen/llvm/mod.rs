@@ -5,29 +5,44 @@ use inkwell::{
     basic_block::BasicBlock,
     builder::Builder,
     context::Context,
-    module::Module as LlvmModule,
-    types::{BasicType as _, BasicTypeEnum},
-    values::{BasicValue, FunctionValue, IntValue, PointerValue},
-    IntPredicate,
+    module::{Linkage, Module as LlvmModule},
+    types::{BasicType, BasicTypeEnum},
+    values::{
+        AggregateValueEnum, BasicValue, BasicValueEnum, FloatValue, FunctionValue, PointerValue,
+    },
+    AddressSpace, FloatPredicate, IntPredicate,
 };
 
 use crate::{
-    compiler::Compiler,
+    compiler::{Compiler, TypeManager},
     repr::{
         identifier::{FunctionIdx, ScopedBinding},
         ir::{
-            BasicBlockIdx, BinaryOp, ConstantValue, Function, Terminator, Triple, TripleRef,
-            UnaryOp, Value,
+            BasicBlockIdx, BinaryOp, ConstantValue, Function, Panic, Projection, Terminator,
+            Triple, TripleRef, UnaryOp, Value,
         },
     },
     ty::Ty,
+    util::diagnostic::location,
 };
 
+/// Name of the generated routine which reports an out of bounds index.
+const BOUNDS_PANIC: &str = "lumina.panic.bounds";
+
+/// Code that the program exits with if it panics at runtime.
+const PANIC_EXIT_CODE: u64 = 101;
+
 /// A single LLVM module.
 pub struct Module<'compiler, 'ink> {
     /// Compiler state.
     compiler: &'compiler Compiler,
 
+    /// Name of the file that the source was read from.
+    file: &'compiler str,
+
+    /// Source that the module was compiled from, used to report locations at runtime.
+    source: &'compiler str,
+
     /// LLVM context.
     llvm_ctx: &'ink Context,
 
@@ -39,8 +54,14 @@ pub struct Module<'compiler, 'ink> {
 }
 
 impl<'compiler, 'ink> Module<'compiler, 'ink> {
-    /// Create a new module from an existing [`Compiler`] and LLVM [`Context`].
-    pub fn new(compiler: &'compiler Compiler, llvm_ctx: &'ink Context) -> Self {
+    /// Create a new module from an existing [`Compiler`] and LLVM [`Context`], for the source
+    /// that was compiled from the provided file.
+    pub fn new(
+        compiler: &'compiler Compiler,
+        llvm_ctx: &'ink Context,
+        file: &'compiler str,
+        source: &'compiler str,
+    ) -> Self {
         let module = llvm_ctx.create_module("module");
 
         // TODO: Declare all functions in the compiler
@@ -63,14 +84,16 @@ impl<'compiler, 'ink> Module<'compiler, 'ink> {
                     {
                         let signature = registration.get_signature();
 
-                        llvm_ctx.get_ty(&signature.return_ty).fn_type(
-                            &signature
-                                .arguments
-                                .iter()
-                                .map(|ty| llvm_ctx.get_ty(ty).into())
-                                .collect::<Vec<_>>(),
-                            false,
-                        )
+                        llvm_ctx
+                            .get_ty(&compiler.types, &signature.return_ty)
+                            .fn_type(
+                                &signature
+                                    .arguments
+                                    .iter()
+                                    .map(|ty| llvm_ctx.get_ty(&compiler.types, ty).into())
+                                    .collect::<Vec<_>>(),
+                                false,
+                            )
                     },
                     None,
                 )
@@ -80,6 +103,8 @@ impl<'compiler, 'ink> Module<'compiler, 'ink> {
 
         Self {
             compiler,
+            file,
+            source,
             llvm_ctx,
             module,
             functions,
@@ -96,6 +121,77 @@ impl<'compiler, 'ink> Module<'compiler, 'ink> {
         value
     }
 
+    /// Get the LLVM representation for a [`Ty`].
+    fn get_ty(&self, ty: &Ty) -> BasicTypeEnum<'ink> {
+        self.llvm_ctx.get_ty(&self.compiler.types, ty)
+    }
+
+    /// Find the routine which reports an out of bounds index and exits the program, generating it
+    /// the first time it is needed. It accepts the index, the length of the array, and the
+    /// location of the index within the source as a string.
+    fn bounds_panic(&self) -> FunctionValue<'ink> {
+        if let Some(function) = self.module.get_function(BOUNDS_PANIC) {
+            return function;
+        }
+
+        let i64_ty = self.llvm_ctx.i64_type();
+        let i32_ty = self.llvm_ctx.i32_type();
+        let ptr_ty = self.llvm_ctx.i8_type().ptr_type(AddressSpace::default());
+
+        let function = self.module.add_function(
+            BOUNDS_PANIC,
+            self.llvm_ctx
+                .void_type()
+                .fn_type(&[i64_ty.into(), i64_ty.into(), ptr_ty.into()], false),
+            Some(Linkage::Internal),
+        );
+
+        // Report and exit using the C standard library
+        let dprintf = self.module.add_function(
+            "dprintf",
+            i32_ty.fn_type(&[i32_ty.into(), ptr_ty.into()], true),
+            Some(Linkage::External),
+        );
+        let exit = self.module.add_function(
+            "exit",
+            self.llvm_ctx.void_type().fn_type(&[i32_ty.into()], false),
+            Some(Linkage::External),
+        );
+
+        let builder = self.llvm_ctx.create_builder();
+        builder.position_at_end(self.llvm_ctx.append_basic_block(function, "entry"));
+
+        let message = builder
+            .build_global_string_ptr(
+                "panic: index out of bounds: the length is %lld but the index is %lld (at %s)\n",
+                "bounds_panic_message",
+            )
+            .unwrap();
+
+        let [index, length, location] =
+            [0, 1, 2].map(|i| function.get_nth_param(i).unwrap().into());
+        builder
+            .build_call(
+                dprintf,
+                &[
+                    // Standard error
+                    i32_ty.const_int(2, false).into(),
+                    message.as_pointer_value().into(),
+                    length,
+                    index,
+                    location,
+                ],
+                "report",
+            )
+            .unwrap();
+        builder
+            .build_call(exit, &[i32_ty.const_int(PANIC_EXIT_CODE, false).into()], "")
+            .unwrap();
+        builder.build_unreachable().unwrap();
+
+        function
+    }
+
     /// Produce the inner LLVM module.
     pub fn into_inner(self) -> LlvmModule<'ink> {
         self.module
@@ -104,19 +200,61 @@ impl<'compiler, 'ink> Module<'compiler, 'ink> {
 
 /// Utility methods for LLVM context.
 trait ContextExt {
-    /// Get the internal representation for a [`Ty`].
-    fn get_ty(&self, ty: &Ty) -> BasicTypeEnum;
+    /// Get the internal representation for a [`Ty`], resolving any user-defined types from the
+    /// provided [`TypeManager`].
+    fn get_ty(&self, types: &TypeManager, ty: &Ty) -> BasicTypeEnum;
 }
 
 impl ContextExt for Context {
-    fn get_ty(&self, ty: &Ty) -> BasicTypeEnum {
+    fn get_ty(&self, types: &TypeManager, ty: &Ty) -> BasicTypeEnum {
         match ty {
-            Ty::Int => self.i64_type().into(),
-            Ty::Uint => self.i64_type().into(),
+            Ty::Int | Ty::Uint => self.i64_type().into(),
+            Ty::I8 | Ty::U8 => self.i8_type().into(),
+            Ty::I16 | Ty::U16 => self.i16_type().into(),
+            Ty::I32 | Ty::U32 => self.i32_type().into(),
+            Ty::F32 => self.f32_type().into(),
+            Ty::F64 => self.f64_type().into(),
             Ty::Boolean => self.bool_type().into(),
             Ty::Unit => todo!(),
             Ty::Never => todo!(),
-            Ty::Array { inner, size } => self.get_ty(inner).array_type(*size).into(),
+            Ty::Array { inner, size } => self.get_ty(types, inner).array_type(*size).into(),
+            Ty::Struct(idx) => self
+                .struct_type(
+                    &types
+                        .get(*idx)
+                        .expect("struct must be registered")
+                        .fields()
+                        .iter()
+                        .map(|(_, ty)| self.get_ty(types, ty))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .into(),
+            Ty::Tuple(tys) => self
+                .struct_type(
+                    &tys.iter()
+                        .map(|ty| self.get_ty(types, ty))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .into(),
+            // The discriminant is followed by the payload of every variant
+            Ty::Enum(idx) => self
+                .struct_type(
+                    &std::iter::once(self.i64_type().into())
+                        .chain(
+                            types
+                                .get_enum(*idx)
+                                .expect("enum must be registered")
+                                .variants()
+                                .iter()
+                                .flat_map(|(_, payload)| payload)
+                                .map(|ty| self.get_ty(types, ty)),
+                        )
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .into(),
         }
     }
 }
@@ -131,9 +269,7 @@ pub struct FunctionGenerator<'module, 'compiler, 'ink> {
     builder: Builder<'ink>,
 
     /// Resulting values for each of the triples
-    results: HashMap<TripleRef, Option<IntValue<'ink>>>,
-    /// WARN: Hack until pointers are better represented
-    pointers: HashMap<TripleRef, PointerValue<'ink>>,
+    results: HashMap<TripleRef, Option<BasicValueEnum<'ink>>>,
     bindings: HashMap<ScopedBinding, PointerValue<'ink>>,
 
     blocks: HashMap<BasicBlockIdx, BasicBlock<'ink>>,
@@ -163,7 +299,6 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
             builder,
             function,
             bindings: HashMap::new(),
-            pointers: HashMap::new(),
             results: HashMap::new(),
             blocks: HashMap::new(),
         }
@@ -177,57 +312,62 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
             .get(self.function.identifier)
             .unwrap();
 
-        // Create stack allocations for all of the variables in scope
+        // Create stack allocations for all of the variables in scope, except unit values which
+        // are never stored
         self.bindings = self
             .function
             .scope
             .iter()
-            .map(|binding| {
+            .filter_map(|binding| {
                 let (symbol, ty) = registration.get_binding(*binding).unwrap();
 
-                (
+                if matches!(ty, Ty::Unit) {
+                    return None;
+                }
+
+                Some((
                     *binding,
-                    self.alloca(ty, self.module.compiler.symbols.resolve(symbol).unwrap()),
-                )
+                    self.alloca(
+                        self.module.get_ty(&ty),
+                        self.module.compiler.symbols.resolve(symbol).unwrap(),
+                    ),
+                ))
             })
             .collect::<HashMap<_, _>>();
 
-        let user_entry = self.gen_block(
-            &self
-                .function
-                .basic_blocks
-                .iter_enumerated()
-                .next()
-                .unwrap()
-                .0,
-        );
+        // Blocks must be generated after every block that dominates them, so that any values they
+        // use (including those flowing into phis) have already been generated
+        let order = self.function.reverse_postorder();
+        for block_idx in &order {
+            let basic_block = self
+                .module
+                .llvm_ctx
+                .append_basic_block(self.llvm_function, &format!("bb_{block_idx:?}"));
+            self.blocks.insert(*block_idx, basic_block);
+        }
+
+        let user_entry = self.get_block(order.first().expect("function must have an entry"));
 
         self.builder
             .position_at_end(self.llvm_function.get_first_basic_block().unwrap());
         self.builder.build_unconditional_branch(user_entry).unwrap();
-    }
 
-    fn gen_block(&mut self, block_idx: &BasicBlockIdx) -> BasicBlock<'ink> {
-        if let Some(block) = self.blocks.get(block_idx) {
-            return *block;
+        for block_idx in &order {
+            self.gen_block(block_idx);
         }
+    }
 
-        let prev_builder = self.builder.get_insert_block();
-
-        let basic_block = self
-            .module
-            .llvm_ctx
-            .append_basic_block(self.llvm_function, &format!("bb_{block_idx:?}"));
-        self.blocks.insert(*block_idx, basic_block);
-        self.builder.position_at_end(basic_block);
+    fn gen_block(&mut self, block_idx: &BasicBlockIdx) {
+        self.builder.position_at_end(self.get_block(block_idx));
 
         let block = self.function.basic_blocks.get(*block_idx).unwrap().clone();
 
         for (idx, triple) in block.triples.iter_enumerated() {
             let result = match triple {
-                Triple::BinaryOp { lhs, rhs, op } => Some(self.gen_op_binary(lhs, rhs, op)),
+                Triple::BinaryOp { lhs, rhs, op, ty } => Some(self.gen_op_binary(lhs, rhs, op, ty)),
                 Triple::UnaryOp { rhs, op } => Some(self.gen_op_unary(rhs, op)),
                 Triple::Copy(value) => Some(self.gen_copy(value)),
+                Triple::Cast { value, from, to } => Some(self.gen_cast(value, from, to)),
                 Triple::Call(function, params) => Some(self.gen_call(function, params)),
                 Triple::Assign(symbol, value) => {
                     self.gen_assign(symbol, value);
@@ -235,21 +375,23 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
                 }
                 Triple::Load(binding) => Some(self.gen_load(binding)),
                 Triple::Phi(values) => Some(self.gen_phi(values)),
-                Triple::Index { value, index } => Some(self.gen_index(*value, *index)),
-                Triple::SetIndex {
-                    array_ptr,
-                    index,
+                Triple::Aggregate { ty, values } => Some(self.gen_aggregate(ty, values)),
+                Triple::Variant {
+                    ty,
+                    variant,
+                    values,
+                } => Some(self.gen_variant(ty, *variant, values)),
+                Triple::Extract { value, index } => Some(self.gen_extract(value, *index)),
+                Triple::AssignPlace {
+                    binding,
+                    path,
                     value,
                 } => {
-                    self.gen_set_index(*array_ptr, *index, *value);
-                    None
-                }
-                Triple::AllocArray(size) => {
-                    let ptr = self.gen_alloc_array(*size);
-                    self.pointers.insert(TripleRef::new(*block_idx, idx), ptr);
-
+                    self.gen_assign_place(binding, path, value);
                     None
                 }
+                Triple::LoadPlace { binding, path } => Some(self.gen_load_place(binding, path)),
+                Triple::Index { value, index } => Some(self.gen_index(value, index)),
             };
             self.results.insert(TripleRef::new(*block_idx, idx), result);
         }
@@ -263,16 +405,28 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
                 default,
                 branches,
             } => self.gen_switch(value, default, branches),
+            Terminator::Unreachable => {
+                self.builder.build_unreachable().unwrap();
+            }
+            Terminator::Panic(panic) => self.gen_panic(panic),
         }
+    }
 
-        if let Some(prev) = prev_builder {
-            self.builder.position_at_end(prev);
-        }
-
-        basic_block
+    /// Find the LLVM basic block which was created for a basic block.
+    fn get_block(&self, block_idx: &BasicBlockIdx) -> BasicBlock<'ink> {
+        *self
+            .blocks
+            .get(block_idx)
+            .expect("basic block must be reachable")
     }
 
-    fn gen_op_binary(&mut self, lhs: &Value, rhs: &Value, op: &BinaryOp) -> IntValue<'ink> {
+    fn gen_op_binary(
+        &mut self,
+        lhs: &Value,
+        rhs: &Value,
+        op: &BinaryOp,
+        ty: &Ty,
+    ) -> BasicValueEnum<'ink> {
         let lhs = self
             .retrieve_value(lhs)
             .expect("lhs of binary op cannot be unit");
@@ -280,67 +434,239 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
             .retrieve_value(rhs)
             .expect("rhs of binary cannot be unit");
 
-        match op {
+        if ty.is_float() {
+            return self.gen_op_binary_float(lhs.into_float_value(), rhs.into_float_value(), op);
+        }
+
+        let (lhs, rhs) = (lhs.into_int_value(), rhs.into_int_value());
+        let signed = ty.is_signed();
+
+        // Select the comparison predicate based on the signedness of the operands
+        let compare = |signed_predicate, unsigned_predicate, name| {
+            self.builder
+                .build_int_compare(
+                    if signed {
+                        signed_predicate
+                    } else {
+                        unsigned_predicate
+                    },
+                    lhs,
+                    rhs,
+                    name,
+                )
+                .unwrap()
+        };
+
+        // Shifting by the bit width or more produces poison, so the amount wraps around within it
+        let shift_amount = || {
+            let mask = lhs
+                .get_type()
+                .const_int(u64::from(lhs.get_type().get_bit_width() - 1), false);
+
+            self.builder.build_and(rhs, mask, "shift_amount").unwrap()
+        };
+
+        let result = match op {
             BinaryOp::Add => self.builder.build_int_add(lhs, rhs, "add_result").unwrap(),
             BinaryOp::Sub => self.builder.build_int_sub(lhs, rhs, "sub_result").unwrap(),
             BinaryOp::Multiply => self.builder.build_int_mul(lhs, rhs, "mul_result").unwrap(),
-            BinaryOp::Divide => self
+            BinaryOp::Divide if signed => self
                 .builder
                 .build_int_signed_div(lhs, rhs, "div_result")
                 .unwrap(),
-            BinaryOp::Eq => self
+            BinaryOp::Divide => self
                 .builder
-                .build_int_compare(IntPredicate::EQ, lhs, rhs, "eq_result")
+                .build_int_unsigned_div(lhs, rhs, "div_result")
                 .unwrap(),
-            BinaryOp::NotEq => self
+            BinaryOp::Modulo if signed => self
                 .builder
-                .build_int_compare(IntPredicate::NE, lhs, rhs, "not_eq_result")
+                .build_int_signed_rem(lhs, rhs, "mod_result")
                 .unwrap(),
-            BinaryOp::And => self.builder.build_and(lhs, rhs, "and_result").unwrap(),
-            BinaryOp::Or => self.builder.build_or(lhs, rhs, "or_result").unwrap(),
-            BinaryOp::Greater => self
+            BinaryOp::Modulo => self
                 .builder
-                .build_int_compare(IntPredicate::SGT, lhs, rhs, "greater_result")
+                .build_int_unsigned_rem(lhs, rhs, "mod_result")
                 .unwrap(),
-            BinaryOp::Less => self
+            BinaryOp::BitAnd => self.builder.build_and(lhs, rhs, "bit_and_result").unwrap(),
+            BinaryOp::BitOr => self.builder.build_or(lhs, rhs, "bit_or_result").unwrap(),
+            BinaryOp::BitXor => self.builder.build_xor(lhs, rhs, "bit_xor_result").unwrap(),
+            BinaryOp::ShiftLeft => self
                 .builder
-                .build_int_compare(IntPredicate::SLT, lhs, rhs, "less_result")
+                .build_left_shift(lhs, shift_amount(), "shift_left_result")
                 .unwrap(),
-            BinaryOp::GreaterEq => self
+            // Signed values use an arithmetic shift to preserve the sign
+            BinaryOp::ShiftRight => self
                 .builder
-                .build_int_compare(IntPredicate::SGE, lhs, rhs, "greater_eq_result")
+                .build_right_shift(lhs, shift_amount(), signed, "shift_right_result")
                 .unwrap(),
-            BinaryOp::LessEq => self
+            BinaryOp::Eq => compare(IntPredicate::EQ, IntPredicate::EQ, "eq_result"),
+            BinaryOp::NotEq => compare(IntPredicate::NE, IntPredicate::NE, "not_eq_result"),
+            BinaryOp::And => self.builder.build_and(lhs, rhs, "and_result").unwrap(),
+            BinaryOp::Or => self.builder.build_or(lhs, rhs, "or_result").unwrap(),
+            BinaryOp::Greater => compare(IntPredicate::SGT, IntPredicate::UGT, "greater_result"),
+            BinaryOp::Less => compare(IntPredicate::SLT, IntPredicate::ULT, "less_result"),
+            BinaryOp::GreaterEq => {
+                compare(IntPredicate::SGE, IntPredicate::UGE, "greater_eq_result")
+            }
+            BinaryOp::LessEq => compare(IntPredicate::SLE, IntPredicate::ULE, "less_eq_result"),
+        };
+
+        result.into()
+    }
+
+    fn gen_op_binary_float(
+        &mut self,
+        lhs: FloatValue<'ink>,
+        rhs: FloatValue<'ink>,
+        op: &BinaryOp,
+    ) -> BasicValueEnum<'ink> {
+        let compare = |predicate, name| -> BasicValueEnum<'ink> {
+            self.builder
+                .build_float_compare(predicate, lhs, rhs, name)
+                .unwrap()
+                .into()
+        };
+
+        match op {
+            BinaryOp::Add => self
                 .builder
-                .build_int_compare(IntPredicate::SLE, lhs, rhs, "less_eq_result")
-                .unwrap(),
+                .build_float_add(lhs, rhs, "add_result")
+                .unwrap()
+                .into(),
+            BinaryOp::Sub => self
+                .builder
+                .build_float_sub(lhs, rhs, "sub_result")
+                .unwrap()
+                .into(),
+            BinaryOp::Multiply => self
+                .builder
+                .build_float_mul(lhs, rhs, "mul_result")
+                .unwrap()
+                .into(),
+            BinaryOp::Divide => self
+                .builder
+                .build_float_div(lhs, rhs, "div_result")
+                .unwrap()
+                .into(),
+            BinaryOp::Modulo => self
+                .builder
+                .build_float_rem(lhs, rhs, "mod_result")
+                .unwrap()
+                .into(),
+            BinaryOp::Eq => compare(FloatPredicate::OEQ, "eq_result"),
+            BinaryOp::NotEq => compare(FloatPredicate::UNE, "not_eq_result"),
+            BinaryOp::Greater => compare(FloatPredicate::OGT, "greater_result"),
+            BinaryOp::Less => compare(FloatPredicate::OLT, "less_result"),
+            BinaryOp::GreaterEq => compare(FloatPredicate::OGE, "greater_eq_result"),
+            BinaryOp::LessEq => compare(FloatPredicate::OLE, "less_eq_result"),
+            BinaryOp::BitAnd
+            | BinaryOp::BitOr
+            | BinaryOp::BitXor
+            | BinaryOp::ShiftLeft
+            | BinaryOp::ShiftRight
+            | BinaryOp::And
+            | BinaryOp::Or => unreachable!("{op:?} cannot be applied to floats"),
         }
     }
 
-    fn gen_op_unary(&mut self, rhs: &Value, op: &UnaryOp) -> IntValue<'ink> {
+    fn gen_op_unary(&mut self, rhs: &Value, op: &UnaryOp) -> BasicValueEnum<'ink> {
         let rhs = self
             .retrieve_value(rhs)
             .expect("rhs of unary cannot be unit");
 
-        match op {
-            UnaryOp::Minus => self.builder.build_int_neg(rhs, "neg_result").unwrap(),
-            UnaryOp::Not => self.builder.build_not(rhs, "not_result").unwrap(),
+        match (op, rhs) {
+            (UnaryOp::Minus, BasicValueEnum::FloatValue(rhs)) => self
+                .builder
+                .build_float_neg(rhs, "neg_result")
+                .unwrap()
+                .into(),
+            (UnaryOp::Minus, rhs) => self
+                .builder
+                .build_int_neg(rhs.into_int_value(), "neg_result")
+                .unwrap()
+                .into(),
+            (UnaryOp::Not, rhs) => self
+                .builder
+                .build_not(rhs.into_int_value(), "not_result")
+                .unwrap()
+                .into(),
         }
     }
 
-    fn gen_copy(&mut self, value: &Value) -> IntValue<'ink> {
+    fn gen_copy(&mut self, value: &Value) -> BasicValueEnum<'ink> {
         self.retrieve_value(value).unwrap()
     }
 
-    fn gen_jump(&mut self, bb: &BasicBlockIdx) {
-        // Ensure the basic block is compiled
-        let bb = self.gen_block(bb);
+    fn gen_cast(&mut self, value: &Value, from: &Ty, to: &Ty) -> BasicValueEnum<'ink> {
+        let value = self.retrieve_value(value).expect("cannot cast unit value");
+        let target = self.module.get_ty(to);
 
-        // Insert the branch to it
-        self.builder.build_unconditional_branch(bb).unwrap();
+        match (from.is_float(), to.is_float()) {
+            // Sign of the source determines whether the value is sign or zero extended
+            (false, false) => self
+                .builder
+                .build_int_cast_sign_flag(
+                    value.into_int_value(),
+                    target.into_int_type(),
+                    from.is_signed(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+            (false, true) if from.is_signed() => self
+                .builder
+                .build_signed_int_to_float(
+                    value.into_int_value(),
+                    target.into_float_type(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+            (false, true) => self
+                .builder
+                .build_unsigned_int_to_float(
+                    value.into_int_value(),
+                    target.into_float_type(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+            (true, false) if to.is_signed() => self
+                .builder
+                .build_float_to_signed_int(
+                    value.into_float_value(),
+                    target.into_int_type(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+            (true, false) => self
+                .builder
+                .build_float_to_unsigned_int(
+                    value.into_float_value(),
+                    target.into_int_type(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+            (true, true) => self
+                .builder
+                .build_float_cast(
+                    value.into_float_value(),
+                    target.into_float_type(),
+                    "cast_result",
+                )
+                .unwrap()
+                .into(),
+        }
     }
 
-    fn gen_call(&mut self, function: &FunctionIdx, params: &[Value]) -> IntValue<'ink> {
+    fn gen_jump(&mut self, bb: &BasicBlockIdx) {
+        self.builder
+            .build_unconditional_branch(self.get_block(bb))
+            .unwrap();
+    }
+
+    fn gen_call(&mut self, function: &FunctionIdx, params: &[Value]) -> BasicValueEnum<'ink> {
         // Ensure the function is compiled
         let function_value = self.module.functions.get(*function).unwrap();
 
@@ -365,7 +691,6 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
             .unwrap()
             .try_as_basic_value()
             .unwrap_left()
-            .into_int_value()
     }
 
     fn gen_return(&self, value: &Value) {
@@ -377,22 +702,16 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
     }
 
     fn gen_assign(&mut self, ident: &ScopedBinding, value: &Value) {
-        if let Value::Pointer(ptr) = value {
-            // HACK: Override the existing binding for the provided pointer
-            self.bindings
-                .insert(*ident, *self.pointers.get(ptr).unwrap());
-        } else {
-            let value = self
-                .retrieve_value(value)
-                .expect("unit value cannot be assigned");
-            let ptr = self.bindings.get(ident).unwrap();
-
-            self.builder.build_store(*ptr, value).unwrap();
-        }
+        let value = self
+            .retrieve_value(value)
+            .expect("unit value cannot be assigned");
+        let ptr = self.bindings.get(ident).unwrap();
+
+        self.builder.build_store(*ptr, value).unwrap();
     }
 
-    fn gen_load(&self, binding: &ScopedBinding) -> IntValue<'ink> {
-        let (symbol, _) = self
+    fn gen_load(&self, binding: &ScopedBinding) -> BasicValueEnum<'ink> {
+        let (symbol, ty) = self
             .module
             .compiler
             .functions
@@ -405,9 +724,47 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
         let name = self.module.compiler.symbols.resolve(symbol).unwrap();
 
         self.builder
-            .build_load(self.module.llvm_ctx.i64_type(), *ptr, name)
+            .build_load(self.module.get_ty(&ty), *ptr, name)
             .unwrap()
-            .into_int_value()
+    }
+
+    fn gen_panic(&mut self, panic: &Panic) {
+        let i64_ty = self.module.llvm_ctx.i64_type();
+
+        match panic {
+            Panic::IndexOutOfBounds {
+                index,
+                length,
+                span,
+            } => {
+                let index = self.retrieve_value(index).unwrap();
+
+                // Resolve the location up front, so it can be reported as it appears to the user
+                let (line, column) = location(self.module.source, span.start);
+                let location = self
+                    .builder
+                    .build_global_string_ptr(
+                        &format!("{}:{line}:{column}", self.module.file),
+                        "bounds_panic_location",
+                    )
+                    .unwrap();
+
+                self.builder
+                    .build_call(
+                        self.module.bounds_panic(),
+                        &[
+                            index.into(),
+                            i64_ty.const_int(*length as u64, false).into(),
+                            location.as_pointer_value().into(),
+                        ],
+                        "",
+                    )
+                    .unwrap();
+            }
+        }
+
+        // The panic routine will never return
+        self.builder.build_unreachable().unwrap();
     }
 
     fn gen_switch(
@@ -423,20 +780,21 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
                 (
                     // Compile the case value
                     self.retrieve_value(case)
-                        .expect("cannot use unit value as switch case"),
-                    // Compile the destination basic block
-                    self.gen_block(bb),
+                        .expect("cannot use unit value as switch case")
+                        .into_int_value(),
+                    self.get_block(bb),
                 )
             })
             .collect::<Vec<_>>();
 
-        let else_block = self.gen_block(default);
+        let else_block = self.get_block(default);
 
         self.builder
             .build_switch(
                 // Build the value to switch on
                 self.retrieve_value(value)
-                    .expect("cannot switch on unit value"),
+                    .expect("cannot switch on unit value")
+                    .into_int_value(),
                 // Compile out the default branch
                 else_block,
                 &cases,
@@ -444,78 +802,219 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
             .unwrap();
     }
 
-    fn gen_phi(&mut self, values: &[(Value, BasicBlockIdx)]) -> IntValue<'ink> {
+    fn gen_phi(&mut self, values: &[(Value, BasicBlockIdx)]) -> BasicValueEnum<'ink> {
+        // Each of the incoming blocks has already been generated, so their values are available
+        let incoming = values
+            .iter()
+            .map(|(value, bb)| (self.retrieve_value(value).unwrap(), self.get_block(bb)))
+            .collect::<Vec<_>>();
+
+        // Type of the phi is determined by the values flowing into it
+        let ty = incoming
+            .first()
+            .map(|(value, _)| value.get_type())
+            .expect("phi must have incoming values");
+
+        let phi = self.builder.build_phi(ty, "switch phi").unwrap();
+        for (value, bb) in &incoming {
+            phi.add_incoming(&[(value, *bb)]);
+        }
+
+        phi.as_basic_value()
+    }
+
+    fn gen_aggregate(&mut self, ty: &Ty, values: &[Value]) -> BasicValueEnum<'ink> {
+        let aggregate: AggregateValueEnum = match self.module.get_ty(ty) {
+            BasicTypeEnum::StructType(ty) => ty.get_undef().into(),
+            BasicTypeEnum::ArrayType(ty) => ty.get_undef().into(),
+            ty => unreachable!("cannot build aggregate of {ty:?}"),
+        };
+
+        // Insert each value into an initially undefined aggregate
         values
             .iter()
-            .fold(
-                self.builder
-                    .build_phi(self.module.llvm_ctx.i64_type(), "switch phi")
-                    .unwrap(),
-                |phi, (value, bb)| {
-                    let bb = self.gen_block(bb);
-                    let value = self.retrieve_value(value).unwrap();
+            .enumerate()
+            .fold(aggregate, |aggregate, (i, value)| {
+                let value = self
+                    .retrieve_value(value)
+                    .expect("cannot insert unit value into aggregate");
 
-                    phi.add_incoming(&[(&value, bb)]);
+                self.builder
+                    .build_insert_value(aggregate, value, i as u32, "aggregate_insert")
+                    .unwrap()
+            })
+            .as_basic_value_enum()
+    }
 
-                    phi
-                },
+    fn gen_variant(&mut self, ty: &Ty, variant: u32, values: &[Value]) -> BasicValueEnum<'ink> {
+        let Ty::Enum(idx) = ty else {
+            unreachable!("variant must be of an enum type");
+        };
+        let offset = self
+            .module
+            .compiler
+            .types
+            .get_enum(*idx)
+            .expect("enum must be registered")
+            .payload_offset(variant as usize);
+
+        // Tag the value with the discriminant, leaving the payload of other variants undefined
+        let tagged = self
+            .builder
+            .build_insert_value(
+                self.module.get_ty(ty).into_struct_type().get_undef(),
+                self.module
+                    .llvm_ctx
+                    .i64_type()
+                    .const_int(variant as u64, false),
+                0,
+                "variant_discriminant",
             )
-            .as_basic_value()
-            .into_int_value()
-    }
+            .unwrap()
+            .into_struct_value();
 
-    fn gen_index(&mut self, value: ScopedBinding, index: Value) -> IntValue<'ink> {
-        let index = self.retrieve_value(&index).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .fold(tagged, |aggregate, (i, value)| {
+                let value = self
+                    .retrieve_value(value)
+                    .expect("cannot insert unit value into variant");
 
-        // WARN: BAD BAD BAD
-        let pointee_ty = self.module.llvm_ctx.i64_type();
+                self.builder
+                    .build_insert_value(aggregate, value, (offset + i) as u32, "variant_insert")
+                    .unwrap()
+                    .into_struct_value()
+            })
+            .into()
+    }
 
-        let array_ptr = self.bindings.get(&value).expect("symbol to be defined");
-        let item_ptr = unsafe {
-            self.builder
-                .build_gep(pointee_ty, *array_ptr, &[index], "gep_result")
-        }
-        .unwrap();
+    fn gen_extract(&mut self, value: &Value, index: u32) -> BasicValueEnum<'ink> {
+        let value = self
+            .retrieve_value(value)
+            .expect("cannot extract from unit value")
+            .into_struct_value();
 
         self.builder
-            .build_load(pointee_ty, item_ptr, "item_fetch")
+            .build_extract_value(value, index, "extract_result")
             .unwrap()
-            .into_int_value()
     }
 
-    fn gen_set_index(&mut self, array_ptr: TripleRef, index: Value, value: Value) {
-        let index = self.retrieve_value(&index).unwrap();
+    /// Produce a pointer to a location nested within a binding, along with the type of the value
+    /// stored there.
+    fn gen_place(
+        &mut self,
+        binding: &ScopedBinding,
+        path: &[Projection],
+    ) -> (PointerValue<'ink>, Ty) {
+        let (_, mut ty) = self
+            .module
+            .compiler
+            .functions
+            .get(self.function.identifier)
+            .unwrap()
+            .get_binding(*binding)
+            .unwrap();
 
-        // WARN: BAD BAD BAD
-        let pointee_ty = self.module.llvm_ctx.i64_type();
+        // Follow the path through each nested aggregate to find the target location
+        let mut ptr = *self.bindings.get(binding).expect("symbol must be defined");
+        for projection in path {
+            let llvm_ty = self.module.get_ty(&ty);
 
-        let array_ptr = self.pointers.get(&array_ptr).unwrap();
-        let item_ptr = unsafe {
-            self.builder
-                .build_gep(pointee_ty, *array_ptr, &[index], "gep_result")
+            (ptr, ty) = match (projection, &ty) {
+                (Projection::Field(index), Ty::Struct(idx)) => (
+                    self.builder
+                        .build_struct_gep(llvm_ty, ptr, *index, "field_ptr")
+                        .unwrap(),
+                    self.module
+                        .compiler
+                        .types
+                        .get(*idx)
+                        .expect("struct must be registered")
+                        .fields()[*index as usize]
+                        .1
+                        .clone(),
+                ),
+                (Projection::Field(index), Ty::Tuple(tys)) => (
+                    self.builder
+                        .build_struct_gep(llvm_ty, ptr, *index, "element_ptr")
+                        .unwrap(),
+                    tys[*index as usize].clone(),
+                ),
+                (Projection::Index(index), Ty::Array { inner, .. }) => {
+                    let index = self.retrieve_value(index).unwrap().into_int_value();
+
+                    (
+                        unsafe {
+                            self.builder.build_in_bounds_gep(
+                                llvm_ty,
+                                ptr,
+                                &[self.module.llvm_ctx.i64_type().const_zero(), index],
+                                "item_ptr",
+                            )
+                        }
+                        .unwrap(),
+                        *inner.clone(),
+                    )
+                }
+                (projection, ty) => unreachable!("cannot apply {projection:?} to {ty:?}"),
+            };
         }
-        .unwrap();
 
-        let value = self.retrieve_value(&value).unwrap();
+        (ptr, ty)
+    }
+
+    fn gen_assign_place(&mut self, binding: &ScopedBinding, path: &[Projection], value: &Value) {
+        let (ptr, _) = self.gen_place(binding, path);
+
+        let value = self
+            .retrieve_value(value)
+            .expect("unit value cannot be assigned");
 
-        self.builder.build_store(item_ptr, value).unwrap();
+        self.builder.build_store(ptr, value).unwrap();
     }
 
-    fn gen_alloc_array(&mut self, size: u32) -> PointerValue<'ink> {
+    fn gen_load_place(
+        &mut self,
+        binding: &ScopedBinding,
+        path: &[Projection],
+    ) -> BasicValueEnum<'ink> {
+        let (ptr, ty) = self.gen_place(binding, path);
+
         self.builder
-            .build_array_alloca(
-                self.module.llvm_ctx.i64_type(),
-                self.module
-                    .llvm_ctx
-                    .i64_type()
-                    .const_int(size as u64, false),
-                "array_alloca",
+            .build_load(self.module.get_ty(&ty), ptr, "place_fetch")
+            .unwrap()
+    }
+
+    fn gen_index(&mut self, value: &Value, index: &Value) -> BasicValueEnum<'ink> {
+        let array = self
+            .retrieve_value(value)
+            .expect("cannot index unit value")
+            .into_array_value();
+        let index = self.retrieve_value(index).unwrap().into_int_value();
+
+        // Spill the array to the stack, so that it can be addressed with a dynamic index
+        let array_ty = array.get_type();
+        let array_ptr = self.alloca(array_ty, "index_array");
+        self.builder.build_store(array_ptr, array).unwrap();
+
+        let item_ptr = unsafe {
+            self.builder.build_in_bounds_gep(
+                array_ty,
+                array_ptr,
+                &[self.module.llvm_ctx.i64_type().const_zero(), index],
+                "gep_result",
             )
+        }
+        .unwrap();
+
+        self.builder
+            .build_load(array_ty.get_element_type(), item_ptr, "item_fetch")
             .unwrap()
     }
 
     /// Emit an allocation instruction in the entry basic block
-    fn alloca(&self, ty: Ty, name: &str) -> PointerValue<'ink> {
+    fn alloca(&self, ty: impl BasicType<'ink>, name: &str) -> PointerValue<'ink> {
         // Find the entry for this function
         let entry = self.llvm_function.get_first_basic_block().unwrap();
 
@@ -523,43 +1022,38 @@ impl<'module, 'compiler, 'ink> FunctionGenerator<'module, 'compiler, 'ink> {
         let builder = self.module.llvm_ctx.create_builder();
         builder.position_at_end(entry);
 
-        builder
-            .build_alloca(self.module.llvm_ctx.get_ty(&ty), name)
-            .unwrap()
+        builder.build_alloca(ty, name).unwrap()
     }
 
-    fn retrieve_value(&self, value: &Value) -> Option<IntValue<'ink>> {
+    fn retrieve_value(&self, value: &Value) -> Option<BasicValueEnum<'ink>> {
         match value {
-            Value::Constant(value) => Some(match value {
-                ConstantValue::Integer(value) => self
-                    .module
-                    .llvm_ctx
-                    .i64_type()
-                    .const_int(*value as u64, false),
-                ConstantValue::Boolean(value) => {
-                    let ty = self.module.llvm_ctx.bool_type();
-
-                    if *value {
-                        ty.const_all_ones()
-                    } else {
-                        ty.const_zero()
-                    }
-                }
-            }),
+            Value::Constant(value) => Some(self.gen_constant(value)),
             Value::Triple(triple) => Some(
                 self.results
                     .get(triple)
                     .expect("triple must exist")
                     .expect("triple must produce value"),
             ),
-            Value::Pointer(_) => None,
-            Value::Parameter(i) => Some(
-                self.llvm_function
-                    .get_nth_param(*i as u32)
-                    .unwrap()
-                    .into_int_value(),
-            ),
+            Value::Parameter(i) => Some(self.llvm_function.get_nth_param(*i as u32).unwrap()),
             Value::Unit => None,
         }
     }
+
+    fn gen_constant(&self, value: &ConstantValue) -> BasicValueEnum<'ink> {
+        let ctx = self.module.llvm_ctx;
+
+        match *value {
+            ConstantValue::Integer(value) => ctx.i64_type().const_int(value as u64, false).into(),
+            ConstantValue::I8(value) => ctx.i8_type().const_int(value as u64, false).into(),
+            ConstantValue::I16(value) => ctx.i16_type().const_int(value as u64, false).into(),
+            ConstantValue::I32(value) => ctx.i32_type().const_int(value as u64, false).into(),
+            ConstantValue::Uint(value) => ctx.i64_type().const_int(value, false).into(),
+            ConstantValue::U8(value) => ctx.i8_type().const_int(value as u64, false).into(),
+            ConstantValue::U16(value) => ctx.i16_type().const_int(value as u64, false).into(),
+            ConstantValue::U32(value) => ctx.i32_type().const_int(value as u64, false).into(),
+            ConstantValue::F32(value) => ctx.f32_type().const_float(value as f64).into(),
+            ConstantValue::F64(value) => ctx.f64_type().const_float(value).into(),
+            ConstantValue::Boolean(value) => ctx.bool_type().const_int(value as u64, false).into(),
+        }
+    }
 }
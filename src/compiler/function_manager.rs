@@ -1,5 +1,5 @@
 use crate::ty::Ty;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use index_vec::IndexVec;
 
@@ -63,6 +63,23 @@ impl FunctionManager {
     pub fn iter(&self) -> impl Iterator<Item = (FunctionIdx, &FunctionRegistration)> {
         self.registrations.iter_enumerated()
     }
+
+    /// Find every function which may be called when running the provided function, including
+    /// itself.
+    pub fn reachable(&self, idx: FunctionIdx) -> HashSet<FunctionIdx> {
+        let mut reachable = HashSet::from([idx]);
+        let mut pending = vec![idx];
+
+        while let Some(idx) = pending.pop() {
+            for callee in &self.registrations[idx].calls {
+                if reachable.insert(*callee) {
+                    pending.push(*callee);
+                }
+            }
+        }
+
+        reachable
+    }
 }
 
 /// compile process, including the signature, type information, and other representations.
@@ -73,6 +90,9 @@ pub struct FunctionRegistration {
 
     /// Information for every binding within the function, containing symbol and type information.
     bindings: HashMap<ScopedBinding, (Symbol, Ty)>,
+
+    /// Functions which are called from within this function.
+    calls: HashSet<FunctionIdx>,
 }
 
 impl FunctionRegistration {
@@ -81,6 +101,7 @@ impl FunctionRegistration {
         Self {
             signature,
             bindings: HashMap::new(),
+            calls: HashSet::new(),
         }
     }
 
@@ -98,6 +119,11 @@ impl FunctionRegistration {
         self.bindings.get(&scoped_binding).cloned()
     }
 
+    /// Record that the provided function is called from within this function.
+    pub fn add_call(&mut self, callee: FunctionIdx) {
+        self.calls.insert(callee);
+    }
+
     /// Get a reference to the signature.
     pub fn get_signature(&self) -> &FunctionSignature {
         &self.signature
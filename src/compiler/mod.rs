@@ -1,14 +1,19 @@
 mod function_manager;
+mod type_manager;
+mod warning;
 
+use itertools::Itertools;
 use string_interner::{DefaultBackend, DefaultSymbol, StringInterner};
 
 use self::function_manager::*;
+pub use self::{type_manager::*, warning::Warning};
 
 use crate::{
     hir::SolveType,
     repr::ir,
-    stage::{self, parse::ParseError},
+    stage::{self, lower_ir::LowerError, parse::ParseError},
     ty::TyError,
+    util::diagnostic::Diagnostic,
 };
 
 /// A symbol represents an interned string.
@@ -16,11 +21,25 @@ pub type Symbol = DefaultSymbol;
 
 #[derive(Debug, thiserror::Error)]
 pub enum CompilerError {
-    #[error(transparent)]
-    Parse(#[from] ParseError),
+    #[error("{}", .0.iter().join("\n"))]
+    Parse(Vec<ParseError>),
 
     #[error(transparent)]
     Ty(#[from] TyError),
+
+    #[error(transparent)]
+    Lower(#[from] LowerError),
+}
+
+impl CompilerError {
+    /// Produce a diagnostic for each of the errors, which can be rendered for the user.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            CompilerError::Parse(errors) => errors.iter().map(Diagnostic::from).collect(),
+            CompilerError::Ty(e) => vec![Diagnostic::from(e)],
+            CompilerError::Lower(e) => vec![Diagnostic::from(e)],
+        }
+    }
 }
 
 /// Contains all of the state required for a compiler pass.
@@ -31,19 +50,59 @@ pub struct Compiler {
 
     /// All functions that have been registered with the compiler.
     pub functions: FunctionManager,
+
+    /// All user-defined types that have been registered with the compiler.
+    pub types: TypeManager,
+
+    /// Settings which alter the code that is produced.
+    pub options: CompilerOptions,
+
+    /// Potential problems found whilst compiling, which didn't prevent compilation.
+    pub warnings: Vec<Warning>,
+}
+
+/// Settings which alter the code produced by the compiler.
+#[derive(Debug, Clone)]
+pub struct CompilerOptions {
+    /// Whether array indexes are checked against the length of the array at runtime.
+    pub bounds_checks: bool,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            bounds_checks: true,
+        }
+    }
 }
 
 impl Compiler {
+    /// Report a potential problem, without stopping compilation.
+    pub fn warn(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+
+    /// Determine whether a name has opted out of being warned about when unused, either because
+    /// it starts with an underscore or was generated by the compiler.
+    pub fn is_silenced(&self, symbol: Symbol) -> bool {
+        self.symbols
+            .resolve(symbol)
+            .is_some_and(|name| name.starts_with('_') || name.starts_with('#'))
+    }
+
     /// From the provided source, compile and return the IR of each of the functions.
     pub fn compile(&mut self, source: impl AsRef<str>) -> Result<Vec<ir::Function>, CompilerError> {
-        // Parse the source
-        let program = stage::parse::parse(self, source.as_ref())?;
+        // Parse the source, only continuing if it was error free
+        let program = match stage::parse::parse(self, source.as_ref()) {
+            (Some(program), errors) if errors.is_empty() => program,
+            (_, errors) => return Err(CompilerError::Parse(errors)),
+        };
 
         // Perform type checking
         let program = program.solve(self, &mut ())?;
 
         // Lower into IR
-        let ir = stage::lower_ir::lower(self, program);
+        let ir = stage::lower_ir::lower(self, program)?;
 
         Ok(ir)
     }
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use index_vec::IndexVec;
+
+use crate::{
+    repr::identifier::{EnumIdx, StructIdx},
+    ty::Ty,
+    util::span::Span,
+};
+
+use super::Symbol;
+
+/// Handles all of the user-defined types.
+#[derive(Default, Debug, Clone)]
+pub struct TypeManager {
+    /// Struct registrations, stored against a unique index.
+    structs: IndexVec<StructIdx, StructRegistration>,
+
+    /// Map to resolve a struct's symbol into it's index.
+    symbols: HashMap<Symbol, StructIdx>,
+
+    /// Enum registrations, stored against a unique index.
+    enums: IndexVec<EnumIdx, EnumRegistration>,
+
+    /// Map to resolve an enum's symbol into it's index.
+    enum_symbols: HashMap<Symbol, EnumIdx>,
+}
+
+impl TypeManager {
+    /// Declare a struct with the provided symbol, so that it can be referred to before its fields
+    /// are known. Declaring the same symbol multiple times will produce the same index.
+    pub fn declare(&mut self, symbol: Symbol) -> StructIdx {
+        if let Some(idx) = self.get_idx(symbol) {
+            return idx;
+        }
+
+        let idx = self.structs.push(StructRegistration::new(symbol));
+        self.symbols.insert(symbol, idx);
+
+        idx
+    }
+
+    /// Define the fields of a declared struct, named at the provided span. Will return `false` if
+    /// the struct has already been defined.
+    pub fn define(&mut self, idx: StructIdx, fields: Vec<(Symbol, Ty)>, span: Span) -> bool {
+        let registration = &mut self.structs[idx];
+
+        if registration.fields.is_some() {
+            return false;
+        }
+
+        registration.fields = Some(fields);
+        registration.span = span;
+
+        true
+    }
+
+    /// Attempt to get the index associated with a symbol.
+    pub fn get_idx(&self, symbol: Symbol) -> Option<StructIdx> {
+        self.symbols.get(&symbol).cloned()
+    }
+
+    /// Get the registration associated with a [`StructIdx`].
+    pub fn get(&self, idx: StructIdx) -> Option<&StructRegistration> {
+        self.structs.get(idx)
+    }
+
+    /// Declare an enum with the provided symbol, so that it can be referred to before its variants
+    /// are known. Declaring the same symbol multiple times will produce the same index.
+    pub fn declare_enum(&mut self, symbol: Symbol) -> EnumIdx {
+        if let Some(idx) = self.get_enum_idx(symbol) {
+            return idx;
+        }
+
+        let idx = self.enums.push(EnumRegistration::new(symbol));
+        self.enum_symbols.insert(symbol, idx);
+
+        idx
+    }
+
+    /// Define the variants of a declared enum, named at the provided span. Will return `false` if
+    /// the enum has already been defined.
+    pub fn define_enum(
+        &mut self,
+        idx: EnumIdx,
+        variants: Vec<(Symbol, Vec<Ty>)>,
+        span: Span,
+    ) -> bool {
+        let registration = &mut self.enums[idx];
+
+        if registration.variants.is_some() {
+            return false;
+        }
+
+        registration.variants = Some(variants);
+        registration.span = span;
+
+        true
+    }
+
+    /// Attempt to get the enum index associated with a symbol.
+    pub fn get_enum_idx(&self, symbol: Symbol) -> Option<EnumIdx> {
+        self.enum_symbols.get(&symbol).cloned()
+    }
+
+    /// Get the registration associated with an [`EnumIdx`].
+    pub fn get_enum(&self, idx: EnumIdx) -> Option<&EnumRegistration> {
+        self.enums.get(idx)
+    }
+
+    /// Find the position and type of a field on the provided type, if it is a struct with the
+    /// field.
+    pub fn resolve_field(&self, ty: &Ty, field: Symbol) -> Option<(usize, &Ty)> {
+        let Ty::Struct(idx) = ty else {
+            return None;
+        };
+
+        self.get(*idx)?.field(field)
+    }
+
+    /// Find the first user-defined type which contains itself (possibly through other types), as
+    /// it could only be represented with an infinite size. Produces the name and location of the
+    /// type.
+    pub fn find_infinite(&self) -> Option<(Symbol, &Span)> {
+        self.structs
+            .iter_enumerated()
+            .map(|(idx, registration)| (Ty::Struct(idx), registration.symbol, &registration.span))
+            .chain(self.enums.iter_enumerated().map(|(idx, registration)| {
+                (Ty::Enum(idx), registration.symbol, &registration.span)
+            }))
+            .find(|(ty, _, _)| self.contains(ty, ty, &mut HashSet::new()))
+            .map(|(_, symbol, span)| (symbol, span))
+    }
+
+    /// Whether a value of `ty` stores a value of `target` somewhere within it.
+    fn contains(&self, ty: &Ty, target: &Ty, visited: &mut HashSet<Ty>) -> bool {
+        self.stored_tys(ty).into_iter().any(|stored| {
+            stored == target
+                || (visited.insert(stored.clone()) && self.contains(stored, target, visited))
+        })
+    }
+
+    /// Each of the types that are stored directly within a value of `ty`.
+    fn stored_tys<'a>(&'a self, ty: &'a Ty) -> Vec<&'a Ty> {
+        match ty {
+            Ty::Struct(idx) => self.structs[*idx]
+                .fields()
+                .iter()
+                .map(|(_, ty)| ty)
+                .collect(),
+            Ty::Enum(idx) => self.enums[*idx]
+                .variants()
+                .iter()
+                .flat_map(|(_, payload)| payload)
+                .collect(),
+            Ty::Tuple(tys) => tys.iter().collect(),
+            Ty::Array { inner, .. } => vec![inner],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Information for a struct, including its name and the type of each of its fields.
+#[derive(Debug, Clone)]
+pub struct StructRegistration {
+    /// Name of the struct.
+    symbol: Symbol,
+
+    /// Name and type of each field, in declaration order. Will only be present once the struct has
+    /// been defined.
+    fields: Option<Vec<(Symbol, Ty)>>,
+
+    /// Location of the name within the definition of the struct.
+    span: Span,
+}
+
+impl StructRegistration {
+    /// Create a new registration for a struct which is yet to be defined.
+    fn new(symbol: Symbol) -> Self {
+        Self {
+            symbol,
+            fields: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Get the name of the struct.
+    pub fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    /// Get the name and type of each field, in declaration order.
+    pub fn fields(&self) -> &[(Symbol, Ty)] {
+        self.fields.as_deref().unwrap_or_default()
+    }
+
+    /// Find the position and type of a field.
+    pub fn field(&self, symbol: Symbol) -> Option<(usize, &Ty)> {
+        self.fields()
+            .iter()
+            .enumerate()
+            .find(|(_, (test_symbol, _))| *test_symbol == symbol)
+            .map(|(i, (_, ty))| (i, ty))
+    }
+}
+
+/// Information for an enum, including its name and the payload of each of its variants.
+#[derive(Debug, Clone)]
+pub struct EnumRegistration {
+    /// Name of the enum.
+    symbol: Symbol,
+
+    /// Name and payload types of each variant, in declaration order. Will only be present once the
+    /// enum has been defined.
+    variants: Option<Vec<(Symbol, Vec<Ty>)>>,
+
+    /// Location of the name within the definition of the enum.
+    span: Span,
+}
+
+impl EnumRegistration {
+    /// Create a new registration for an enum which is yet to be defined.
+    fn new(symbol: Symbol) -> Self {
+        Self {
+            symbol,
+            variants: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Get the name of the enum.
+    pub fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    /// Get the name and payload types of each variant, in declaration order.
+    pub fn variants(&self) -> &[(Symbol, Vec<Ty>)] {
+        self.variants.as_deref().unwrap_or_default()
+    }
+
+    /// Find the discriminant and payload types of a variant.
+    pub fn variant(&self, symbol: Symbol) -> Option<(usize, &[Ty])> {
+        self.variants()
+            .iter()
+            .enumerate()
+            .find(|(_, (test_symbol, _))| *test_symbol == symbol)
+            .map(|(i, (_, payload))| (i, payload.as_slice()))
+    }
+
+    /// Position of the first payload value of a variant within the representation of the enum.
+    /// The discriminant is stored first, followed by the payload of each variant in order.
+    pub fn payload_offset(&self, variant: usize) -> usize {
+        1 + self.variants()[..variant]
+            .iter()
+            .map(|(_, payload)| payload.len())
+            .sum::<usize>()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::util::{diagnostic::Diagnostic, span::Span};
+
+/// A potential problem in the program which doesn't prevent it from being compiled. Anything that
+/// is unused can be silenced by prefixing its name with an underscore.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Warning {
+    #[error("unused binding `{name}`")]
+    UnusedBinding { name: String, span: Span },
+
+    #[error("unused parameter `{name}`")]
+    UnusedParameter { name: String, span: Span },
+
+    #[error("function `{name}` is never called")]
+    UnusedFunction { name: String, span: Span },
+
+    #[error("unreachable statement")]
+    UnreachableCode {
+        span: Span,
+        /// Location of the statement which prevents the code from being reached.
+        diverging_span: Span,
+    },
+
+    #[error("unused result of type {ty}")]
+    UnusedResult { ty: String, span: Span },
+}
+
+impl Warning {
+    /// The location in the source that this warning originated from.
+    pub fn span(&self) -> &Span {
+        match self {
+            Warning::UnusedBinding { span, .. }
+            | Warning::UnusedParameter { span, .. }
+            | Warning::UnusedFunction { span, .. }
+            | Warning::UnreachableCode { span, .. }
+            | Warning::UnusedResult { span, .. } => span,
+        }
+    }
+}
+
+impl From<&Warning> for Diagnostic {
+    fn from(warning: &Warning) -> Self {
+        let diagnostic = Diagnostic::warning(warning, warning.span().clone());
+
+        match warning {
+            Warning::UnusedBinding { name, span }
+            | Warning::UnusedParameter { name, span }
+            | Warning::UnusedFunction { name, span } => diagnostic.with_note(
+                format!("if this is intentional, prefix it with an underscore: `_{name}`"),
+                span.clone(),
+            ),
+            Warning::UnreachableCode { diverging_span, .. } => diagnostic.with_note(
+                "any code following this statement is unreachable",
+                diverging_span.clone(),
+            ),
+            Warning::UnusedResult { span, .. } => diagnostic.with_note(
+                "if this is intentional, bind it with `let _ = ...;`",
+                span.clone(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compiler::Compiler;
+    use rstest::*;
+
+    /// Compile the source, and produce the name of each warning that was reported.
+    fn warnings(source: &str) -> Vec<&'static str> {
+        let mut compiler = Compiler::default();
+        compiler.compile(source).unwrap();
+
+        compiler
+            .warnings
+            .iter()
+            .map(|warning| match warning {
+                Warning::UnusedBinding { .. } => "unused_binding",
+                Warning::UnusedParameter { .. } => "unused_parameter",
+                Warning::UnusedFunction { .. } => "unused_function",
+                Warning::UnreachableCode { .. } => "unreachable_code",
+                Warning::UnusedResult { .. } => "unused_result",
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::none("fn main() -> int { let a = 1; return a; }", &[])]
+    #[case::unused_binding("fn main() -> int { let a = 1; return 1; }", &["unused_binding"])]
+    #[case::silenced_binding("fn main() -> int { let _a = 1; return 1; }", &[])]
+    #[case::shadowed("fn main() -> int { let a = 1; let a = 2; return a; }", &["unused_binding"])]
+    #[case::only_assigned(
+        "fn main() -> int { let mut a = 1; a = 2; return 1; }",
+        &["unused_binding"]
+    )]
+    #[case::compound_assigned("fn main() -> int { let mut a = 1; a += 2; return 1; }", &[])]
+    #[case::destructured(
+        "fn main() -> int { let (a, _b) = (1, 2); return 1; }",
+        &["unused_binding"]
+    )]
+    #[case::loop_binding(
+        "fn main() -> int { for i in 0..3 { } return 1; }",
+        &["unused_binding"]
+    )]
+    #[case::unused_parameter(
+        "fn f(a: int) -> int { 1 } fn main() -> int { f(1) }",
+        &["unused_parameter"]
+    )]
+    #[case::silenced_parameter("fn f(_a: int) -> int { 1 } fn main() -> int { f(1) }", &[])]
+    #[case::pattern_parameter(
+        "fn f((a, b): (int, int)) -> int { a } fn main() -> int { f((1, 2)) }",
+        &["unused_binding"]
+    )]
+    #[case::unused_function(
+        "fn f() -> int { 1 } fn main() -> int { 1 }",
+        &["unused_function"]
+    )]
+    #[case::silenced_function("fn _f() -> int { 1 } fn main() -> int { 1 }", &[])]
+    #[case::recursive_function(
+        "fn f() -> int { f() } fn main() -> int { 1 }",
+        &["unused_function"]
+    )]
+    #[case::called_by_unused_function(
+        "fn f() -> int { g() } fn g() -> int { 1 } fn main() -> int { 1 }",
+        &["unused_function", "unused_function"]
+    )]
+    #[case::called_recursive_function(
+        "fn f(n: int) -> int { if n == 0 { 0 } else { f(n - 1) } } fn main() -> int { f(1) }",
+        &[]
+    )]
+    #[case::unreachable(
+        "fn main() -> int { return 1; return 2; }",
+        &["unreachable_code"]
+    )]
+    #[case::unreachable_after_if(
+        "fn main() -> int { if true { return 1; } else { return 2; } return 3; }",
+        &["unreachable_code"]
+    )]
+    #[case::unreachable_after_let(
+        "fn main() -> int { let _x = loop { return 1; }; return 2; }",
+        &["unreachable_code"]
+    )]
+    #[case::unused_result("fn main() -> int { 1 + 2; return 1; }", &["unused_result"])]
+    #[case::discarded_result("fn main() -> int { let _ = 1 + 2; return 1; }", &[])]
+    fn warning(#[case] source: &str, #[case] expected: &[&str]) {
+        assert_eq!(warnings(source), expected);
+    }
+
+    #[test]
+    fn unused_result_names_type() {
+        let mut compiler = Compiler::default();
+        compiler
+            .compile("struct Point { x: int } fn main() -> int { Point { x: 1 }; 1 }")
+            .unwrap();
+
+        assert!(matches!(
+            &compiler.warnings[..],
+            [Warning::UnusedResult { ty, .. }] if ty == "Point"
+        ));
+    }
+
+    #[rstest]
+    #[case::binding("fn main() -> int { let a = 1; return 1; }")]
+    #[case::destructured("fn main() -> int { let (_b, a) = (1, 2); return 1; }")]
+    #[case::loop_binding("fn main() -> int { for a in 0..3 { } return 1; }")]
+    #[case::pattern_parameter(
+        "fn f((_b, a): (int, int)) -> int { 1 } fn main() -> int { f((1, 2)) }"
+    )]
+    fn unused_binding_span(#[case] source: &str) {
+        let mut compiler = Compiler::default();
+        compiler.compile(source).unwrap();
+
+        let [Warning::UnusedBinding { span, .. }] = &compiler.warnings[..] else {
+            panic!("expected a single unused binding warning");
+        };
+        assert_eq!(&source[span.clone()], "a");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ty::parse_array_length;
+
 use super::*;
 
 ast_node! {
@@ -8,6 +10,15 @@ ast_node! {
     }
 }
 
+ast_node! {
+    ArrayRepeat<M> {
+        value: Box<Expression<M>>,
+        length: u32,
+        span,
+        ty_info,
+    }
+}
+
 impl<M: AstMetadata> Parsable for Array<M> {
     fn register(parser: &mut Parser) {
         assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
@@ -16,11 +27,12 @@ impl<M: AstMetadata> Parsable for Array<M> {
                 // Parse opening square bracket
                 let span_start = match lexer.next_spanned().unwrap() {
                     (Token::LeftSquare, span) => span.start,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::LeftSquare),
                             found: Box::new(token),
                             reason: "array literal must start with square brace".to_string(),
+                            span,
                         });
                     }
                 };
@@ -29,23 +41,57 @@ impl<M: AstMetadata> Parsable for Array<M> {
                 let mut init = Vec::new();
                 let mut expect_item = true;
                 let span_end = loop {
-                    match (lexer.peek_token().unwrap(), expect_item) {
-                        (Token::Comma, false) => {
+                    match (lexer.peek_spanned().unwrap(), expect_item) {
+                        ((Token::Comma, _), false) => {
                             expect_item = true;
                             lexer.next_token();
                         }
-                        (Token::RightSquare, _) => {
+                        ((Token::RightSquare, _), _) => {
                             break lexer.next_spanned().unwrap().1.end;
                         }
                         (_, true) => {
                             init.push(parser.parse(compiler, lexer, Precedence::Lowest)?);
                             expect_item = false;
+
+                            // A semicolon after the first item repeats it, such as `[0; 4]`
+                            if init.len() == 1
+                                && matches!(lexer.peek_token(), Some(Token::SemiColon))
+                            {
+                                lexer.next_token();
+
+                                let length = parse_array_length(lexer)?;
+
+                                let span_end =
+                                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                                        span: lexer.eof_span(),
+                                    })? {
+                                        (Token::RightSquare, span) => span.end,
+                                        (token, span) => {
+                                            return Err(ParseError::ExpectedToken {
+                                                expected: Box::new(Token::RightSquare),
+                                                found: Box::new(token),
+                                                reason:
+                                                    "expected closing bracket after array length"
+                                                        .to_string(),
+                                                span,
+                                            });
+                                        }
+                                    };
+
+                                return Ok(Expression::ArrayRepeat(ArrayRepeat {
+                                    value: Box::new(init.pop().unwrap()),
+                                    length,
+                                    span: span_start..span_end,
+                                    ty_info: None,
+                                }));
+                            }
                         }
-                        (token, _) => {
+                        ((token, span), _) => {
                             return Err(ParseError::ExpectedToken {
                                 expected: Box::new(Token::RightSquare),
                                 found: Box::new(token.clone()),
                                 reason: "expected a comma or closing brace".to_string(),
+                                span: span.clone(),
                             });
                         }
                     }
@@ -76,11 +122,36 @@ impl SolveType for Array<UntypedAstMetadata> {
             .map(|i| i.solve(compiler, state))
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Make sure all of the init items agree on the type
-        let ty_info = init
+        // Make sure all of the init items agree with the first item that produces a value
+        if let Some(first) = init.iter().find(|i| i.get_ty_info().ty != Ty::Never) {
+            let expected = &first.get_ty_info().ty;
+
+            if let Some(item) = init.iter().find(|i| {
+                let ty = &i.get_ty_info().ty;
+                ty != expected && ty != &Ty::Never
+            }) {
+                return Err(TyMismatch {
+                    expected: expected.clone(),
+                    found: item.get_ty_info().ty.clone(),
+                }
+                .at_with_expected(
+                    compiler,
+                    item.span().clone(),
+                    Some(first.span().clone()),
+                ));
+            }
+        }
+
+        let mut ty_info = init
             .iter()
             .map(|i| i.get_ty_info().clone())
-            .collect::<Result<TyInfo, _>>()?;
+            .collect::<Result<TyInfo, _>>()
+            .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        // An empty array takes its element type from what it is expected to be, if anything
+        if let Some(Ty::Array { inner, .. }) = self.ty_info.filter(|_| init.is_empty()) {
+            ty_info.ty = *inner;
+        }
 
         Ok(Array {
             span: self.span,
@@ -96,6 +167,31 @@ impl SolveType for Array<UntypedAstMetadata> {
     }
 }
 
+impl SolveType for ArrayRepeat<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, crate::ty::TyError> {
+        let value = self.value.solve(compiler, state)?;
+
+        Ok(ArrayRepeat {
+            ty_info: TyInfo {
+                ty: Ty::Array {
+                    inner: Box::new(value.get_ty_info().ty.clone()),
+                    size: self.length,
+                },
+                return_ty: value.get_ty_info().return_ty.clone(),
+            },
+            value: Box::new(value),
+            length: self.length,
+            span: self.span,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -155,6 +251,42 @@ mod test {
             assert_array_len(&array.init[2], 3);
         }
 
+        #[rstest]
+        #[case::single("[0; 4]", 4)]
+        #[case::empty("[1; 0]", 0)]
+        #[case::nested("[[1, 2]; 3]", 3)]
+        fn repeat(parser: Parser, #[case] source: &str, #[case] expected: u32) {
+            let array: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::ArrayRepeat(ArrayRepeat { length, .. }) = array else {
+                panic!("expected to parse array repeat");
+            };
+
+            assert_eq!(length, expected);
+        }
+
+        #[rstest]
+        #[case::missing_length("[0;]")]
+        #[case::negative_length("[0; -1]")]
+        #[case::non_literal_length("[0; n]")]
+        #[case::unclosed("[0; 4")]
+        #[case::repeat_after_list("[0, 1; 4]")]
+        fn repeat_fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+
         fn assert_array_len<M: AstMetadata>(
             expression: &Expression<M>,
             length: usize,
@@ -168,4 +300,113 @@ mod test {
             array
         }
     }
+    mod ty {
+        use super::*;
+
+        #[test]
+        fn homogeneous() {
+            let array = Array::<UntypedAstMetadata>::new(
+                vec![
+                    Expression::integer(1, Span::default()),
+                    Expression::integer(2, Span::default()),
+                ],
+                Span::default(),
+                None,
+            );
+
+            assert_eq!(
+                array
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                Ty::Array {
+                    inner: Box::new(Ty::Int),
+                    size: 2
+                }
+            );
+        }
+
+        #[test]
+        fn element_mismatch() {
+            let array = Array::<UntypedAstMetadata>::new(
+                vec![
+                    Expression::integer(1, 1..2),
+                    Expression::integer(2, 4..5),
+                    Expression::boolean(true, 7..11),
+                ],
+                0..12,
+                None,
+            );
+
+            let result = array.solve(&mut Compiler::default(), &mut Scope::new());
+
+            assert!(matches!(
+                result,
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    span,
+                    expected_span: Some(expected_span),
+                }) if expected == "int"
+                    && found == "bool"
+                    && span == (7..11)
+                    && expected_span == (1..2)
+            ));
+        }
+
+        #[test]
+        fn repeat() {
+            let array = ArrayRepeat::<UntypedAstMetadata>::new(
+                Box::new(Expression::boolean(false, Span::default())),
+                8,
+                Span::default(),
+                None,
+            );
+
+            assert_eq!(
+                array
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                Ty::Array {
+                    inner: Box::new(Ty::Boolean),
+                    size: 8
+                }
+            );
+        }
+
+        #[test]
+        fn length_mismatch() {
+            let array = Array::<UntypedAstMetadata>::new(
+                vec![
+                    Expression::Array(Array::new(
+                        vec![Expression::integer(1, Span::default())],
+                        Span::default(),
+                        None,
+                    )),
+                    Expression::Array(Array::new(
+                        vec![
+                            Expression::integer(1, Span::default()),
+                            Expression::integer(2, Span::default()),
+                        ],
+                        Span::default(),
+                        None,
+                    )),
+                ],
+                Span::default(),
+                None,
+            );
+
+            assert!(matches!(
+                array.solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::ArrayLength {
+                    expected: 1,
+                    found: 2,
+                    ..
+                })
+            ));
+        }
+    }
 }
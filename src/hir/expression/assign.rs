@@ -3,34 +3,48 @@ use super::*;
 ast_node! {
     Assign<M> {
         binding: M::IdentIdentifier,
+        projections: Vec<Projection<M>>,
+        operation: Option<InfixOperation>,
         value: Box<Expression<M>>,
         span,
         ty_info,
     }
 }
 
+/// A single step from a binding towards the location within it that is being assigned to.
+#[derive(Clone, Debug)]
+pub enum Projection<M: AstMetadata> {
+    /// A named field of a struct.
+    Field(Symbol),
+    /// An element of a tuple.
+    Element(u32),
+    /// An element of an array, at an index which is evaluated before the assigned value.
+    Index(Expression<M>),
+}
+
 impl<M: AstMetadata> Parsable for Assign<M> {
     fn register(parser: &mut Parser) {
         assert!(parser.register_infix::<Expression<UntypedAstMetadata>>(
             Token::Eq,
             |parser, compiler, lexer, left| {
-                let (binding, binding_span) = match left {
-                    Expression::Ident(Ident { binding, span, .. }) => (binding, span),
-                    lhs => {
-                        return Err(ParseError::InvalidInfixLhs {
-                            found: Box::new(lhs),
-                            reason: "assign must start with ident".to_string(),
-                        });
-                    }
-                };
+                if !is_assignable(&left) {
+                    return Err(ParseError::InvalidInfixLhs {
+                        span: left.span().clone(),
+                        found: Box::new(left),
+                        reason: "assign must be to a binding, field or index".to_string(),
+                    });
+                }
+                let binding_span = left.span().clone();
+                let (binding, projections) = assignment_target(left);
 
-                match lexer.next_token().unwrap() {
-                    Token::Eq => (),
-                    token => {
+                match lexer.next_spanned().unwrap() {
+                    (Token::Eq, _) => (),
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Eq),
                             found: Box::new(token),
                             reason: "equals sign following binding for assign".to_string(),
+                            span,
                         });
                     }
                 }
@@ -41,6 +55,8 @@ impl<M: AstMetadata> Parsable for Assign<M> {
                 Ok(Expression::Assign(Assign {
                     span: binding_span.start..value.span().end,
                     binding,
+                    projections,
+                    operation: None,
                     value: Box::new(value),
                     ty_info: None,
                 }))
@@ -52,52 +68,51 @@ impl<M: AstMetadata> Parsable for Assign<M> {
             (Token::MinusAssign, InfixOperation::Minus),
             (Token::MulAssign, InfixOperation::Multiply),
             (Token::DivAssign, InfixOperation::Divide),
+            (Token::ModAssign, InfixOperation::Modulo),
+            (Token::BitAndAssign, InfixOperation::BitAnd),
+            (Token::BitOrAssign, InfixOperation::BitOr),
+            (Token::BitXorAssign, InfixOperation::BitXor),
+            (Token::ShiftLeftAssign, InfixOperation::ShiftLeft),
+            (Token::ShiftRightAssign, InfixOperation::ShiftRight),
         ]
         .into_iter()
         .for_each(|(token, operation)| {
             assert!(parser.register_infix::<Expression<UntypedAstMetadata>>(
                 token.clone(),
                 move |parser, compiler, lexer, left| {
-                    let (binding, binding_span) = match left {
-                        Expression::Ident(Ident { binding, span, .. }) => (binding, span),
-                        lhs => {
-                            return Err(ParseError::InvalidInfixLhs {
-                                found: Box::new(lhs),
-                                reason: "assign must start with ident".to_string(),
-                            });
-                        }
-                    };
+                    if !is_assignable(&left) {
+                        return Err(ParseError::InvalidInfixLhs {
+                            span: left.span().clone(),
+                            found: Box::new(left),
+                            reason: "assign must be to a binding, field or index".to_string(),
+                        });
+                    }
+                    let binding_span = left.span().clone();
+                    let (binding, projections) = assignment_target(left);
 
-                    let token_span = match lexer.next_spanned().unwrap() {
-                        (t, span) if t == token => span,
-                        (token, _) => {
+                    match lexer.next_spanned().unwrap() {
+                        (t, _) if t == token => (),
+                        (token, span) => {
                             return Err(ParseError::ExpectedToken {
                                 expected: Box::new(Token::Eq),
                                 found: Box::new(token),
                                 reason: "equals sign following binding for assign".to_string(),
+                                span,
                             });
                         }
                     };
 
                     let value: Expression<UntypedAstMetadata> =
                         parser.parse(compiler, lexer, Precedence::Lowest)?;
-                    let value_span = value.span();
 
                     Ok(Expression::Assign(Assign {
-                        span: binding_span.start..value_span.end,
+                        span: binding_span.start..value.span().end,
                         binding,
-                        // Manually build an infix operation (not great, but it'll do)
-                        value: Box::new(Expression::Infix(Infix {
-                            left: Box::new(Expression::Ident(Ident {
-                                binding,
-                                span: binding_span,
-                                ty_info: None,
-                            })),
-                            operation,
-                            span: token_span.start..value_span.end,
-                            right: Box::new(value),
-                            ty_info: None,
-                        })),
+                        projections,
+                        // The current value is combined with the new value once the location has
+                        // been resolved, so that it is only evaluated once
+                        operation: Some(operation),
+                        value: Box::new(value),
                         ty_info: None,
                     }))
                 }
@@ -106,6 +121,38 @@ impl<M: AstMetadata> Parsable for Assign<M> {
     }
 }
 
+/// Determine whether an expression refers to a location that can be assigned to.
+fn is_assignable(left: &Expression<UntypedAstMetadata>) -> bool {
+    match left {
+        Expression::Ident(_) => true,
+        Expression::Field(Field { value, .. })
+        | Expression::TupleIndex(TupleIndex { value, .. })
+        | Expression::Index(Index { value, .. }) => is_assignable(value),
+        _ => false,
+    }
+}
+
+/// Break down the left hand side of an assignment into the binding that is being assigned to, and
+/// the path of projections within it. The expression must already be known to be assignable.
+fn assignment_target(
+    left: Expression<UntypedAstMetadata>,
+) -> (Symbol, Vec<Projection<UntypedAstMetadata>>) {
+    let (target, projection) = match left {
+        Expression::Ident(Ident { binding, .. }) => return (binding, Vec::new()),
+        Expression::Field(Field { value, field, .. }) => (value, Projection::Field(field)),
+        Expression::TupleIndex(TupleIndex { value, index, .. }) => {
+            (value, Projection::Element(index))
+        }
+        Expression::Index(Index { value, index, .. }) => (value, Projection::Index(*index)),
+        _ => unreachable!("assignment target must be assignable"),
+    };
+
+    let (binding, mut projections) = assignment_target(*target);
+    projections.push(projection);
+
+    (binding, projections)
+}
+
 impl SolveType for Assign<UntypedAstMetadata> {
     type State = Scope;
 
@@ -115,24 +162,114 @@ impl SolveType for Assign<UntypedAstMetadata> {
         state: &mut Self::State,
     ) -> Result<Self::Typed, crate::ty::TyError> {
         // Work out what type the variable has to be
-        let (binding, ty) = state
-            .resolve(self.binding)
-            .ok_or(TyError::SymbolNotFound(self.binding))?;
+        let (binding, mut ty) =
+            state
+                .resolve(self.binding)
+                .ok_or_else(|| TyError::SymbolNotFound {
+                    name: compiler.symbols.resolve(self.binding).unwrap().to_string(),
+                    span: self.span.clone(),
+                })?;
+
+        // Only mutable bindings may be assigned to
+        let (mutable, declaration_span) = state.get_mutability(&binding);
+        if !mutable {
+            return Err(TyError::AssignToImmutable {
+                binding: compiler.symbols.resolve(self.binding).unwrap().to_string(),
+                span: self.span.clone(),
+                declaration_span: declaration_span.clone(),
+            });
+        }
+
+        // Compound operations read the current value before replacing it
+        if self.operation.is_some() {
+            state.mark_used(&binding);
+        }
+
+        // Follow any projections to the location that is being assigned to
+        let mut return_tys = Vec::new();
+        let projections = self
+            .projections
+            .into_iter()
+            .map(|projection| {
+                Ok(match projection {
+                    Projection::Field(field) => {
+                        ty = compiler
+                            .types
+                            .resolve_field(&ty, field)
+                            .map(|(_, ty)| ty.clone())
+                            .ok_or_else(|| TyError::NoField {
+                                ty: ty.name(compiler),
+                                field: compiler.symbols.resolve(field).unwrap().to_string(),
+                                span: self.span.clone(),
+                            })?;
+
+                        Projection::Field(field)
+                    }
+                    Projection::Element(index) => {
+                        ty = match &ty {
+                            Ty::Tuple(tys) => tys.get(index as usize).cloned(),
+                            _ => None,
+                        }
+                        .ok_or_else(|| TyError::NoElement {
+                            ty: ty.name(compiler),
+                            index,
+                            span: self.span.clone(),
+                        })?;
+
+                        Projection::Element(index)
+                    }
+                    Projection::Index(index) => {
+                        let index = index.solve(compiler, state)?;
+                        return_tys.push(index.get_ty_info().return_ty.clone());
+
+                        ty = match &ty {
+                            Ty::Array { inner, size } => {
+                                check_index(compiler, &index, *size)?;
+
+                                *inner.clone()
+                            }
+                            _ => {
+                                return Err(TyError::Index {
+                                    ty: ty.name(compiler),
+                                    span: self.span.clone(),
+                                });
+                            }
+                        };
+
+                        Projection::Index(index)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         let value = self.value.solve(compiler, state)?;
+        return_tys.push(value.get_ty_info().return_ty.clone());
 
-        let value_ty = value.get_ty_info().ty.clone();
+        // Compound assignments store the result of the operation
+        let value_ty = match &self.operation {
+            Some(operation) => operation
+                .result_ty(&ty, &value.get_ty_info().ty)
+                .map_err(|e| e.at(compiler, self.span.clone()))?,
+            None => value.get_ty_info().ty.clone(),
+        };
 
         if value_ty != ty {
-            return Err(TyError::Mismatch(ty, value_ty));
+            return Err(TyError::Mismatch {
+                expected: ty.name(compiler),
+                found: value_ty.name(compiler),
+                span: value.span().clone(),
+                expected_span: None,
+            });
         }
 
+        let ty_info = TyInfo::try_from((Ty::Unit, return_tys))
+            .map_err(|e| e.at(compiler, self.span.clone()))?;
+
         Ok(Assign {
             binding,
-            ty_info: TyInfo {
-                ty: Ty::Unit,
-                return_ty: value.get_ty_info().return_ty.clone(),
-            },
+            projections,
+            operation: self.operation,
+            ty_info,
             value: Box::new(value),
             span: self.span,
         })
@@ -158,6 +295,9 @@ mod test {
             // Register additional parsers for testing
             Integer::<UntypedAstMetadata>::register(&mut parser);
             Ident::<UntypedAstMetadata>::register(&mut parser);
+            Field::<UntypedAstMetadata>::register(&mut parser);
+            Index::<UntypedAstMetadata>::register(&mut parser);
+            Call::<UntypedAstMetadata>::register(&mut parser);
 
             parser
         }
@@ -199,25 +339,203 @@ mod test {
                 )
                 .unwrap();
 
-            let Expression::Assign(Assign { value, .. }) = assign else {
+            let Expression::Assign(Assign {
+                operation, value, ..
+            }) = assign
+            else {
                 panic!("expected to parse assignment")
             };
-            let Expression::Infix(Infix { operation, .. }) = *value else {
-                panic!("expected assignment to contain infix operation");
+
+            assert_eq!(operation, Some(expected));
+            assert!(matches!(*value, Expression::Integer(_)));
+        }
+
+        #[rstest]
+        #[case::single_field("a.b = 1", 1)]
+        #[case::nested_field("a.b.c = 1", 2)]
+        #[case::op_assign_field("a.b += 1", 1)]
+        #[case::index("a[1] = 1", 1)]
+        #[case::nested_index("a[1][b] = 1", 2)]
+        #[case::op_assign_index("a[1] *= 2", 1)]
+        #[case::element("a.0 = 1", 1)]
+        #[case::mixed("a.b[1].0 = 1", 3)]
+        fn place(parser: Parser, #[case] source: &str, #[case] depth: usize) {
+            let mut compiler = Compiler::default();
+
+            let assign: Expression<UntypedAstMetadata> = parser
+                .parse(&mut compiler, &mut Lexer::from(source), Precedence::Lowest)
+                .unwrap();
+
+            let Expression::Assign(assign) = assign else {
+                panic!("expected to parse assignment")
             };
 
-            assert_eq!(operation, expected);
+            assert_eq!("a", compiler.symbols.resolve(assign.binding).unwrap());
+            assert_eq!(assign.projections.len(), depth);
         }
 
         #[rstest]
-        fn invalid(parser: Parser) {
+        #[case::literal("1 = otherident")]
+        #[case::call("f()[0] = 1")]
+        #[case::op_assign_literal("1 += 1")]
+        fn invalid(parser: Parser, #[case] source: &str) {
             let result: Result<Expression<UntypedAstMetadata>, _> = parser.parse(
                 &mut Compiler::default(),
-                &mut Lexer::from("1 = otherident"),
+                &mut Lexer::from(source),
                 Precedence::Lowest,
             );
 
             assert!(matches!(result, Err(ParseError::InvalidInfixLhs { .. })));
         }
     }
+
+    mod ty {
+        use super::*;
+
+        /// Produce a compiler with `struct Point { x: int }` defined, and a scope with mutable
+        /// `p: Point` and `a: [[bool; 2]; 3]`, along with an immutable `c: int`.
+        fn setup() -> (Compiler, Scope) {
+            let mut compiler = Compiler::default();
+
+            let point = compiler.symbols.get_or_intern("Point");
+            let x = compiler.symbols.get_or_intern("x");
+            let idx = compiler.types.declare(point);
+            compiler
+                .types
+                .define(idx, vec![(x, Ty::Int)], Span::default());
+
+            let mut scope = Scope::new();
+            scope.register_mut(
+                compiler.symbols.get_or_intern("p"),
+                Ty::Struct(idx),
+                Span::default(),
+            );
+            scope.register_mut(
+                compiler.symbols.get_or_intern("a"),
+                Ty::Array {
+                    inner: Box::new(Ty::Array {
+                        inner: Box::new(Ty::Boolean),
+                        size: 2,
+                    }),
+                    size: 3,
+                },
+                Span::default(),
+            );
+            scope.register(compiler.symbols.get_or_intern("c"), Ty::Int, 0..5);
+
+            (compiler, scope)
+        }
+
+        #[rstest]
+        #[case::matching_field("x", Expression::integer(1, Span::default()), true)]
+        #[case::mismatched_field("x", Expression::boolean(true, Span::default()), false)]
+        #[case::unknown_field("y", Expression::integer(1, Span::default()), false)]
+        fn field(
+            #[case] name: &str,
+            #[case] value: Expression<UntypedAstMetadata>,
+            #[case] success: bool,
+        ) {
+            let (mut compiler, mut scope) = setup();
+
+            let assign = Assign::new(
+                compiler.symbols.get_or_intern("p"),
+                vec![Projection::Field(compiler.symbols.get_or_intern(name))],
+                None,
+                Box::new(value),
+                Span::default(),
+                None,
+            );
+
+            assert_eq!(assign.solve(&mut compiler, &mut scope).is_ok(), success);
+        }
+
+        #[rstest]
+        #[case::element(2, Expression::boolean(true, Span::default()), true)]
+        #[case::row(1, Expression::boolean(true, Span::default()), false)]
+        #[case::mismatched_element(2, Expression::integer(1, Span::default()), false)]
+        #[case::too_deep(3, Expression::boolean(true, Span::default()), false)]
+        fn index(
+            #[case] depth: usize,
+            #[case] value: Expression<UntypedAstMetadata>,
+            #[case] success: bool,
+        ) {
+            let (mut compiler, mut scope) = setup();
+
+            let assign = Assign::new(
+                compiler.symbols.get_or_intern("a"),
+                (0..depth)
+                    .map(|i| Projection::Index(Expression::integer(i as u64, Span::default())))
+                    .collect(),
+                None,
+                Box::new(value),
+                Span::default(),
+                None,
+            );
+
+            assert_eq!(assign.solve(&mut compiler, &mut scope).is_ok(), success);
+        }
+
+        #[test]
+        fn compound_numeric() {
+            let (mut compiler, mut scope) = setup();
+
+            // p.x += 1
+            let assign = Assign::new(
+                compiler.symbols.get_or_intern("p"),
+                vec![Projection::Field(compiler.symbols.get_or_intern("x"))],
+                Some(InfixOperation::Plus),
+                Box::new(Expression::integer(1, Span::default())),
+                Span::default(),
+                None,
+            );
+
+            assert!(assign.solve(&mut compiler, &mut scope).is_ok());
+        }
+
+        #[test]
+        fn compound_boolean() {
+            let (mut compiler, mut scope) = setup();
+
+            // a[0][0] += true
+            let assign = Assign::new(
+                compiler.symbols.get_or_intern("a"),
+                vec![
+                    Projection::Index(Expression::integer(0, Span::default())),
+                    Projection::Index(Expression::integer(0, Span::default())),
+                ],
+                Some(InfixOperation::Plus),
+                Box::new(Expression::boolean(true, Span::default())),
+                Span::default(),
+                None,
+            );
+
+            assert!(assign.solve(&mut compiler, &mut scope).is_err());
+        }
+
+        #[rstest]
+        #[case::plain(None)]
+        #[case::compound(Some(InfixOperation::Plus))]
+        fn immutable(#[case] operation: Option<InfixOperation>) {
+            let (mut compiler, mut scope) = setup();
+
+            // c = 1
+            let assign = Assign::new(
+                compiler.symbols.get_or_intern("c"),
+                vec![],
+                operation,
+                Box::new(Expression::integer(1, Span::default())),
+                10..15,
+                None,
+            );
+
+            assert!(matches!(
+                assign.solve(&mut compiler, &mut scope),
+                Err(TyError::AssignToImmutable {
+                    binding,
+                    span,
+                    declaration_span,
+                }) if binding == "c" && span == (10..15) && declaration_span == (0..5)
+            ));
+        }
+    }
 }
@@ -14,35 +14,61 @@ impl<M: AstMetadata> Parsable for Block<M> {
             Token::LeftBrace,
             |parser, compiler, lexer| {
                 // Parse opening bracket
-                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::LeftBrace, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::LeftBrace),
                             found: Box::new(token),
                             reason: "block must start with opening brace".to_string(),
+                            span,
                         });
                     }
                 };
 
-                // Parse statements
+                // Parse statements, recovering from any that fail
                 let mut statements = Vec::new();
                 while lexer
                     .peek_token()
-                    .map(|t| !matches!(t, Token::RightBrace))
+                    .map(|t| {
+                        !matches!(
+                            t,
+                            Token::RightBrace | Token::Fn | Token::Struct | Token::Enum
+                        )
+                    })
                     .unwrap_or(false)
                 {
-                    statements.push(parser.parse(compiler, lexer, Precedence::Lowest)?);
+                    // Documentation can only be attached to functions, so treat it as a comment
+                    if let Some(Token::DocComment(_)) = lexer.peek_token() {
+                        lexer.next_token();
+                        continue;
+                    }
+
+                    match parser.parse(compiler, lexer, Precedence::Lowest) {
+                        Ok(statement) => statements.push(statement),
+                        Err(e) => {
+                            lexer.report(e);
+                            lexer.synchronise();
+                        }
+                    }
                 }
 
-                // Parse ending bracket
-                let end_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
-                    (Token::RightBrace, span) => span,
-                    (token, _) => {
+                // Parse ending bracket, leaving any other token in place to recover from
+                let end_span = match lexer.peek_spanned() {
+                    Some((Token::RightBrace, _)) => lexer.next_spanned().unwrap().1,
+                    Some((token, span)) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::RightBrace),
-                            found: Box::new(token),
+                            found: Box::new(token.clone()),
                             reason: "block must end with closing brace".to_string(),
+                            span: span.clone(),
+                        });
+                    }
+                    None => {
+                        return Err(ParseError::UnexpectedEOF {
+                            span: lexer.eof_span(),
                         });
                     }
                 };
@@ -74,26 +100,43 @@ impl SolveType for Block<UntypedAstMetadata> {
             .map(|statement| statement.solve(compiler, state))
             .collect::<Result<Vec<_>, _>>()?;
 
+        // Anything following a statement that never completes can't be reached
+        if let Some(i) = statements[..statements.len().saturating_sub(1)]
+            .iter()
+            .position(Statement::diverges)
+        {
+            compiler.warn(Warning::UnreachableCode {
+                span: statements[i + 1].span().start..statements.last().unwrap().span().end,
+                diverging_span: statements[i].span().clone(),
+            });
+        }
+
         let ty_info = TyInfo::try_from((
-            // Type of this block will be the implicit return of the last block
-            statements
-                .last()
-                // The block can only inherit the type of an expression statement
-                // .filter(|s| {
-                //     matches!(
-                //         s,
-                //         Statement::Expression(ExpressionStatement {
-                //             implicit_return: true,
-                //             ..
-                //         })
-                //     )
-                // })
-                .map(|s| s.get_ty_info().ty.clone())
-                .unwrap_or(Ty::Unit),
+            // A block never completes if any of its statements don't
+            if statements.iter().any(Statement::diverges) {
+                Ty::Never
+            } else {
+                // Type of this block will be the implicit return of the last block
+                statements
+                    .last()
+                    // The block can only inherit the type of an expression statement
+                    // .filter(|s| {
+                    //     matches!(
+                    //         s,
+                    //         Statement::Expression(ExpressionStatement {
+                    //             implicit_return: true,
+                    //             ..
+                    //         })
+                    //     )
+                    // })
+                    .map(|s| s.get_ty_info().ty.clone())
+                    .unwrap_or(Ty::Unit)
+            },
             statements
                 .iter()
                 .map(|statement| statement.get_ty_info().return_ty.clone()),
-        ))?;
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
 
         // Leave a scope
         assert_eq!(
@@ -156,5 +199,102 @@ mod test {
 
             assert_eq!(b.statements.len(), count);
         }
+
+        #[rstest]
+        #[case::single("{ true; ); true }", 2, 1)]
+        #[case::multiple("{ ); ); true }", 1, 2)]
+        #[case::trailing("{ true; ) }", 1, 1)]
+        #[case::skip_block("{ ) { true; } true; true }", 1, 1)]
+        fn recover(
+            parser: Parser,
+            #[case] source: &str,
+            #[case] count: usize,
+            #[case] errors: usize,
+        ) {
+            let mut lexer = Lexer::from(source);
+
+            let b: Expression<UntypedAstMetadata> = parser
+                .parse(&mut Compiler::default(), &mut lexer, Precedence::Lowest)
+                .unwrap();
+
+            let Expression::Block(b) = b else {
+                panic!("expected to parse block");
+            };
+
+            assert_eq!(b.statements.len(), count);
+            assert_eq!(lexer.take_errors().len(), errors);
+        }
+
+        #[rstest]
+        fn stop_at_function(parser: Parser) {
+            let mut lexer = Lexer::from("{ true; fn");
+
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut lexer,
+                    Precedence::Lowest,
+                )
+                .is_err());
+
+            // The function keyword must be left for the caller to recover with
+            assert_eq!(lexer.next_token(), Some(Token::Fn));
+        }
+    }
+
+    mod ty {
+        use string_interner::Symbol;
+
+        use super::*;
+
+        fn integer(value: u64) -> Statement<UntypedAstMetadata> {
+            Statement::expression(
+                Expression::integer(value, Span::default()),
+                true,
+                Span::default(),
+            )
+        }
+
+        fn value(value: u64) -> Statement<UntypedAstMetadata> {
+            Statement::expression(
+                Expression::integer(value, Span::default()),
+                false,
+                Span::default(),
+            )
+        }
+
+        fn _return(value: u64) -> Statement<UntypedAstMetadata> {
+            Statement::_return(Expression::integer(value, Span::default()), Span::default())
+        }
+
+        fn let_return(value: u64) -> Statement<UntypedAstMetadata> {
+            Statement::_let(
+                Symbol::try_from_usize(0).unwrap(),
+                Expression::block(vec![_return(value)], Span::default()),
+                Span::default(),
+            )
+        }
+
+        #[rstest]
+        #[case::empty(vec![], Ty::Unit)]
+        #[case::terminated(vec![integer(1)], Ty::Unit)]
+        #[case::implicit(vec![integer(1), value(2)], Ty::Int)]
+        #[case::returns(vec![_return(1)], Ty::Never)]
+        #[case::returns_early(vec![_return(1), integer(2)], Ty::Never)]
+        #[case::returns_before_value(vec![_return(1), value(2)], Ty::Never)]
+        #[case::let_returns(vec![let_return(1)], Ty::Never)]
+        #[case::let_returns_before_value(vec![let_return(1), value(2)], Ty::Never)]
+        fn block(#[case] statements: Vec<Statement<UntypedAstMetadata>>, #[case] expected: Ty) {
+            let block = Block::<UntypedAstMetadata>::new(statements, Span::default(), None);
+
+            assert_eq!(
+                block
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                expected
+            );
+        }
     }
 }
@@ -21,6 +21,7 @@ impl<M: AstMetadata> Parsable for Boolean<M> {
                         expected: Box::new(Token::True),
                         found: Box::new(token),
                         reason: "expected boolean".to_string(),
+                        span,
                     });
                 }
             };
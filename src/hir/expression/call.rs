@@ -11,13 +11,15 @@ ast_node! {
 
 impl<M: AstMetadata> Parsable for Call<M> {
     fn register(parser: &mut Parser) {
-        assert!(
-            parser.register_infix(Token::LeftParen, |parser, compiler, lexer, left| {
+        assert!(parser.register_infix::<Expression<UntypedAstMetadata>>(
+            Token::LeftParen,
+            |parser, compiler, lexer, left| {
                 // Pull out a binding for the LHS
                 let (binding, binding_span) = match left {
                     Expression::Ident(Ident { binding, span, .. }) => (binding, span),
                     lhs => {
                         return Err(ParseError::InvalidInfixLhs {
+                            span: lhs.span().clone(),
                             found: Box::new(lhs),
                             reason: "assign must start with ident".to_string(),
                         });
@@ -26,9 +28,9 @@ impl<M: AstMetadata> Parsable for Call<M> {
 
                 // Consume the args
                 let args = std::iter::from_fn(|| {
-                    match lexer.peek_token()? {
-                        Token::RightParen => None,
-                        Token::LeftParen | Token::Comma => {
+                    match lexer.peek_spanned()? {
+                        (Token::RightParen, _) => None,
+                        (Token::LeftParen | Token::Comma, _) => {
                             // Consume the opening paren or comma
                             lexer.next_token();
 
@@ -40,23 +42,27 @@ impl<M: AstMetadata> Parsable for Call<M> {
                             // Parse the next argument
                             Some(parser.parse(compiler, lexer, Precedence::Lowest))
                         }
-                        token => Some(Err(ParseError::ExpectedToken {
+                        (token, span) => Some(Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Comma),
                             found: Box::new(token.clone()),
                             reason: "function arguments must be separated by a comma".to_string(),
+                            span: span.clone(),
                         })),
                     }
                 })
                 .collect::<Result<Vec<_>, _>>()?;
 
                 // Consume the closing paren
-                let end_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let end_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::RightParen, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::RightParen),
                             found: Box::new(token),
                             reason: "argument list must end with right paren".to_string(),
+                            span,
                         })
                     }
                 };
@@ -68,8 +74,8 @@ impl<M: AstMetadata> Parsable for Call<M> {
                     span,
                     Default::default(),
                 )))
-            })
-        );
+            }
+        ));
     }
 }
 
@@ -89,10 +95,24 @@ impl SolveType for Call<UntypedAstMetadata> {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Compare the arguments to the function types
-        let function_idx = compiler
-            .functions
-            .get_idx(self.name)
-            .ok_or(TyError::SymbolNotFound(self.name))?;
+        let function_idx =
+            compiler
+                .functions
+                .get_idx(self.name)
+                .ok_or_else(|| TyError::SymbolNotFound {
+                    name: compiler.symbols.resolve(self.name).unwrap().to_string(),
+                    span: self.span.clone(),
+                })?;
+
+        // Record the call, so that functions which can never be run can be found
+        if let Some(caller) = state.function() {
+            compiler
+                .functions
+                .get_mut(caller)
+                .expect("function must be defined")
+                .add_call(function_idx);
+        }
+
         let signature = compiler
             .functions
             .get(function_idx)
@@ -104,14 +124,17 @@ impl SolveType for Call<UntypedAstMetadata> {
             panic!("too many arguments");
         }
 
-        if !args
+        // Each argument must match the type of its parameter
+        if let Some((arg, expected)) = args
             .iter()
-            .map(|arg| arg.get_ty_info().ty.clone())
             .zip(&signature.arguments)
-            .all(|(call, signature)| call == *signature)
+            .find(|(arg, expected)| arg.get_ty_info().ty != **expected)
         {
-            // TODO: New type error when a parameter is the wrong type
-            panic!("parameter wong type");
+            return Err(TyMismatch {
+                expected: expected.clone(),
+                found: arg.get_ty_info().ty.clone(),
+            }
+            .at(compiler, arg.span().clone()));
         }
 
         Ok(Call {
@@ -120,7 +143,8 @@ impl SolveType for Call<UntypedAstMetadata> {
                 signature.return_ty.clone(),
                 // Ensure all the return types from the arguments are correct
                 args.iter().map(|arg| arg.get_ty_info().return_ty.clone()),
-            ))?,
+            ))
+            .map_err(|e| e.at(compiler, self.span.clone()))?,
             name: function_idx,
             args,
             span: self.span,
@@ -187,4 +211,23 @@ mod test {
                 .is_err());
         }
     }
+
+    mod ty {
+        use super::*;
+        use crate::compiler::CompilerError;
+
+        #[rstest]
+        fn array_length() {
+            let source = "fn f(a: [int; 3]) -> int { a[0] }\nfn main() -> int { f([1, 2]) }";
+
+            assert!(matches!(
+                Compiler::default().compile(source),
+                Err(CompilerError::Ty(TyError::ArrayLength {
+                    expected: 3,
+                    found: 2,
+                    ..
+                }))
+            ));
+        }
+    }
 }
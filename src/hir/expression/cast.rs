@@ -18,11 +18,12 @@ impl<M: AstMetadata> Parsable for Cast<M> {
             |parser, compiler, lexer, left| {
                 let as_span = match lexer.next_spanned().unwrap() {
                     (Token::As, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::As),
                             found: Box::new(token),
                             reason: "expected to find cast expression".to_string(),
+                            span,
                         });
                     }
                 };
@@ -53,11 +54,15 @@ impl SolveType for Cast<UntypedAstMetadata> {
 
         // Make sure that the value can be cast to the desired type
         match (value.get_ty_info().ty.clone(), self.target_ty.clone()) {
-            // Unsigned integer can become signed
-            (Ty::Uint, Ty::Int) => (),
-            // Signed integer can loose sign
-            (Ty::Int, Ty::Uint) => (),
-            (lhs, rhs) => return Err(TyError::Cast(lhs, rhs)),
+            // Numbers can be converted between each other, changing size or sign as required
+            (from, to) if from.is_numeric() && to.is_numeric() => (),
+            (from, to) => {
+                return Err(TyError::Cast {
+                    from: from.name(compiler),
+                    to: to.name(compiler),
+                    span: self.span,
+                })
+            }
         }
 
         Ok(Cast {
@@ -127,4 +132,27 @@ mod test {
 
         assert!(result.is_err());
     }
+
+    #[rstest]
+    #[case::widen(Expression::integer(1, Span::default()), Ty::I8)]
+    #[case::int_to_float(Expression::integer(1, Span::default()), Ty::F32)]
+    #[case::float_to_uint(Expression::Float(Float::new(1.5, Span::default(), None)), Ty::Uint)]
+    fn ty_success(#[case] value: Expression<UntypedAstMetadata>, #[case] to: Ty) {
+        let cast = Cast::new(Box::new(value), to.clone(), Span::default(), None)
+            .solve(&mut Compiler::default(), &mut Scope::new())
+            .unwrap();
+
+        assert_eq!(cast.ty_info.ty, to);
+    }
+
+    #[rstest]
+    #[case::boolean_to_int(Expression::boolean(true, Span::default()), Ty::Int)]
+    #[case::int_to_boolean(Expression::integer(1, Span::default()), Ty::Boolean)]
+    fn ty_fail(#[case] value: Expression<UntypedAstMetadata>, #[case] to: Ty) {
+        assert!(matches!(
+            Cast::new(Box::new(value), to, Span::default(), None)
+                .solve(&mut Compiler::default(), &mut Scope::new()),
+            Err(TyError::Cast { .. })
+        ));
+    }
 }
@@ -0,0 +1,247 @@
+use super::*;
+
+ast_node! {
+    Field<M> {
+        value: Box<Expression<M>>,
+        field: Symbol,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for Field<M> {
+    fn register(parser: &mut Parser) {
+        assert!(parser.register_infix::<Expression<UntypedAstMetadata>>(
+            Token::Dot,
+            |_, compiler, lexer, left| {
+                // Parse out the dot
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Dot, _) => (),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Dot),
+                            found: Box::new(token),
+                            reason: "expected field access".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                // Parse the name of the field
+                let (field, field_span) =
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Ident(field), span) => (field, span),
+                        // Numeric fields access the elements of a tuple
+                        (token @ (Token::Integer(_) | Token::Float(_)), span) => {
+                            return TupleIndex::from_token(left, token, span);
+                        }
+                        (token, span) => {
+                            return Err(ParseError::ExpectedIdentifier {
+                                found: Box::new(token),
+                                reason: "field access requires field name".to_string(),
+                                span,
+                            });
+                        }
+                    };
+
+                Ok(Expression::Field(Field {
+                    span: left.span().start..field_span.end,
+                    value: Box::new(left),
+                    field: compiler.symbols.get_or_intern(field),
+                    ty_info: None,
+                }))
+            }
+        ));
+    }
+}
+
+impl SolveType for Field<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let value = self.value.solve(compiler, state)?;
+        let value_ty_info = value.get_ty_info();
+
+        let ty = compiler
+            .types
+            .resolve_field(&value_ty_info.ty, self.field)
+            .map(|(_, ty)| ty.clone())
+            .ok_or_else(|| TyError::NoField {
+                ty: value_ty_info.ty.name(compiler),
+                field: compiler.symbols.resolve(self.field).unwrap().to_string(),
+                span: self.span.clone(),
+            })?;
+
+        Ok(Field {
+            ty_info: TyInfo {
+                ty,
+                return_ty: value_ty_info.return_ty.clone(),
+            },
+            value: Box::new(value),
+            field: self.field,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hir::expression::test_util::compiler_with_point;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            Field::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+            Infix::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::single("a.b", 1)]
+        #[case::nested("a.b.c", 2)]
+        fn success(parser: Parser, #[case] source: &str, #[case] depth: usize) {
+            let mut expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            for _ in 0..depth {
+                let Expression::Field(field) = expression else {
+                    panic!("expected field access");
+                };
+
+                expression = *field.value;
+            }
+
+            assert!(matches!(expression, Expression::Ident(_)));
+        }
+
+        #[rstest]
+        fn binds_tighter_than_infix(parser: Parser) {
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from("a.b + 1"),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Infix(Infix { left, .. }) = expression else {
+                panic!("expected infix expression");
+            };
+
+            assert!(matches!(*left, Expression::Field(_)));
+        }
+
+        #[rstest]
+        #[case::missing_field("a.")]
+        #[case::keyword_field("a.let")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn access(compiler: &mut Compiler, field: &str) -> Field<UntypedAstMetadata> {
+            let value = compiler.symbols.get_or_intern("value");
+
+            Field::new(
+                Box::new(Expression::ident(value, Span::default())),
+                compiler.symbols.get_or_intern(field),
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        #[case::int_field("x", Ty::Int)]
+        #[case::bool_field("y", Ty::Boolean)]
+        fn success(#[case] field: &str, #[case] expected: Ty) {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let mut scope = Scope::new();
+            scope.register(
+                compiler.symbols.get_or_intern("value"),
+                Ty::Struct(idx),
+                Span::default(),
+            );
+
+            let field = access(&mut compiler, field);
+
+            assert_eq!(
+                field.solve(&mut compiler, &mut scope).unwrap().ty_info.ty,
+                expected
+            );
+        }
+
+        #[rstest]
+        fn unknown_field() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let mut scope = Scope::new();
+            scope.register(
+                compiler.symbols.get_or_intern("value"),
+                Ty::Struct(idx),
+                Span::default(),
+            );
+
+            let field = access(&mut compiler, "z");
+
+            assert!(matches!(
+                field.solve(&mut compiler, &mut scope),
+                Err(TyError::NoField { ty, field, .. }) if ty == "Point" && field == "z"
+            ));
+        }
+
+        #[rstest]
+        fn not_a_struct() {
+            let (mut compiler, _) = compiler_with_point();
+
+            let mut scope = Scope::new();
+            scope.register(
+                compiler.symbols.get_or_intern("value"),
+                Ty::Int,
+                Span::default(),
+            );
+
+            let field = access(&mut compiler, "x");
+
+            assert!(matches!(
+                field.solve(&mut compiler, &mut scope),
+                Err(TyError::NoField { ty, .. }) if ty == "int"
+            ));
+        }
+    }
+}
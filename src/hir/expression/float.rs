@@ -0,0 +1,153 @@
+use crate::ty::TySpanned;
+
+use super::*;
+
+ast_node! {
+    Float<M> {
+        value: f64,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for Float<M> {
+    fn register(parser: &mut Parser) {
+        parser.register_prefix_test::<Expression<UntypedAstMetadata>>(
+            |token| matches!(token, Token::Float(_)),
+            |_, _, lexer| {
+                let (value, span) = match lexer.next_spanned().unwrap() {
+                    (Token::Float(value), span) => (value, span),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Float(String::new())),
+                            found: Box::new(token),
+                            reason: "expected float".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                let value = value.parse().map_err(|_| ParseError::InvalidLiteral {
+                    expected: "float".to_string(),
+                    span: span.clone(),
+                })?;
+
+                // An optional suffix can specify the type of the literal
+                let Some(suffix) = TySpanned::parse_suffix(lexer, span.end) else {
+                    return Ok(Expression::Float(Float {
+                        value,
+                        span,
+                        ty_info: None,
+                    }));
+                };
+
+                if !suffix.ty.is_float() {
+                    return Err(ParseError::InvalidLiteral {
+                        expected: "float type suffix".to_string(),
+                        span: suffix.span,
+                    });
+                }
+
+                Ok(Expression::Float(Float {
+                    value,
+                    span: span.start..suffix.span.end,
+                    ty_info: Some(suffix.ty),
+                }))
+            },
+        )
+    }
+}
+
+impl SolveType for Float<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(self, _compiler: &mut Compiler, _scope: &mut Scope) -> Result<Self::Typed, TyError> {
+        Ok(Float {
+            value: self.value,
+            span: self.span,
+            ty_info: TyInfo {
+                // Literals without a suffix default to double precision
+                ty: self.ty_info.unwrap_or(Ty::F64),
+                return_ty: None,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::{Lexer, Precedence};
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            Float::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::unsuffixed("1.5", 1.5, None)]
+        #[case::single("1.5f32", 1.5, Some(Ty::F32))]
+        #[case::double("0.25f64", 0.25, Some(Ty::F64))]
+        fn success(
+            parser: Parser,
+            #[case] source: &str,
+            #[case] value: f64,
+            #[case] ty: Option<Ty>,
+        ) {
+            let float: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Float(float) = float else {
+                panic!("expected float to be returned");
+            };
+
+            assert_eq!(float.value, value);
+            assert_eq!(float.ty_info, ty);
+        }
+
+        #[rstest]
+        fn integer_suffix(parser: Parser) {
+            assert!(matches!(
+                parser.parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from("1.5u8"),
+                    Precedence::Lowest,
+                ),
+                Err(ParseError::InvalidLiteral { .. })
+            ));
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        #[rstest]
+        #[case::default(None, Ty::F64)]
+        #[case::suffixed(Some(Ty::F32), Ty::F32)]
+        fn float_infer(#[case] suffix: Option<Ty>, #[case] ty: Ty) {
+            assert_eq!(
+                Float::new(1.5, Span::default(), suffix)
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                ty
+            );
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use super::*;
+
+ast_node! {
+    For<M> {
+        label: Option<Symbol>,
+        binding: M::IdentIdentifier,
+        binding_span: M::Span,
+        start: Box<Expression<M>>,
+        end: Box<Expression<M>>,
+        inclusive: bool,
+        body: Block<M>,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for For<M> {
+    fn register(parser: &mut Parser) {
+        assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
+            Token::For,
+            |parser, compiler, lexer| {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::For, span) => span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::For),
+                            found: Box::new(token),
+                            reason: "expected for statement".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                // Parse out binding
+                let (binding, binding_span) =
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Ident(ident), span) => {
+                            (compiler.symbols.get_or_intern(ident), span)
+                        }
+                        (token, span) => {
+                            return Err(ParseError::ExpectedIdentifier {
+                                found: Box::new(token),
+                                reason: "expected identifier for for loop".to_string(),
+                                span,
+                            });
+                        }
+                    };
+
+                // Parse out `in`
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::In, _) => (),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::In),
+                            found: Box::new(token),
+                            reason: "expected in following for loop binding".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                // Parse out the range
+                let start = parser.parse(compiler, lexer, Precedence::Lowest)?;
+
+                let inclusive = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::DoubleDot, _) => false,
+                    (Token::DoubleDotEq, _) => true,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::DoubleDot),
+                            found: Box::new(token),
+                            reason: "expected range in for loop".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                let end = parser.parse(compiler, lexer, Precedence::Lowest)?;
+
+                // Parse out the block
+                let body = match parser.parse::<Expression<UntypedAstMetadata>, _>(
+                    compiler,
+                    lexer,
+                    Precedence::Lowest,
+                )? {
+                    Expression::<UntypedAstMetadata>::Block(block) => block,
+                    expression => {
+                        return Err(ParseError::ExpectedBlock {
+                            span: expression.span().clone(),
+                        });
+                    }
+                };
+
+                Ok(Expression::For(For {
+                    label: None,
+                    span: start_span.start..body.span.end,
+                    binding,
+                    binding_span,
+                    start: Box::new(start),
+                    end: Box::new(end),
+                    inclusive,
+                    body,
+                    ty_info: None,
+                }))
+            }
+        ));
+    }
+}
+
+impl SolveType for For<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        // An unsuffixed literal bound takes on the type of the other bound, such as in `0..10u8`
+        let (mut start, mut end) = (*self.start, *self.end);
+        let (start, end) = if start.is_unsuffixed_literal() {
+            let end = end.solve(compiler, state)?;
+            start.expect_ty(&end.get_ty_info().ty);
+
+            (start.solve(compiler, state)?, end)
+        } else {
+            let start = start.solve(compiler, state)?;
+            end.expect_ty(&start.get_ty_info().ty);
+
+            (start, end.solve(compiler, state)?)
+        };
+
+        // Both ends of the range must be the same integer type
+        let range_ty = TyInfo::try_from((
+            [start.get_ty_info().ty.clone(), end.get_ty_info().ty.clone()],
+            [
+                start.get_ty_info().return_ty.clone(),
+                end.get_ty_info().return_ty.clone(),
+            ],
+        ))
+        .map_err(|e| e.at(compiler, end.span().clone()))?;
+
+        if !range_ty.ty.is_integer() {
+            return Err(TyError::Mismatch {
+                expected: Ty::Int.name(compiler),
+                found: range_ty.ty.name(compiler),
+                span: start.span().clone(),
+                expected_span: None,
+            });
+        }
+
+        // The binding is only available within the loop
+        state.enter();
+        let binding = state.register(self.binding, range_ty.ty.clone(), self.binding_span.clone());
+        state.enter_loop(self.label);
+        let body = self.body.solve(compiler, state);
+        let breaks = state.leave_loop();
+        state.leave();
+        let body = body?;
+
+        // The loop may finish without a break, so breaks cannot produce a value
+        if let Some((found, span)) = breaks.into_iter().find(|(ty, _)| !ty.check(&Ty::Unit)) {
+            return Err(TyError::Mismatch {
+                expected: Ty::Unit.name(compiler),
+                found: found.name(compiler),
+                span,
+                expected_span: None,
+            });
+        }
+
+        match body.ty_info.ty {
+            Ty::Unit | Ty::Never => (),
+            ref ty => {
+                return Err(TyError::Mismatch {
+                    expected: Ty::Unit.name(compiler),
+                    found: ty.name(compiler),
+                    span: body.span,
+                    expected_span: None,
+                });
+            }
+        };
+
+        // The range may be empty, so the loop is always unit
+        let ty_info = TyInfo::try_from((
+            Ty::Unit,
+            [range_ty.return_ty, body.ty_info.return_ty.clone()],
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(For {
+            label: self.label,
+            ty_info,
+            binding,
+            binding_span: self.binding_span,
+            start: Box::new(start),
+            end: Box::new(end),
+            inclusive: self.inclusive,
+            body,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            For::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            ExpressionStatement::<UntypedAstMetadata>::register(&mut parser);
+            Block::<UntypedAstMetadata>::register(&mut parser);
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+            Infix::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::exclusive("for i in 0..10 { }", false)]
+        #[case::inclusive("for i in 0..=10 { }", true)]
+        #[case::expression_bounds("for i in 1 + 1..n * 2 { i; }", false)]
+        #[case::nested_for("for i in 0..n { for j in i..n { 1; } }", false)]
+        fn success(parser: Parser, #[case] source: &str, #[case] inclusive: bool) {
+            let f: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::For(f) = f else {
+                panic!("expected to parse for loop");
+            };
+
+            assert_eq!(f.inclusive, inclusive);
+        }
+
+        #[rstest]
+        #[case::missing_binding("for in 0..10 { }")]
+        #[case::missing_in("for i 0..10 { }")]
+        #[case::missing_range("for i in 10 { }")]
+        #[case::missing_end("for i in 0.. { }")]
+        #[case::missing_block("for i in 0..10")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn for_loop(
+            start: Expression<UntypedAstMetadata>,
+            end: Expression<UntypedAstMetadata>,
+        ) -> For<UntypedAstMetadata> {
+            For::new(
+                None,
+                Compiler::default().symbols.get_or_intern("i"),
+                Span::default(),
+                Box::new(start),
+                Box::new(end),
+                false,
+                Block::new(vec![], Span::default(), None),
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        fn integer_range() {
+            let f = for_loop(
+                Expression::integer(0, Span::default()),
+                Expression::integer(10, Span::default()),
+            )
+            .solve(&mut Compiler::default(), &mut Scope::new())
+            .unwrap();
+
+            assert_eq!(f.ty_info.ty, Ty::Unit);
+        }
+
+        #[rstest]
+        #[case::unsuffixed_start(
+            Expression::integer(0, Span::default()),
+            Expression::Integer(Integer::new(10, Span::default(), Some(Ty::U8))),
+            Ty::U8
+        )]
+        #[case::unsuffixed_end(
+            Expression::Integer(Integer::new(0, Span::default(), Some(Ty::U16))),
+            Expression::integer(10, Span::default()),
+            Ty::U16
+        )]
+        #[case::negative_start(
+            Expression::Prefix(Prefix::new(
+                PrefixOperation::Minus,
+                Box::new(Expression::integer(1, Span::default())),
+                Span::default(),
+                None,
+            )),
+            Expression::Integer(Integer::new(10, Span::default(), Some(Ty::I8))),
+            Ty::I8
+        )]
+        fn unsuffixed_bound(
+            #[case] start: Expression<UntypedAstMetadata>,
+            #[case] end: Expression<UntypedAstMetadata>,
+            #[case] expected: Ty,
+        ) {
+            let f = for_loop(start, end)
+                .solve(&mut Compiler::default(), &mut Scope::new())
+                .unwrap();
+
+            assert_eq!(f.start.get_ty_info().ty, expected);
+            assert_eq!(f.end.get_ty_info().ty, expected);
+        }
+
+        #[rstest]
+        #[case::boolean_range(
+            Expression::boolean(true, Span::default()),
+            Expression::boolean(false, Span::default())
+        )]
+        #[case::mismatched_range(
+            Expression::Integer(Integer::new(0, Span::default(), Some(Ty::I8))),
+            Expression::Integer(Integer::new(10, Span::default(), Some(Ty::U8)))
+        )]
+        fn fail(
+            #[case] start: Expression<UntypedAstMetadata>,
+            #[case] end: Expression<UntypedAstMetadata>,
+        ) {
+            assert!(for_loop(start, end)
+                .solve(&mut Compiler::default(), &mut Scope::new())
+                .is_err());
+        }
+    }
+}
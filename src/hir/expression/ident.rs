@@ -14,20 +14,38 @@ impl<M: AstMetadata> Parsable for Ident<M> {
     fn register(parser: &mut Parser) {
         parser.register_prefix_test::<Expression<UntypedAstMetadata>>(
             |token| matches!(token, Token::Ident(_)),
-            |_, compiler, lexer| {
+            |parser, compiler, lexer| {
                 let (value, span) = match lexer.next_spanned().unwrap() {
                     (Token::Ident(value), span) => (value, span),
-                    (token, _) => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::Ident(String::new())),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedIdentifier {
                             found: Box::new(token),
                             reason: "expected ident".to_string(),
+                            span,
                         });
                     }
                 };
 
                 let binding = compiler.symbols.get_or_intern(value);
 
+                // A struct name followed by a brace is the start of a struct literal
+                if let Some(idx) = compiler.types.get_idx(binding) {
+                    if matches!(lexer.peek_token(), Some(Token::LeftBrace)) {
+                        return Ok(Expression::StructLiteral(StructLiteral::parse_fields(
+                            parser, compiler, lexer, idx, span,
+                        )?));
+                    }
+                }
+
+                // An enum name followed by a double colon is the start of a variant
+                if let Some(idx) = compiler.types.get_enum_idx(binding) {
+                    if matches!(lexer.peek_token(), Some(Token::DoubleColon)) {
+                        return Ok(Expression::EnumVariant(EnumVariant::parse_variant(
+                            parser, compiler, lexer, idx, span,
+                        )?));
+                    }
+                }
+
                 Ok(Expression::Ident(Ident {
                     binding,
                     span,
@@ -43,12 +61,16 @@ impl SolveType for Ident<UntypedAstMetadata> {
 
     fn solve(
         self,
-        _compiler: &mut crate::compiler::Compiler,
+        compiler: &mut crate::compiler::Compiler,
         state: &mut Self::State,
     ) -> Result<Self::Typed, crate::ty::TyError> {
         let (binding, ty) = state
             .resolve(self.binding)
-            .ok_or(TyError::SymbolNotFound(self.binding))?;
+            .ok_or_else(|| TyError::SymbolNotFound {
+                name: compiler.symbols.resolve(self.binding).unwrap().to_string(),
+                span: self.span.clone(),
+            })?;
+        state.mark_used(&binding);
 
         Ok(Ident {
             ty_info: TyInfo {
@@ -126,7 +148,7 @@ mod test_ident {
 
             // Create a scope and add the symbol to it
             let mut scope = Scope::new();
-            scope.register(symbol, Ty::Int);
+            scope.register(symbol, Ty::Int, Span::default());
 
             let i = Ident::new(symbol, Span::default(), Default::default());
 
@@ -142,15 +164,19 @@ mod test_ident {
 
         #[test]
         fn ident_infer_missing() {
+            let mut compiler = Compiler::default();
             let i = Ident::new(
-                Symbol::try_from_usize(0).unwrap(),
+                compiler.symbols.get_or_intern("a"),
                 Span::default(),
                 Default::default(),
             );
 
-            let result = i.solve(&mut Compiler::default(), &mut Scope::new());
+            let result = i.solve(&mut compiler, &mut Scope::new());
 
-            assert!(result.is_err());
+            assert!(matches!(
+                result,
+                Err(TyError::SymbolNotFound { name, .. }) if name == "a"
+            ));
         }
     }
 }
@@ -14,13 +14,16 @@ impl<M: AstMetadata> Parsable for If<M> {
     fn register(parser: &mut Parser) {
         assert!(
             parser.register_prefix(Token::If, |parser, compiler, lexer| {
-                let span_start = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let span_start = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::If, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::If),
                             found: Box::new(token),
                             reason: "expected if statement".to_string(),
+                            span,
                         });
                     }
                 };
@@ -29,20 +32,26 @@ impl<M: AstMetadata> Parsable for If<M> {
                 let condition = parser.parse(compiler, lexer, Precedence::Lowest)?;
 
                 // Parse out success block
-                let Expression::<UntypedAstMetadata>::Block(success) =
-                    parser.parse(compiler, lexer, Precedence::Lowest)?
-                else {
-                    return Err(ParseError::ExpectedBlock);
+                let success = match parser.parse(compiler, lexer, Precedence::Lowest)? {
+                    Expression::<UntypedAstMetadata>::Block(block) => block,
+                    expression => {
+                        return Err(ParseError::ExpectedBlock {
+                            span: expression.span().clone(),
+                        });
+                    }
                 };
 
                 let otherwise = matches!(lexer.peek_token(), Some(Token::Else))
                     .then(|| {
                         lexer.next_token().unwrap();
 
-                        let Expression::<UntypedAstMetadata>::Block(otherwise) =
-                            parser.parse(compiler, lexer, Precedence::Lowest)?
-                        else {
-                            return Err(ParseError::ExpectedBlock);
+                        let otherwise = match parser.parse(compiler, lexer, Precedence::Lowest)? {
+                            Expression::<UntypedAstMetadata>::Block(block) => block,
+                            expression => {
+                                return Err(ParseError::ExpectedBlock {
+                                    span: expression.span().clone(),
+                                });
+                            }
                         };
 
                         Ok(otherwise)
@@ -78,7 +87,12 @@ impl SolveType for If<UntypedAstMetadata> {
         let condition = self.condition.solve(compiler, state)?;
         let condition_ty = condition.get_ty_info();
         if !condition_ty.ty.check(&Ty::Boolean) {
-            return Err(TyError::Mismatch(Ty::Boolean, condition_ty.ty.clone()));
+            return Err(TyError::Mismatch {
+                expected: Ty::Boolean.name(compiler),
+                found: condition_ty.ty.name(compiler),
+                span: condition.span().clone(),
+                expected_span: None,
+            });
         }
 
         let success = self.success.solve(compiler, state)?;
@@ -104,7 +118,8 @@ impl SolveType for If<UntypedAstMetadata> {
                     .as_ref()
                     .and_then(|otherwise| otherwise.ty_info.return_ty.clone()),
             ],
-        ))?;
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
 
         Ok(If {
             ty_info,
@@ -1,8 +1,10 @@
+use crate::repr::ast::typed::TypedAstMetadata;
+
 use super::*;
 
 ast_node! {
     Index<M> {
-        value: M::IdentIdentifier,
+        value: Box<Expression<M>>,
         index: Box<Expression<M>>,
         span,
         ty_info,
@@ -11,27 +13,20 @@ ast_node! {
 
 impl<M: AstMetadata> Parsable for Index<M> {
     fn register(parser: &mut Parser) {
-        assert!(
-            parser.register_infix(Token::LeftSquare, |parser, compiler, lexer, left| {
-                // Ensure that left is an ident
-                let (binding, binding_span) = match left {
-                    Expression::Ident(Ident { binding, span, .. }) => (binding, span),
-                    lhs => {
-                        return Err(ParseError::InvalidInfixLhs {
-                            found: Box::new(lhs),
-                            reason: "index must start with an ident".to_string(),
-                        });
-                    }
-                };
-
+        assert!(parser.register_infix::<Expression<UntypedAstMetadata>>(
+            Token::LeftSquare,
+            |parser, compiler, lexer, left| {
                 // Parse out opening bracket
-                match lexer.next_token().ok_or(ParseError::UnexpectedEOF)? {
-                    Token::LeftSquare => (),
-                    token => {
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::LeftSquare, _) => (),
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::LeftSquare),
                             found: Box::new(token),
                             reason: "expected index operation".to_string(),
+                            span,
                         })
                     }
                 }
@@ -40,25 +35,28 @@ impl<M: AstMetadata> Parsable for Index<M> {
                 let index = parser.parse(compiler, lexer, Precedence::Lowest)?;
 
                 // Parse closing bracket
-                let closing_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let closing_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::RightSquare, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::RightSquare),
                             found: Box::new(token),
                             reason: "expected closing bracket for index".to_string(),
+                            span,
                         })
                     }
                 };
 
                 Ok(Expression::Index(Index {
-                    value: binding,
+                    span: left.span().start..closing_span.end,
+                    value: Box::new(left),
                     index: Box::new(index),
-                    span: binding_span.start..closing_span.end,
                     ty_info: None,
                 }))
-            })
-        );
+            }
+        ));
     }
 }
 
@@ -72,39 +70,87 @@ impl SolveType for Index<UntypedAstMetadata> {
     ) -> Result<Self::Typed, crate::ty::TyError> {
         // Ensure the inner parts are correct
         let index = self.index.solve(compiler, state)?;
-
-        // Ensure that the index can be used as an index
-        let index_ty = index.get_ty_info().ty.clone();
-        if index_ty != Ty::Int {
-            return Err(TyError::Mismatch(index_ty, Ty::Int));
-        }
-
-        let (value, ty) = state
-            .resolve(self.value)
-            .ok_or(TyError::SymbolNotFound(self.value))?;
+        let value = self.value.solve(compiler, state)?;
 
         // Ensure the value is indexable
         let result_ty = if let Ty::Array {
-            inner: inner_ty, ..
-        } = ty
+            inner: inner_ty,
+            size,
+        } = &value.get_ty_info().ty
         {
-            *inner_ty
+            check_index(compiler, &index, *size)?;
+
+            *inner_ty.clone()
         } else {
-            return Err(TyError::Index(ty));
+            return Err(TyError::Index {
+                ty: value.get_ty_info().ty.name(compiler),
+                span: self.span,
+            });
         };
 
+        let ty_info = TyInfo::try_from((
+            result_ty,
+            [
+                value.get_ty_info().return_ty.clone(),
+                index.get_ty_info().return_ty.clone(),
+            ],
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
         Ok(Index {
-            value,
+            value: Box::new(value),
             span: self.span,
-            ty_info: TyInfo {
-                ty: result_ty,
-                return_ty: index.get_ty_info().return_ty.clone(),
-            },
+            ty_info,
             index: Box::new(index),
         })
     }
 }
 
+/// Ensure that an expression can be used to index into an array of the provided length.
+pub fn check_index(
+    compiler: &Compiler,
+    index: &Expression<TypedAstMetadata>,
+    length: u32,
+) -> Result<(), TyError> {
+    let index_ty = &index.get_ty_info().ty;
+    if index_ty != &Ty::Int {
+        return Err(TyMismatch {
+            expected: Ty::Int,
+            found: index_ty.clone(),
+        }
+        .at(compiler, index.span().clone()));
+    }
+
+    check_constant_index(index, length)
+}
+
+/// Ensure that an index which is known at compile time lies within an array of the provided
+/// length. Indexes that can only be determined at run time are left for the bounds check.
+fn check_constant_index(index: &Expression<TypedAstMetadata>, length: u32) -> Result<(), TyError> {
+    let value = match index {
+        Expression::Integer(Integer { value, .. }) => *value as i64,
+        Expression::Prefix(Prefix {
+            operation: PrefixOperation::Minus,
+            right,
+            ..
+        }) => match right.as_ref() {
+            Expression::Integer(Integer { value, .. }) => (*value as i64).wrapping_neg(),
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    if value < 0 || value >= length as i64 {
+        return Err(TyError::IndexOutOfBounds {
+            index: value,
+            length,
+            span: index.span().clone(),
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -121,6 +167,8 @@ mod test {
             Index::<UntypedAstMetadata>::register(&mut parser);
 
             // Register helper parsers
+            Call::<UntypedAstMetadata>::register(&mut parser);
+            Field::<UntypedAstMetadata>::register(&mut parser);
             Integer::<UntypedAstMetadata>::register(&mut parser);
             Ident::<UntypedAstMetadata>::register(&mut parser);
 
@@ -150,10 +198,34 @@ mod test {
             assert!(index_test(*index.index));
         }
 
+        #[rstest]
+        #[case::ident("a[1]", |e| matches!(e, Expression::Ident(_)))]
+        #[case::chained("a[1][2]", |e| matches!(e, Expression::Index(_)))]
+        #[case::call("f()[1]", |e| matches!(e, Expression::Call(_)))]
+        #[case::field("a.b[1]", |e| matches!(e, Expression::Field(_)))]
+        fn value(
+            parser: Parser,
+            #[case] source: &str,
+            #[case] value_test: fn(Expression<UntypedAstMetadata>) -> bool,
+        ) {
+            let index = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Index(index) = index else {
+                panic!("expected to parse index");
+            };
+
+            assert!(value_test(*index.value));
+        }
+
         #[rstest]
         #[case::missing_closing_bracket("a[1")]
         #[case::missing_index("a[]")]
-        #[case::non_ident_lhs("1[3]")]
         fn fail(parser: Parser, #[case] source: &str) {
             assert!(parser
                 .parse::<Expression<UntypedAstMetadata>, _>(
@@ -164,4 +236,126 @@ mod test {
                 .is_err());
         }
     }
+
+    mod ty {
+        use super::*;
+
+        fn index(compiler: &mut Compiler, value_ty: Ty) -> (Scope, Index<UntypedAstMetadata>) {
+            let value = compiler.symbols.get_or_intern("value");
+
+            let mut scope = Scope::new();
+            scope.register(value, value_ty, Span::default());
+
+            (
+                scope,
+                Index::new(
+                    Box::new(Expression::ident(value, Span::default())),
+                    Box::new(Expression::integer(0, Span::default())),
+                    Span::default(),
+                    None,
+                ),
+            )
+        }
+
+        #[rstest]
+        #[case::int(Ty::Int)]
+        #[case::boolean(Ty::Boolean)]
+        #[case::nested(Ty::Array { inner: Box::new(Ty::Int), size: 2 })]
+        fn element(#[case] inner: Ty) {
+            let mut compiler = Compiler::default();
+
+            let (mut scope, index) = index(
+                &mut compiler,
+                Ty::Array {
+                    inner: Box::new(inner.clone()),
+                    size: 3,
+                },
+            );
+
+            assert_eq!(
+                index.solve(&mut compiler, &mut scope).unwrap().ty_info.ty,
+                inner
+            );
+        }
+
+        #[rstest]
+        #[case::past_end(3)]
+        #[case::far_past_end(10)]
+        fn out_of_bounds(#[case] i: u64) {
+            let mut compiler = Compiler::default();
+
+            let (mut scope, mut index) = index(
+                &mut compiler,
+                Ty::Array {
+                    inner: Box::new(Ty::Int),
+                    size: 3,
+                },
+            );
+            index.index = Box::new(Expression::integer(i, Span::default()));
+
+            assert!(matches!(
+                index.solve(&mut compiler, &mut scope),
+                Err(TyError::IndexOutOfBounds { index, length: 3, .. }) if index == i as i64
+            ));
+        }
+
+        #[rstest]
+        fn negative() {
+            let mut compiler = Compiler::default();
+
+            let (mut scope, mut index) = index(
+                &mut compiler,
+                Ty::Array {
+                    inner: Box::new(Ty::Int),
+                    size: 3,
+                },
+            );
+            index.index = Box::new(Expression::Prefix(Prefix::new(
+                PrefixOperation::Minus,
+                Box::new(Expression::integer(1, Span::default())),
+                Span::default(),
+                None,
+            )));
+
+            assert!(matches!(
+                index.solve(&mut compiler, &mut scope),
+                Err(TyError::IndexOutOfBounds { index: -1, .. })
+            ));
+        }
+
+        #[rstest]
+        fn non_integer_index() {
+            let mut compiler = Compiler::default();
+
+            let (mut scope, mut index) = index(
+                &mut compiler,
+                Ty::Array {
+                    inner: Box::new(Ty::Int),
+                    size: 3,
+                },
+            );
+            index.index = Box::new(Expression::boolean(true, Span::default()));
+
+            assert!(matches!(
+                index.solve(&mut compiler, &mut scope),
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "int" && found == "bool"
+            ));
+        }
+
+        #[rstest]
+        fn not_an_array() {
+            let mut compiler = Compiler::default();
+
+            let (mut scope, index) = index(&mut compiler, Ty::Int);
+
+            assert!(matches!(
+                index.solve(&mut compiler, &mut scope),
+                Err(TyError::Index { ty, .. }) if ty == "int"
+            ));
+        }
+    }
 }
@@ -6,6 +6,12 @@ pub enum InfixOperation {
     Plus,
     Multiply,
     Divide,
+    Modulo,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
     Eq,
     NotEq,
     Greater,
@@ -26,18 +32,30 @@ impl InfixOperation {
     }
 
     /// Determine the resulting type if this operator is applied to the provided parameters.
-    pub fn result_ty(&self, left: &Ty, right: &Ty) -> Result<Ty, TyError> {
+    pub fn result_ty(&self, left: &Ty, right: &Ty) -> Result<Ty, TyMismatch> {
         use InfixOperation::*;
 
         match (self, left, right) {
-            (Plus | Minus | Multiply | Divide, Ty::Int, Ty::Int) => Ok(Ty::Int),
+            (Plus | Minus | Multiply | Divide | Modulo, left, right)
+                if left.is_numeric() && left == right =>
+            {
+                Ok(left.clone())
+            }
+            (BitAnd | BitOr | BitXor | ShiftLeft | ShiftRight, left, right)
+                if left.is_integer() && left == right =>
+            {
+                Ok(left.clone())
+            }
             (Eq | NotEq | Greater | Less | GreaterEq | LessEq, left, right)
-                if left.check(right) =>
+                if left.check(right) && !left.is_aggregate() =>
             {
                 Ok(Ty::Boolean)
             }
             (And | Or, Ty::Boolean, Ty::Boolean) => Ok(Ty::Boolean),
-            (_, left, right) => Err(TyError::Mismatch(left.clone(), right.clone())),
+            (_, left, right) => Err(TyMismatch {
+                expected: left.clone(),
+                found: right.clone(),
+            }),
         }
     }
 }
@@ -51,6 +69,12 @@ impl TryFrom<Token> for InfixOperation {
             Token::Minus => Ok(InfixOperation::Minus),
             Token::Asterix => Ok(InfixOperation::Multiply),
             Token::ForwardSlash => Ok(InfixOperation::Divide),
+            Token::Percent => Ok(InfixOperation::Modulo),
+            Token::Ampersand => Ok(InfixOperation::BitAnd),
+            Token::Pipe => Ok(InfixOperation::BitOr),
+            Token::Caret => Ok(InfixOperation::BitXor),
+            Token::DoubleLeftAngle => Ok(InfixOperation::ShiftLeft),
+            Token::DoubleRightAngle => Ok(InfixOperation::ShiftRight),
             Token::DoubleEq => Ok(InfixOperation::Eq),
             Token::NotEq => Ok(InfixOperation::NotEq),
             Token::LeftAngle => Ok(InfixOperation::Less),
@@ -83,14 +107,17 @@ impl<M: AstMetadata> Parsable for Infix<M> {
             left: Expression<UntypedAstMetadata>,
         ) -> Result<Expression<UntypedAstMetadata>, ParseError> {
             // Work out what the operation is
+            let (token, span) = lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })?;
             let operation =
-                InfixOperation::try_from(lexer.next_token().ok_or(ParseError::UnexpectedEOF)?)
-                    .map_err(|token| ParseError::ExpectedToken {
-                        // WARN: Should be any of the infix tokens
-                        expected: Box::new(Token::Plus),
-                        found: Box::new(token),
-                        reason: "expected to parse valid infix operation".to_string(),
-                    })?;
+                InfixOperation::try_from(token).map_err(|token| ParseError::ExpectedToken {
+                    // WARN: Should be any of the infix tokens
+                    expected: Box::new(Token::Plus),
+                    found: Box::new(token),
+                    reason: "expected to parse valid infix operation".to_string(),
+                    span,
+                })?;
 
             // Parse the right side with associated precedence
             let right: Expression<UntypedAstMetadata> =
@@ -111,6 +138,12 @@ impl<M: AstMetadata> Parsable for Infix<M> {
             Token::Minus,
             Token::Asterix,
             Token::ForwardSlash,
+            Token::Percent,
+            Token::Ampersand,
+            Token::Pipe,
+            Token::Caret,
+            Token::DoubleLeftAngle,
+            Token::DoubleRightAngle,
             Token::DoubleEq,
             Token::NotEq,
             Token::LeftAngle,
@@ -135,8 +168,19 @@ impl SolveType for Infix<UntypedAstMetadata> {
         compiler: &mut crate::compiler::Compiler,
         state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
-        let left = self.left.solve(compiler, state)?;
-        let right = self.right.solve(compiler, state)?;
+        // An unsuffixed literal operand takes on the type of the other operand, such as in `a + 1`
+        let (mut left, mut right) = (*self.left, *self.right);
+        let (left, right) = if left.is_unsuffixed_literal() {
+            let right = right.solve(compiler, state)?;
+            left.expect_ty(&right.get_ty_info().ty);
+
+            (left.solve(compiler, state)?, right)
+        } else {
+            let left = left.solve(compiler, state)?;
+            right.expect_ty(&left.get_ty_info().ty);
+
+            (left, right.solve(compiler, state)?)
+        };
 
         let left_ty_info = left.get_ty_info();
         let right_ty_info = right.get_ty_info();
@@ -144,12 +188,14 @@ impl SolveType for Infix<UntypedAstMetadata> {
         let ty_info = TyInfo::try_from((
             // Resulting type is whatever the infix operator results in
             self.operation
-                .result_ty(&left_ty_info.ty, &right_ty_info.ty)?,
+                .result_ty(&left_ty_info.ty, &right_ty_info.ty)
+                .map_err(|e| e.at(compiler, self.span.clone()))?,
             [
                 left_ty_info.return_ty.clone(),
                 right_ty_info.return_ty.clone(),
             ],
-        ))?;
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
 
         Ok(Infix {
             left: Box::new(left),
@@ -186,6 +232,12 @@ mod test_infix {
         #[case::addition("1 + 1", InfixOperation::Plus)]
         #[case::multiplication("1 * 1", InfixOperation::Multiply)]
         #[case::division("1 / 1", InfixOperation::Divide)]
+        #[case::modulo("1 % 1", InfixOperation::Modulo)]
+        #[case::bit_and("1 & 1", InfixOperation::BitAnd)]
+        #[case::bit_or("1 | 1", InfixOperation::BitOr)]
+        #[case::bit_xor("1 ^ 1", InfixOperation::BitXor)]
+        #[case::shift_left("1 << 1", InfixOperation::ShiftLeft)]
+        #[case::shift_right("1 >> 1", InfixOperation::ShiftRight)]
         #[case::equal("1 == 1", InfixOperation::Eq)]
         #[case::not_equal("1 != 1", InfixOperation::NotEq)]
         #[case::greater("1 > 1", InfixOperation::Greater)]
@@ -214,6 +266,9 @@ mod test_infix {
         #[case("1 + 1 + 1", InfixOperation::Plus, |left| matches!(left, Expression::Infix(Infix { operation: InfixOperation::Plus, .. })), |right| matches!(right, Expression::Integer(_)))]
         #[case("1 + 1 * 1", InfixOperation::Plus, |left| matches!(left, Expression::Integer(_)), |right| matches!(right, Expression::Infix(Infix { operation: InfixOperation::Multiply, ..})))]
         #[case("1 + 1 * 1 == 1", InfixOperation::Eq, |left| matches!(left, Expression::Infix(Infix { operation: InfixOperation::Plus, .. })), |right| matches!(right, Expression::Integer(_)))]
+        #[case("1 | 1 & 1", InfixOperation::BitOr, |left| matches!(left, Expression::Integer(_)), |right| matches!(right, Expression::Infix(Infix { operation: InfixOperation::BitAnd, .. })))]
+        #[case("1 << 1 + 1", InfixOperation::ShiftLeft, |left| matches!(left, Expression::Integer(_)), |right| matches!(right, Expression::Infix(Infix { operation: InfixOperation::Plus, .. })))]
+        #[case("1 & 1 == 1", InfixOperation::Eq, |left| matches!(left, Expression::Infix(Infix { operation: InfixOperation::BitAnd, .. })), |right| matches!(right, Expression::Integer(_)))]
         #[case("1 + 1 * 1 == 1 + 1", InfixOperation::Eq,|left| matches!(left, Expression::Infix(Infix { operation: InfixOperation::Plus, .. })), |right| matches!(right, Expression::Infix(Infix { operation: InfixOperation::Plus, .. })))]
         fn precedence(
             parser: Parser,
@@ -262,6 +317,67 @@ mod test_infix {
             assert_eq!(ty_info.ty, Ty::Int);
             assert_eq!(ty_info.return_ty, None);
         }
+        #[rstest]
+        #[case::int(InfixOperation::Plus, Ty::Int, Ty::Int)]
+        #[case::uint(InfixOperation::Plus, Ty::Uint, Ty::Uint)]
+        #[case::uint_divide(InfixOperation::Divide, Ty::Uint, Ty::Uint)]
+        #[case::uint_shift(InfixOperation::ShiftRight, Ty::Uint, Ty::Uint)]
+        #[case::uint_compare(InfixOperation::Less, Ty::Uint, Ty::Boolean)]
+        #[case::sized(InfixOperation::Multiply, Ty::U8, Ty::U8)]
+        #[case::float(InfixOperation::Divide, Ty::F32, Ty::F32)]
+        #[case::float_compare(InfixOperation::GreaterEq, Ty::F64, Ty::Boolean)]
+        fn result_ty(#[case] operation: InfixOperation, #[case] operand: Ty, #[case] result: Ty) {
+            assert_eq!(operation.result_ty(&operand, &operand).unwrap(), result);
+        }
+
+        #[rstest]
+        #[case::mixed_sign(InfixOperation::Plus, Ty::Int, Ty::Uint)]
+        #[case::boolean(InfixOperation::Plus, Ty::Boolean, Ty::Boolean)]
+        #[case::mixed_size(InfixOperation::Plus, Ty::I32, Ty::Int)]
+        #[case::float_shift(InfixOperation::ShiftLeft, Ty::F64, Ty::F64)]
+        #[case::struct_eq(
+            InfixOperation::Eq,
+            Ty::Struct(StructIdx::new(0)),
+            Ty::Struct(StructIdx::new(0))
+        )]
+        fn result_ty_fail(#[case] operation: InfixOperation, #[case] left: Ty, #[case] right: Ty) {
+            assert!(operation.result_ty(&left, &right).is_err());
+        }
+
+        #[rstest]
+        #[case::right(InfixOperation::plus(), false, Ty::U8)]
+        #[case::left(InfixOperation::plus(), true, Ty::U8)]
+        #[case::shift(InfixOperation::ShiftLeft, false, Ty::U8)]
+        #[case::compare(InfixOperation::Less, true, Ty::Boolean)]
+        fn infix_unsuffixed(
+            #[case] operation: InfixOperation,
+            #[case] literal_left: bool,
+            #[case] ty: Ty,
+        ) {
+            // 1u8 + 1 or 1 + 1u8
+            let suffixed = Expression::Integer(Integer::new(1, Span::default(), Some(Ty::U8)));
+            let unsuffixed = Expression::integer(1, Span::default());
+            let (left, right) = if literal_left {
+                (unsuffixed, suffixed)
+            } else {
+                (suffixed, unsuffixed)
+            };
+
+            let infix = Infix::new(
+                Box::new(left),
+                operation,
+                Box::new(right),
+                Span::default(),
+                Default::default(),
+            )
+            .solve(&mut Compiler::default(), &mut Scope::new())
+            .unwrap();
+
+            assert_eq!(infix.ty_info.ty, ty);
+            assert_eq!(infix.left.get_ty_info().ty, Ty::U8);
+            assert_eq!(infix.right.get_ty_info().ty, Ty::U8);
+        }
+
         #[test]
         fn infix_different() {
             // 0 + false
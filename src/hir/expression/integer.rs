@@ -1,8 +1,10 @@
+use crate::{repr::ast::typed::TypedAstMetadata, ty::TySpanned};
+
 use super::*;
 
 ast_node! {
     Integer<M> {
-        value: i64,
+        value: u64,
         span,
         ty_info,
     }
@@ -15,19 +17,40 @@ impl<M: AstMetadata> Parsable for Integer<M> {
             |_, _, lexer| {
                 let (value, span) = match lexer.next_spanned().unwrap() {
                     (Token::Integer(value), span) => (value, span),
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Integer(0)),
                             found: Box::new(token),
                             reason: "expected integer".to_string(),
+                            span,
                         });
                     }
                 };
 
+                // An optional suffix can specify the type of the literal
+                let Some(suffix) = TySpanned::parse_suffix(lexer, span.end) else {
+                    return Ok(Expression::Integer(Integer {
+                        value,
+                        span,
+                        ty_info: None,
+                    }));
+                };
+
+                let span = span.start..suffix.span.end;
+
+                // Integers with a float suffix are actually float literals
+                if suffix.ty.is_float() {
+                    return Ok(Expression::Float(Float {
+                        value: value as f64,
+                        span,
+                        ty_info: Some(suffix.ty),
+                    }));
+                }
+
                 Ok(Expression::Integer(Integer {
                     value,
                     span,
-                    ty_info: None,
+                    ty_info: Some(suffix.ty),
                 }))
             },
         )
@@ -37,12 +60,33 @@ impl<M: AstMetadata> Parsable for Integer<M> {
 impl SolveType for Integer<UntypedAstMetadata> {
     type State = Scope;
 
-    fn solve(self, _compiler: &mut Compiler, _scope: &mut Scope) -> Result<Self::Typed, TyError> {
+    fn solve(self, compiler: &mut Compiler, _scope: &mut Scope) -> Result<Self::Typed, TyError> {
+        self.solve_literal(compiler, false)
+    }
+}
+
+impl Integer<UntypedAstMetadata> {
+    /// Type check the literal, where a `negated` literal is the operand of a prefix `-`.
+    pub fn solve_literal(
+        self,
+        compiler: &Compiler,
+        negated: bool,
+    ) -> Result<Integer<TypedAstMetadata>, TyError> {
+        // Literals without a suffix default to a regular integer
+        let ty = self.ty_info.unwrap_or(Ty::Int);
+
+        if !ty.fits_literal(self.value, negated) {
+            return Err(TyError::LiteralOutOfRange {
+                ty: ty.name(compiler),
+                span: self.span,
+            });
+        }
+
         Ok(Integer {
             value: self.value,
             span: self.span,
             ty_info: TyInfo {
-                ty: Ty::Int,
+                ty,
                 return_ty: None,
             },
         })
@@ -63,7 +107,8 @@ mod test_integer {
         #[rstest]
         #[case::single_digit(1)]
         #[case::multi_digit(123)]
-        fn success(#[case] value: i64) {
+        #[case::max(u64::MAX)]
+        fn success(#[case] value: u64) {
             let mut parser = Parser::new();
 
             Integer::<UntypedAstMetadata>::register(&mut parser);
@@ -82,6 +127,46 @@ mod test_integer {
 
             assert_eq!(integer.value, value);
         }
+
+        #[rstest]
+        #[case::unsuffixed("10", |e: &Expression<UntypedAstMetadata>| matches!(e, Expression::Integer(Integer { ty_info: None, .. })))]
+        #[case::signed("10i8", |e: &Expression<UntypedAstMetadata>| matches!(e, Expression::Integer(Integer { ty_info: Some(Ty::I8), .. })))]
+        #[case::unsigned("10u32", |e: &Expression<UntypedAstMetadata>| matches!(e, Expression::Integer(Integer { ty_info: Some(Ty::U32), .. })))]
+        #[case::float("10f32", |e: &Expression<UntypedAstMetadata>| matches!(e, Expression::Float(Float { ty_info: Some(Ty::F32), .. })))]
+        fn suffix(#[case] source: &str, #[case] test: fn(&Expression<UntypedAstMetadata>) -> bool) {
+            let mut parser = Parser::new();
+
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            let integer: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            assert!(test(&integer));
+            assert_eq!(integer.span(), &(0..source.len()));
+        }
+
+        #[test]
+        fn separated_suffix() {
+            let mut parser = Parser::new();
+
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            let mut lexer = Lexer::from("10 u8");
+            let integer: Expression<UntypedAstMetadata> = parser
+                .parse(&mut Compiler::default(), &mut lexer, Precedence::Lowest)
+                .unwrap();
+
+            assert!(matches!(
+                integer,
+                Expression::Integer(Integer { ty_info: None, .. })
+            ));
+            assert_eq!(lexer.next_token(), Some(Token::U8));
+        }
     }
 
     mod ty {
@@ -98,6 +183,36 @@ mod test_integer {
             );
         }
 
+        #[rstest]
+        #[case::u8_max(255, Ty::U8)]
+        #[case::i8_max(127, Ty::I8)]
+        #[case::u32(4_000_000_000, Ty::U32)]
+        #[case::uint_max(u64::MAX, Ty::Uint)]
+        fn integer_suffix(#[case] value: u64, #[case] ty: Ty) {
+            assert_eq!(
+                Integer::new(value, Span::default(), Some(ty.clone()))
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                ty
+            );
+        }
+
+        #[rstest]
+        #[case::u8(256, Ty::U8)]
+        #[case::i8(129, Ty::I8)]
+        #[case::i8_min_magnitude(128, Ty::I8)]
+        #[case::i16(40_000, Ty::I16)]
+        #[case::int(9_223_372_036_854_775_808, Ty::Int)]
+        fn integer_out_of_range(#[case] value: u64, #[case] ty: Ty) {
+            assert!(matches!(
+                Integer::new(value, Span::default(), Some(ty))
+                    .solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::LiteralOutOfRange { .. })
+            ));
+        }
+
         #[test]
         fn integer_return() {
             assert_eq!(
@@ -2,6 +2,7 @@ use super::*;
 
 ast_node! {
     Loop<M> {
+        label: Option<Symbol>,
         body: Block<M>,
         span,
         ty_info,
@@ -13,34 +14,113 @@ impl<M: AstMetadata> Parsable for Loop<M> {
         assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
             Token::Loop,
             |parser, compiler, lexer| {
-                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::Loop, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Loop),
                             found: Box::new(token),
                             reason: "expected loop statement".to_string(),
+                            span,
                         });
                     }
                 };
 
                 // Parse out the block
-                let Expression::Block(body) = parser.parse::<Expression<UntypedAstMetadata>, _>(
+                let body = match parser.parse::<Expression<UntypedAstMetadata>, _>(
                     compiler,
                     lexer,
                     Precedence::Lowest,
-                )?
-                else {
-                    return Err(ParseError::ExpectedBlock);
+                )? {
+                    Expression::<UntypedAstMetadata>::Block(block) => block,
+                    expression => {
+                        return Err(ParseError::ExpectedBlock {
+                            span: expression.span().clone(),
+                        });
+                    }
                 };
 
                 Ok(Expression::Loop(Loop {
+                    label: None,
                     span: start_span.start..body.span.end,
                     body,
                     ty_info: None,
                 }))
             }
         ));
+
+        // Any of the loops may be preceded by a label
+        parser.register_prefix_test::<Expression<UntypedAstMetadata>>(
+            |token| matches!(token, Token::Label(_)),
+            |parser, compiler, lexer| {
+                let (label, start_span) =
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Label(label), span) => {
+                            (compiler.symbols.get_or_intern(label), span)
+                        }
+                        (token, span) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::Label(String::new())),
+                                found: Box::new(token),
+                                reason: "expected loop label".to_string(),
+                                span,
+                            });
+                        }
+                    };
+
+                // Parse out the colon
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Colon, _) => (),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Colon),
+                            found: Box::new(token),
+                            reason: "expected colon following loop label".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                // Only a loop can follow the label
+                match lexer.peek_spanned() {
+                    Some((Token::Loop | Token::While | Token::For, _)) => (),
+                    Some((_, span)) => {
+                        return Err(ParseError::ExpectedLoop { span: span.clone() });
+                    }
+                    None => {
+                        return Err(ParseError::UnexpectedEOF {
+                            span: lexer.eof_span(),
+                        });
+                    }
+                }
+
+                // Parse only the loop itself, leaving any operators for the enclosing expression
+                let mut expression = parser.parse::<Expression<UntypedAstMetadata>, _>(
+                    compiler,
+                    lexer,
+                    Precedence::Call,
+                )?;
+
+                // Attach the label to the loop
+                let (loop_label, loop_span) = match &mut expression {
+                    Expression::Loop(e) => (&mut e.label, &mut e.span),
+                    Expression::While(e) => (&mut e.label, &mut e.span),
+                    Expression::For(e) => (&mut e.label, &mut e.span),
+                    _ => unreachable!("loop parsers must produce a loop"),
+                };
+
+                *loop_label = Some(label);
+                loop_span.start = start_span.start;
+
+                Ok(expression)
+            },
+        );
     }
 }
 
@@ -52,19 +132,51 @@ impl SolveType for Loop<UntypedAstMetadata> {
         compiler: &mut crate::compiler::Compiler,
         state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
-        // Type check the body
-        let body = self.body.solve(compiler, state)?;
+        // Type check the body, tracking any breaks out of it
+        state.enter_loop(self.label);
+        let body = self.body.solve(compiler, state);
+        let breaks = state.leave_loop();
+        let body = body?;
 
-        // TODO: Temporary whilst can't break expression
+        // The body is repeated, so it cannot produce a value itself
         match body.ty_info.ty {
             Ty::Unit | Ty::Never => (),
-            ty => {
-                return Err(TyError::Mismatch(Ty::Unit, ty));
+            ref ty => {
+                return Err(TyError::Mismatch {
+                    expected: Ty::Unit.name(compiler),
+                    found: ty.name(compiler),
+                    span: body.span,
+                    expected_span: None,
+                });
+            }
+        };
+
+        // Every break must produce the same type, and a loop without any breaks never finishes
+        let mut breaks = breaks
+            .into_iter()
+            .filter(|(ty, _)| !matches!(ty, Ty::Never));
+        let ty = match breaks.next() {
+            Some((expected, expected_span)) => {
+                if let Some((found, span)) = breaks.find(|(ty, _)| *ty != expected) {
+                    return Err(TyError::Mismatch {
+                        expected: expected.name(compiler),
+                        found: found.name(compiler),
+                        span,
+                        expected_span: Some(expected_span),
+                    });
+                }
+
+                expected
             }
+            None => Ty::Never,
         };
 
         Ok(Loop {
-            ty_info: body.ty_info.clone(),
+            label: self.label,
+            ty_info: TyInfo {
+                ty,
+                return_ty: body.ty_info.return_ty.clone(),
+            },
             body,
             span: self.span,
         })
@@ -100,6 +212,7 @@ mod test {
         #[case::terminated_block("loop { 1; }")]
         #[case::unterminated_block("loop { 1 }")]
         #[case::nested_loop("loop { loop { 1; } }")]
+        #[case::labelled("'outer: loop { 1; }")]
         fn success(parser: Parser, #[case] source: &str) {
             let l: Expression<UntypedAstMetadata> = parser
                 .parse(
@@ -115,6 +228,8 @@ mod test {
         #[rstest]
         #[case::missing_block("loop")]
         #[case::expression_without_block("loop 1")]
+        #[case::label_missing_colon("'outer loop { }")]
+        #[case::label_without_loop("'outer: 1")]
         fn fail(parser: Parser, #[case] source: &str) {
             assert!(parser
                 .parse::<Expression<UntypedAstMetadata>, _>(
@@ -124,5 +239,81 @@ mod test {
                 )
                 .is_err());
         }
+
+        #[rstest]
+        #[case::literal("'outer: 1 + 2", 8..9)]
+        #[case::block("'outer: { 1 }", 8..9)]
+        fn label_without_loop(parser: Parser, #[case] source: &str, #[case] span: Span) {
+            let result = parser.parse::<Expression<UntypedAstMetadata>, _>(
+                &mut Compiler::default(),
+                &mut Lexer::from(source),
+                Precedence::Lowest,
+            );
+
+            assert!(matches!(
+                result,
+                Err(ParseError::ExpectedLoop { span: found }) if found == span
+            ));
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn break_with(
+            value: Option<Expression<UntypedAstMetadata>>,
+        ) -> Statement<UntypedAstMetadata> {
+            Statement::Break(Break::new(None, value, Span::default(), None))
+        }
+
+        fn loop_with(statements: Vec<Statement<UntypedAstMetadata>>) -> Loop<UntypedAstMetadata> {
+            Loop::new(
+                None,
+                Block::new(statements, Span::default(), None),
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        #[case::no_break(vec![], Ty::Never)]
+        #[case::unit_break(vec![break_with(None)], Ty::Unit)]
+        #[case::value_break(vec![break_with(Some(Expression::integer(1, Span::default())))], Ty::Int)]
+        #[case::nested_loop_break(
+            vec![
+                Statement::ExpressionStatement(ExpressionStatement::new(
+                    Expression::Loop(loop_with(vec![break_with(Some(Expression::boolean(true, Span::default())))])),
+                    true,
+                    Span::default(),
+                    None,
+                )),
+                break_with(Some(Expression::integer(1, Span::default()))),
+            ],
+            Ty::Int
+        )]
+        fn success(#[case] statements: Vec<Statement<UntypedAstMetadata>>, #[case] ty: Ty) {
+            let l = loop_with(statements)
+                .solve(&mut Compiler::default(), &mut Scope::new())
+                .unwrap();
+
+            assert_eq!(l.ty_info.ty, ty);
+        }
+
+        #[rstest]
+        fn mismatched_breaks() {
+            let l = loop_with(vec![
+                break_with(Some(Expression::integer(1, Span::default()))),
+                break_with(Some(Expression::boolean(true, Span::default()))),
+            ]);
+
+            assert!(matches!(
+                l.solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "int" && found == "bool"
+            ));
+        }
     }
 }
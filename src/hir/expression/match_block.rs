@@ -0,0 +1,661 @@
+use super::*;
+
+ast_node! {
+    Match<M> {
+        value: Box<Expression<M>>,
+        arms: Vec<MatchArm<M>>,
+        span,
+        ty_info,
+    }
+}
+
+/// A single arm of a match expression, which will be evaluated if the pattern matches.
+#[derive(Clone, Debug)]
+pub struct MatchArm<M: AstMetadata> {
+    pub pattern: Pattern<M>,
+    pub body: Expression<M>,
+}
+
+/// Patterns that values can be matched against.
+#[derive(Clone, Debug)]
+pub enum Pattern<M: AstMetadata> {
+    /// Matches any value.
+    Wildcard { span: M::Span },
+    /// Matches a variant of an enum, binding each of the values in its payload. Payload values
+    /// bound to `_` are ignored.
+    Variant {
+        name: EnumIdx,
+        variant: Symbol,
+        bindings: Vec<Option<M::IdentIdentifier>>,
+        span: M::Span,
+    },
+}
+
+impl<M: AstMetadata> Pattern<M> {
+    pub fn span(&self) -> &M::Span {
+        match self {
+            Pattern::Wildcard { span } | Pattern::Variant { span, .. } => span,
+        }
+    }
+}
+
+impl Pattern<UntypedAstMetadata> {
+    /// Parse a pattern, which is either a wildcard or an enum variant.
+    fn parse(compiler: &mut Compiler, lexer: &mut Lexer<'_>) -> Result<Self, ParseError> {
+        let (name, name_span) = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::Ident(name), span) if name == "_" => {
+                return Ok(Pattern::Wildcard { span });
+            }
+            (Token::Ident(name), span) => (name, span),
+            (token, span) => {
+                return Err(ParseError::ExpectedIdentifier {
+                    found: Box::new(token),
+                    reason: "expected pattern".to_string(),
+                    span,
+                });
+            }
+        };
+
+        let idx = compiler
+            .types
+            .get_enum_idx(compiler.symbols.get_or_intern(&name))
+            .ok_or_else(|| ParseError::UnknownType {
+                name,
+                span: name_span.clone(),
+            })?;
+
+        let (variant, mut span) = parse_variant_name(compiler, lexer, name_span)?;
+
+        // Bind each of the payload values, if present
+        let mut bindings = Vec::new();
+        if matches!(lexer.peek_token(), Some(Token::LeftParen)) {
+            lexer.next_token();
+
+            let end_span = loop {
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::RightParen, span) => break span,
+                    (Token::Ident(binding), _) if binding == "_" => bindings.push(None),
+                    (Token::Ident(binding), _) => {
+                        bindings.push(Some(compiler.symbols.get_or_intern(binding)))
+                    }
+                    (token, span) => {
+                        return Err(ParseError::ExpectedIdentifier {
+                            found: Box::new(token),
+                            reason: "payload pattern must bind an identifier".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Comma, _) => (),
+                    (Token::RightParen, span) => break span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Comma),
+                            found: Box::new(token),
+                            reason: "payload bindings must be separated by a comma".to_string(),
+                            span,
+                        });
+                    }
+                }
+            };
+
+            span = span.start..end_span.end;
+        }
+
+        Ok(Pattern::Variant {
+            name: idx,
+            variant,
+            bindings,
+            span,
+        })
+    }
+}
+
+impl<M: AstMetadata> Parsable for Match<M> {
+    fn register(parser: &mut Parser) {
+        assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
+            Token::Match,
+            |parser, compiler, lexer| {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Match, span) => span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Match),
+                            found: Box::new(token),
+                            reason: "expected match expression".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                // Parse the value being matched on
+                let value = parser.parse(compiler, lexer, Precedence::Lowest)?;
+
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::LeftBrace, _) => (),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::LeftBrace),
+                            found: Box::new(token),
+                            reason: "match arms must begin with opening brace".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                // Parse each of the arms
+                let mut arms = Vec::new();
+                let end_span = loop {
+                    if let Some((Token::RightBrace, _)) = lexer.peek_spanned() {
+                        break lexer.next_spanned().unwrap().1;
+                    }
+
+                    let pattern = Pattern::parse(compiler, lexer)?;
+
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::FatArrow, _) => (),
+                        (token, span) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::FatArrow),
+                                found: Box::new(token),
+                                reason: "pattern must be followed by an arrow".to_string(),
+                                span,
+                            });
+                        }
+                    }
+
+                    let body: Expression<UntypedAstMetadata> =
+                        parser.parse(compiler, lexer, Precedence::Lowest)?;
+                    let is_block = matches!(body, Expression::Block(_));
+
+                    arms.push(MatchArm { pattern, body });
+
+                    // Arms are separated by commas, which are optional following a block
+                    match lexer.peek_spanned() {
+                        Some((Token::Comma, _)) => {
+                            lexer.next_token();
+                        }
+                        Some((Token::RightBrace, _)) => (),
+                        _ if is_block => (),
+                        Some((token, span)) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::Comma),
+                                found: Box::new(token.clone()),
+                                reason: "match arms must be separated by a comma".to_string(),
+                                span: span.clone(),
+                            });
+                        }
+                        None => {
+                            return Err(ParseError::UnexpectedEOF {
+                                span: lexer.eof_span(),
+                            });
+                        }
+                    }
+                };
+
+                Ok(Expression::Match(Match {
+                    value: Box::new(value),
+                    arms,
+                    span: start_span.start..end_span.end,
+                    ty_info: None,
+                }))
+            }
+        ));
+    }
+}
+
+impl SolveType for Match<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let value = self.value.solve(compiler, state)?;
+        let value_ty = value.get_ty_info().ty.clone();
+
+        // Only enums can be matched on
+        let Ty::Enum(enum_idx) = value_ty else {
+            return Err(TyError::Match {
+                ty: value_ty.name(compiler),
+                span: value.span().clone(),
+            });
+        };
+        let variants = compiler
+            .types
+            .get_enum(enum_idx)
+            .map(|registration| registration.variants().to_vec())
+            .unwrap_or_default();
+
+        // Track which variants have been handled by an arm
+        let mut covered = vec![false; variants.len()];
+
+        let arms = self
+            .arms
+            .into_iter()
+            .map(|arm| {
+                let pattern = match arm.pattern {
+                    Pattern::Wildcard { span } => {
+                        covered.fill(true);
+                        state.enter();
+
+                        Pattern::Wildcard { span }
+                    }
+                    Pattern::Variant {
+                        name,
+                        variant,
+                        bindings,
+                        span,
+                    } => {
+                        if name != enum_idx {
+                            return Err(TyError::Mismatch {
+                                expected: value_ty.name(compiler),
+                                found: Ty::Enum(name).name(compiler),
+                                span,
+                                expected_span: Some(value.span().clone()),
+                            });
+                        }
+
+                        let Some((i, (_, payload))) = variants
+                            .iter()
+                            .enumerate()
+                            .find(|(_, (test_variant, _))| *test_variant == variant)
+                        else {
+                            return Err(TyError::UnknownVariant {
+                                ty: value_ty.name(compiler),
+                                variant: compiler.symbols.resolve(variant).unwrap().to_string(),
+                                span,
+                            });
+                        };
+
+                        if payload.len() != bindings.len() {
+                            return Err(TyError::VariantArity {
+                                expected: payload.len(),
+                                found: bindings.len(),
+                                span,
+                            });
+                        }
+
+                        covered[i] = true;
+
+                        // Payload values are bound within the scope of the arm
+                        state.enter();
+                        let bindings = bindings
+                            .into_iter()
+                            .zip(payload)
+                            .map(|(binding, ty)| {
+                                binding.map(|binding| {
+                                    state.register(binding, ty.clone(), span.clone())
+                                })
+                            })
+                            .collect();
+
+                        Pattern::Variant {
+                            name,
+                            variant,
+                            bindings,
+                            span,
+                        }
+                    }
+                };
+
+                let body = arm.body.solve(compiler, state);
+                state.leave();
+
+                Ok(MatchArm {
+                    pattern,
+                    body: body?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Every variant must be handled
+        let missing = variants
+            .iter()
+            .zip(covered)
+            .filter(|(_, covered)| !covered)
+            .map(|((variant, _), _)| compiler.symbols.resolve(*variant).unwrap().to_string())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(TyError::NonExhaustive {
+                missing,
+                span: self.span,
+            });
+        }
+
+        let ty_info = TyInfo::try_from((
+            // Every arm must produce the same type
+            arms.iter()
+                .map(|arm| arm.body.get_ty_info().ty.clone())
+                .collect::<Vec<_>>(),
+            // Any potential place for a return statement must be accounted for
+            [value.get_ty_info().return_ty.clone()]
+                .into_iter()
+                .chain(
+                    arms.iter()
+                        .map(|arm| arm.body.get_ty_info().return_ty.clone()),
+                )
+                .collect::<Vec<_>>(),
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(Match {
+            value: Box::new(value),
+            arms,
+            span: self.span,
+            ty_info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hir::expression::test_util::compiler_with_shape;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            Match::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Block::<UntypedAstMetadata>::register(&mut parser);
+            ExpressionStatement::<UntypedAstMetadata>::register(&mut parser);
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::wildcard("match s { _ => 1 }", 1)]
+        #[case::variants(
+            "match s { Shape::Circle(r) => r, Shape::Rect(w, _) => w, Shape::Empty => 0 }",
+            3
+        )]
+        #[case::trailing_comma("match s { Shape::Empty => 0, _ => 1, }", 2)]
+        #[case::block_without_comma("match s { Shape::Empty => { 0 } _ => { 1 } }", 2)]
+        #[case::empty("match s { }", 0)]
+        fn success(parser: Parser, #[case] source: &str, #[case] arm_count: usize) {
+            let (mut compiler, _) = compiler_with_shape();
+
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(&mut compiler, &mut Lexer::from(source), Precedence::Lowest)
+                .unwrap();
+
+            let Expression::Match(m) = expression else {
+                panic!("expected match expression");
+            };
+
+            assert_eq!(m.arms.len(), arm_count);
+        }
+
+        #[rstest]
+        fn payload_bindings(parser: Parser) {
+            let (mut compiler, _) = compiler_with_shape();
+
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut compiler,
+                    &mut Lexer::from("match s { Shape::Rect(w, _) => w }"),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Match(m) = expression else {
+                panic!("expected match expression");
+            };
+            let Pattern::Variant { bindings, .. } = &m.arms[0].pattern else {
+                panic!("expected variant pattern");
+            };
+
+            assert!(matches!(bindings.as_slice(), [Some(_), None]));
+        }
+
+        #[rstest]
+        #[case::missing_arrow("match s { _ 1 }")]
+        #[case::missing_comma("match s { _ => 1 _ => 2 }")]
+        #[case::unknown_enum("match s { Other::A => 1 }")]
+        #[case::literal_pattern("match s { 1 => 1 }")]
+        #[case::unclosed("match s { _ => 1")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            let (mut compiler, _) = compiler_with_shape();
+
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut compiler,
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn variant_pattern(
+            compiler: &mut Compiler,
+            idx: EnumIdx,
+            variant: &str,
+            bindings: &[Option<&str>],
+        ) -> Pattern<UntypedAstMetadata> {
+            Pattern::Variant {
+                name: idx,
+                variant: compiler.symbols.get_or_intern(variant),
+                bindings: bindings
+                    .iter()
+                    .map(|binding| binding.map(|binding| compiler.symbols.get_or_intern(binding)))
+                    .collect(),
+                span: Span::default(),
+            }
+        }
+
+        fn arm(
+            pattern: Pattern<UntypedAstMetadata>,
+            body: Expression<UntypedAstMetadata>,
+        ) -> MatchArm<UntypedAstMetadata> {
+            MatchArm { pattern, body }
+        }
+
+        /// Build a match on a value `s` of the enum.
+        fn match_on(
+            compiler: &mut Compiler,
+            idx: EnumIdx,
+            arms: Vec<MatchArm<UntypedAstMetadata>>,
+        ) -> (Match<UntypedAstMetadata>, Scope) {
+            let s = compiler.symbols.get_or_intern("s");
+
+            let mut scope = Scope::new();
+            scope.register(s, Ty::Enum(idx), Span::default());
+
+            (
+                Match::new(
+                    Box::new(Expression::ident(s, Span::default())),
+                    arms,
+                    Span::default(),
+                    None,
+                ),
+                scope,
+            )
+        }
+
+        #[rstest]
+        fn exhaustive_with_bindings() {
+            let (mut compiler, idx) = compiler_with_shape();
+            let r = compiler.symbols.get_or_intern("r");
+            let w = compiler.symbols.get_or_intern("w");
+
+            let arms = vec![
+                arm(
+                    variant_pattern(&mut compiler, idx, "Circle", &[Some("r")]),
+                    Expression::ident(r, Span::default()),
+                ),
+                arm(
+                    variant_pattern(&mut compiler, idx, "Rect", &[Some("w"), None]),
+                    Expression::ident(w, Span::default()),
+                ),
+                arm(
+                    variant_pattern(&mut compiler, idx, "Empty", &[]),
+                    Expression::integer(0, Span::default()),
+                ),
+            ];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            assert_eq!(
+                m.solve(&mut compiler, &mut scope).unwrap().ty_info.ty,
+                Ty::Int
+            );
+        }
+
+        #[rstest]
+        fn wildcard_covers_remaining() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let arms = vec![
+                arm(
+                    variant_pattern(&mut compiler, idx, "Empty", &[]),
+                    Expression::integer(0, Span::default()),
+                ),
+                arm(
+                    Pattern::Wildcard {
+                        span: Span::default(),
+                    },
+                    Expression::integer(1, Span::default()),
+                ),
+            ];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            assert!(m.solve(&mut compiler, &mut scope).is_ok());
+        }
+
+        #[rstest]
+        fn non_exhaustive() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let arms = vec![arm(
+                variant_pattern(&mut compiler, idx, "Circle", &[None]),
+                Expression::integer(0, Span::default()),
+            )];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            let Err(TyError::NonExhaustive { missing, .. }) = m.solve(&mut compiler, &mut scope)
+            else {
+                panic!("expected non-exhaustive match");
+            };
+
+            assert_eq!(missing, ["Rect", "Empty"]);
+        }
+
+        #[rstest]
+        fn binding_out_of_scope() {
+            let (mut compiler, idx) = compiler_with_shape();
+            let r = compiler.symbols.get_or_intern("r");
+
+            let arms = vec![
+                arm(
+                    variant_pattern(&mut compiler, idx, "Circle", &[Some("r")]),
+                    Expression::integer(0, Span::default()),
+                ),
+                arm(
+                    Pattern::Wildcard {
+                        span: Span::default(),
+                    },
+                    Expression::ident(r, Span::default()),
+                ),
+            ];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            assert!(matches!(
+                m.solve(&mut compiler, &mut scope),
+                Err(TyError::SymbolNotFound { name, .. }) if name == "r"
+            ));
+        }
+
+        #[rstest]
+        fn arm_mismatch() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let arms = vec![
+                arm(
+                    variant_pattern(&mut compiler, idx, "Empty", &[]),
+                    Expression::integer(0, Span::default()),
+                ),
+                arm(
+                    Pattern::Wildcard {
+                        span: Span::default(),
+                    },
+                    Expression::boolean(true, Span::default()),
+                ),
+            ];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            assert!(matches!(
+                m.solve(&mut compiler, &mut scope),
+                Err(TyError::Mismatch { .. })
+            ));
+        }
+
+        #[rstest]
+        #[case::wrong_arity("Circle", &[])]
+        #[case::unknown_variant("Triangle", &[])]
+        fn invalid_pattern(#[case] variant: &str, #[case] bindings: &[Option<&str>]) {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let arms = vec![arm(
+                variant_pattern(&mut compiler, idx, variant, bindings),
+                Expression::integer(0, Span::default()),
+            )];
+            let (m, mut scope) = match_on(&mut compiler, idx, arms);
+
+            assert!(matches!(
+                m.solve(&mut compiler, &mut scope),
+                Err(TyError::VariantArity { .. } | TyError::UnknownVariant { .. })
+            ));
+        }
+
+        #[rstest]
+        fn match_non_enum() {
+            let mut compiler = Compiler::default();
+
+            let m = Match::new(
+                Box::new(Expression::integer(1, Span::default())),
+                vec![arm(
+                    Pattern::Wildcard {
+                        span: Span::default(),
+                    },
+                    Expression::integer(0, Span::default()),
+                )],
+                Span::default(),
+                None,
+            );
+
+            assert!(matches!(
+                m.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::Match { ty, .. }) if ty == "int"
+            ));
+        }
+    }
+}
@@ -6,12 +6,24 @@ mod block;
 mod boolean;
 mod call;
 mod cast;
+mod field;
+mod float;
+mod for_loop;
 mod ident;
 mod if_else;
 mod index;
 mod infix;
 mod integer;
 mod loop_block;
+mod match_block;
+mod prefix;
+mod struct_literal;
+mod tuple;
+mod variant;
+mod while_loop;
+
+#[cfg(test)]
+mod test_util;
 
 pub use array::*;
 pub use assign::*;
@@ -19,18 +31,29 @@ pub use block::*;
 pub use boolean::*;
 pub use call::*;
 pub use cast::*;
+pub use field::*;
+pub use float::*;
+pub use for_loop::*;
 pub use ident::*;
 pub use if_else::*;
 pub use index::*;
 pub use infix::*;
 pub use integer::*;
 pub use loop_block::*;
+pub use match_block::*;
+pub use prefix::*;
+pub use struct_literal::*;
+pub use tuple::*;
+pub use variant::*;
+pub use while_loop::*;
 
 ast_node! {
     Expression<M>(
         Array,
+        ArrayRepeat,
         Infix,
         Integer,
+        Float,
         Boolean,
         Ident,
         Block,
@@ -38,8 +61,17 @@ ast_node! {
         Index,
         Call,
         Loop,
+        While,
+        For,
         Assign,
         Cast,
+        Prefix,
+        StructLiteral,
+        Field,
+        EnumVariant,
+        Match,
+        Tuple,
+        TupleIndex,
     )
 }
 
@@ -55,7 +87,7 @@ impl<M: AstMetadata<Span = Span, TyInfo: Default>> Expression<M> {
         ))
     }
 
-    pub fn integer(value: i64, span: Span) -> Self {
+    pub fn integer(value: u64, span: Span) -> Self {
         Self::Integer(Integer::new(value, span, M::TyInfo::default()))
     }
 
@@ -91,6 +123,85 @@ impl<M: AstMetadata<Span = Span, TyInfo: Default>> Expression<M> {
     }
 }
 
+impl Expression<UntypedAstMetadata> {
+    /// Provide the type that this expression is expected to have, such as from an annotation, so
+    /// that any literals without a suffix can take it on rather than falling back to their
+    /// default. The expectation is followed through operations that produce the same type as
+    /// their operands, and into the elements of array and tuple literals.
+    pub fn expect_ty(&mut self, ty: &Ty) {
+        match (self, ty) {
+            (Expression::Integer(Integer { ty_info, .. }), ty) if ty.is_integer() => {
+                ty_info.get_or_insert_with(|| ty.clone());
+            }
+            (Expression::Float(Float { ty_info, .. }), ty) if ty.is_float() => {
+                ty_info.get_or_insert_with(|| ty.clone());
+            }
+            (
+                Expression::Prefix(Prefix {
+                    operation: PrefixOperation::Minus,
+                    right,
+                    ..
+                }),
+                ty,
+            ) => right.expect_ty(ty),
+            (
+                Expression::Infix(Infix {
+                    left,
+                    operation,
+                    right,
+                    ..
+                }),
+                ty,
+            ) if !matches!(
+                operation,
+                InfixOperation::Eq
+                    | InfixOperation::NotEq
+                    | InfixOperation::Greater
+                    | InfixOperation::Less
+                    | InfixOperation::GreaterEq
+                    | InfixOperation::LessEq
+            ) =>
+            {
+                left.expect_ty(ty);
+                right.expect_ty(ty);
+            }
+            (Expression::Array(Array { init, ty_info, .. }), Ty::Array { inner, .. }) => {
+                init.iter_mut().for_each(|value| value.expect_ty(inner));
+
+                // An empty array has no elements to take the type from
+                if init.is_empty() {
+                    ty_info.get_or_insert_with(|| ty.clone());
+                }
+            }
+            (Expression::ArrayRepeat(ArrayRepeat { value, .. }), Ty::Array { inner, .. }) => {
+                value.expect_ty(inner);
+            }
+            (Expression::Tuple(Tuple { values, .. }), Ty::Tuple(tys)) => {
+                values
+                    .iter_mut()
+                    .zip(tys)
+                    .for_each(|(value, ty)| value.expect_ty(ty));
+            }
+            _ => (),
+        }
+    }
+
+    /// Whether this is a literal without a suffix, which can take on a type provided by
+    /// [`Self::expect_ty`].
+    pub fn is_unsuffixed_literal(&self) -> bool {
+        match self {
+            Expression::Integer(Integer { ty_info, .. }) => ty_info.is_none(),
+            Expression::Float(Float { ty_info, .. }) => ty_info.is_none(),
+            Expression::Prefix(Prefix {
+                operation: PrefixOperation::Minus,
+                right,
+                ..
+            }) => right.is_unsuffixed_literal(),
+            _ => false,
+        }
+    }
+}
+
 impl<M: AstMetadata> Parsable for Expression<M> {
     fn register(parser: &mut Parser) {
         // Register all variant parsers
@@ -100,12 +211,19 @@ impl<M: AstMetadata> Parsable for Expression<M> {
         Boolean::<UntypedAstMetadata>::register(parser);
         Call::<UntypedAstMetadata>::register(parser);
         Cast::<UntypedAstMetadata>::register(parser);
+        Field::<UntypedAstMetadata>::register(parser);
+        Float::<UntypedAstMetadata>::register(parser);
+        For::<UntypedAstMetadata>::register(parser);
         Ident::<UntypedAstMetadata>::register(parser);
         If::<UntypedAstMetadata>::register(parser);
         Index::<UntypedAstMetadata>::register(parser);
         Infix::<UntypedAstMetadata>::register(parser);
         Integer::<UntypedAstMetadata>::register(parser);
         Loop::<UntypedAstMetadata>::register(parser);
+        Match::<UntypedAstMetadata>::register(parser);
+        Prefix::<UntypedAstMetadata>::register(parser);
+        Tuple::<UntypedAstMetadata>::register(parser);
+        While::<UntypedAstMetadata>::register(parser);
     }
 }
 
@@ -120,16 +238,27 @@ impl SolveType for Expression<UntypedAstMetadata> {
         Ok(match self {
             Expression::Infix(e) => Expression::Infix(e.solve(compiler, state)?),
             Expression::Integer(e) => Expression::Integer(e.solve(compiler, state)?),
+            Expression::Float(e) => Expression::Float(e.solve(compiler, state)?),
             Expression::Boolean(e) => Expression::Boolean(e.solve(compiler, state)?),
             Expression::Ident(e) => Expression::Ident(e.solve(compiler, state)?),
             Expression::Block(e) => Expression::Block(e.solve(compiler, state)?),
             Expression::If(e) => Expression::If(e.solve(compiler, state)?),
             Expression::Index(e) => Expression::Index(e.solve(compiler, state)?),
             Expression::Loop(e) => Expression::Loop(e.solve(compiler, state)?),
+            Expression::While(e) => Expression::While(e.solve(compiler, state)?),
+            Expression::For(e) => Expression::For(e.solve(compiler, state)?),
             Expression::Call(e) => Expression::Call(e.solve(compiler, state)?),
             Expression::Assign(e) => Expression::Assign(e.solve(compiler, state)?),
             Expression::Cast(e) => Expression::Cast(e.solve(compiler, state)?),
             Expression::Array(e) => Expression::Array(e.solve(compiler, state)?),
+            Expression::ArrayRepeat(e) => Expression::ArrayRepeat(e.solve(compiler, state)?),
+            Expression::Prefix(e) => Expression::Prefix(e.solve(compiler, state)?),
+            Expression::StructLiteral(e) => Expression::StructLiteral(e.solve(compiler, state)?),
+            Expression::Field(e) => Expression::Field(e.solve(compiler, state)?),
+            Expression::EnumVariant(e) => Expression::EnumVariant(e.solve(compiler, state)?),
+            Expression::Match(e) => Expression::Match(e.solve(compiler, state)?),
+            Expression::Tuple(e) => Expression::Tuple(e.solve(compiler, state)?),
+            Expression::TupleIndex(e) => Expression::TupleIndex(e.solve(compiler, state)?),
         })
     }
 }
@@ -0,0 +1,259 @@
+use super::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PrefixOperation {
+    Minus,
+    Not,
+}
+
+impl PrefixOperation {
+    /// Determine the resulting type if this operator is applied to the provided operand.
+    pub fn result_ty(&self, ty: &Ty) -> Result<Ty, TyMismatch> {
+        match (self, ty) {
+            (PrefixOperation::Minus, ty) if ty.is_signed() || ty.is_float() => Ok(ty.clone()),
+            (PrefixOperation::Not, Ty::Boolean) => Ok(Ty::Boolean),
+            (PrefixOperation::Minus, found) => Err(TyMismatch {
+                expected: Ty::Int,
+                found: found.clone(),
+            }),
+            (PrefixOperation::Not, found) => Err(TyMismatch {
+                expected: Ty::Boolean,
+                found: found.clone(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Token> for PrefixOperation {
+    type Error = Token;
+
+    fn try_from(token: Token) -> Result<Self, Self::Error> {
+        match token {
+            Token::Minus => Ok(PrefixOperation::Minus),
+            Token::Bang => Ok(PrefixOperation::Not),
+            token => Err(token),
+        }
+    }
+}
+
+ast_node! {
+    Prefix<M> {
+        operation: PrefixOperation,
+        right: Box<Expression<M>>,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for Prefix<M> {
+    fn register(parser: &mut Parser) {
+        fn parse(
+            parser: &Parser,
+            compiler: &mut Compiler,
+            lexer: &mut Lexer,
+        ) -> Result<Expression<UntypedAstMetadata>, ParseError> {
+            // Work out what the operation is
+            let (token, span) = lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })?;
+            let operation =
+                PrefixOperation::try_from(token).map_err(|token| ParseError::ExpectedToken {
+                    // WARN: Should be any of the prefix tokens
+                    expected: Box::new(Token::Minus),
+                    found: Box::new(token),
+                    reason: "expected to parse valid prefix operation".to_string(),
+                    span: span.clone(),
+                })?;
+
+            // Operand binds tighter than any infix operation
+            let right: Expression<UntypedAstMetadata> =
+                parser.parse(compiler, lexer, Precedence::Prefix)?;
+
+            Ok(Expression::Prefix(Prefix {
+                span: span.start..right.span().end,
+                operation,
+                right: Box::new(right),
+                ty_info: None,
+            }))
+        }
+
+        // Register the same parser for all of the possible prefix operations
+        [Token::Minus, Token::Bang].into_iter().for_each(|token| {
+            assert!(parser.register_prefix(token, parse));
+        });
+    }
+}
+
+impl SolveType for Prefix<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let right = match (self.operation, *self.right) {
+            // Only a negated literal can reach the minimum value of a signed type
+            (PrefixOperation::Minus, Expression::Integer(integer)) => {
+                Expression::Integer(integer.solve_literal(compiler, true)?)
+            }
+            (_, right) => right.solve(compiler, state)?,
+        };
+
+        let ty = self
+            .operation
+            .result_ty(&right.get_ty_info().ty)
+            .map_err(|e| e.at(compiler, right.span().clone()))?;
+
+        Ok(Prefix {
+            operation: self.operation,
+            span: self.span,
+            ty_info: TyInfo {
+                ty,
+                return_ty: right.get_ty_info().return_ty.clone(),
+            },
+            right: Box::new(right),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    mod parse {
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            Prefix::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Infix::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+            Boolean::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::minus("-1", PrefixOperation::Minus)]
+        #[case::not("!true", PrefixOperation::Not)]
+        #[case::nested("--1", PrefixOperation::Minus)]
+        fn success(parser: Parser, #[case] source: &str, #[case] operation: PrefixOperation) {
+            let e: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Prefix(e) = e else {
+                panic!("expected to parse prefix expression");
+            };
+
+            assert_eq!(e.operation, operation);
+        }
+
+        #[rstest]
+        fn precedence(parser: Parser) {
+            // -1 + 2 should be (-1) + 2
+            let e: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from("-1 + 2"),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Infix(e) = e else {
+                panic!("expected to parse infix expression");
+            };
+
+            assert!(matches!(*e.left, Expression::Prefix(_)));
+            assert!(matches!(*e.right, Expression::Integer(_)));
+        }
+
+        #[rstest]
+        #[case::missing_operand("-")]
+        #[case::infix_operand("!+")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        #[rstest]
+        #[case::minus(
+            PrefixOperation::Minus,
+            Expression::integer(1, Span::default()),
+            Ty::Int
+        )]
+        #[case::minus_float(
+            PrefixOperation::Minus,
+            Expression::Float(Float::new(1.5, Span::default(), None)),
+            Ty::F64
+        )]
+        #[case::minus_min(
+            PrefixOperation::Minus,
+            Expression::Integer(Integer::new(128, Span::default(), Some(Ty::I8))),
+            Ty::I8
+        )]
+        #[case::minus_int_min(
+            PrefixOperation::Minus,
+            Expression::integer(9_223_372_036_854_775_808, Span::default()),
+            Ty::Int
+        )]
+        #[case::not(
+            PrefixOperation::Not,
+            Expression::boolean(true, Span::default()),
+            Ty::Boolean
+        )]
+        fn success(
+            #[case] operation: PrefixOperation,
+            #[case] right: Expression<UntypedAstMetadata>,
+            #[case] ty: Ty,
+        ) {
+            let prefix = Prefix::new(operation, Box::new(right), Span::default(), None);
+
+            let ty_info = prefix
+                .solve(&mut Compiler::default(), &mut Scope::new())
+                .unwrap()
+                .ty_info;
+
+            assert_eq!(ty_info.ty, ty);
+            assert_eq!(ty_info.return_ty, None);
+        }
+
+        #[rstest]
+        #[case::minus_boolean(PrefixOperation::Minus, Expression::boolean(true, Span::default()))]
+        #[case::not_integer(PrefixOperation::Not, Expression::integer(1, Span::default()))]
+        #[case::minus_out_of_range(
+            PrefixOperation::Minus,
+            Expression::Integer(Integer::new(129, Span::default(), Some(Ty::I8)))
+        )]
+        #[case::minus_int_out_of_range(
+            PrefixOperation::Minus,
+            Expression::integer(9_223_372_036_854_775_809, Span::default())
+        )]
+        fn fail(#[case] operation: PrefixOperation, #[case] right: Expression<UntypedAstMetadata>) {
+            let prefix = Prefix::new(operation, Box::new(right), Span::default(), None);
+
+            assert!(prefix
+                .solve(&mut Compiler::default(), &mut Scope::new())
+                .is_err());
+        }
+    }
+}
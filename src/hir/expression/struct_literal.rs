@@ -0,0 +1,380 @@
+use crate::repr::ast::typed::TypedAstMetadata;
+
+use super::*;
+
+ast_node! {
+    StructLiteral<M> {
+        name: StructIdx,
+        fields: Vec<(Symbol, Expression<M>)>,
+        span,
+        ty_info,
+    }
+}
+
+impl StructLiteral<UntypedAstMetadata> {
+    /// Parse the field list of a struct literal, following the name of the struct. Will be
+    /// triggered by [`Ident`] when it encounters a known struct name followed by an opening brace.
+    pub fn parse_fields(
+        parser: &Parser,
+        compiler: &mut Compiler,
+        lexer: &mut Lexer<'_>,
+        name: StructIdx,
+        name_span: Span,
+    ) -> Result<StructLiteral<UntypedAstMetadata>, ParseError> {
+        // Opening brace for field list
+        match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::LeftBrace, _) => (),
+            (token, span) => {
+                return Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::LeftBrace),
+                    found: Box::new(token),
+                    reason: "struct literal fields must begin with opening brace".to_string(),
+                    span,
+                });
+            }
+        }
+
+        // Parse each of the fields, which may have a trailing comma
+        let mut fields = Vec::new();
+        let end_span = loop {
+            let field = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })? {
+                (Token::RightBrace, span) => break span,
+                (Token::Ident(field), _) => compiler.symbols.get_or_intern(field),
+                (token, span) => {
+                    return Err(ParseError::ExpectedIdentifier {
+                        found: Box::new(token),
+                        reason: "struct literal field must have identifier".to_string(),
+                        span,
+                    });
+                }
+            };
+
+            match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })? {
+                (Token::Colon, _) => (),
+                (token, span) => {
+                    return Err(ParseError::ExpectedToken {
+                        expected: Box::new(Token::Colon),
+                        found: Box::new(token),
+                        reason: "field name and value must be separated by a colon".to_string(),
+                        span,
+                    });
+                }
+            }
+
+            let value: Expression<UntypedAstMetadata> =
+                parser.parse(compiler, lexer, Precedence::Lowest)?;
+            fields.push((field, value));
+
+            // Fields are separated by commas, unless the list is finished
+            match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })? {
+                (Token::Comma, _) => (),
+                (Token::RightBrace, span) => break span,
+                (token, span) => {
+                    return Err(ParseError::ExpectedToken {
+                        expected: Box::new(Token::Comma),
+                        found: Box::new(token),
+                        reason: "struct literal fields must be separated by a comma".to_string(),
+                        span,
+                    });
+                }
+            }
+        };
+
+        Ok(StructLiteral {
+            name,
+            fields,
+            span: name_span.start..end_span.end,
+            ty_info: None,
+        })
+    }
+}
+
+impl SolveType for StructLiteral<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let ty = Ty::Struct(self.name);
+
+        // Solve each field, and place it in the position it was declared in
+        let field_count = compiler
+            .types
+            .get(self.name)
+            .map(|registration| registration.fields().len())
+            .unwrap_or_default();
+        let mut fields: Vec<Option<(Symbol, Expression<TypedAstMetadata>)>> =
+            (0..field_count).map(|_| None).collect();
+
+        for (field, value) in self.fields {
+            let value = value.solve(compiler, state)?;
+
+            let (i, expected) =
+                compiler
+                    .types
+                    .resolve_field(&ty, field)
+                    .ok_or_else(|| TyError::NoField {
+                        ty: ty.name(compiler),
+                        field: compiler.symbols.resolve(field).unwrap().to_string(),
+                        span: value.span().clone(),
+                    })?;
+
+            if fields[i].is_some() {
+                return Err(TyError::DuplicateField {
+                    field: compiler.symbols.resolve(field).unwrap().to_string(),
+                    span: value.span().clone(),
+                });
+            }
+
+            let found = &value.get_ty_info().ty;
+            if !found.check(expected) {
+                return Err(TyError::Mismatch {
+                    expected: expected.name(compiler),
+                    found: found.name(compiler),
+                    span: value.span().clone(),
+                    expected_span: None,
+                });
+            }
+
+            fields[i] = Some((field, value));
+        }
+
+        // Every field must be provided
+        let fields = fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| {
+                field.ok_or_else(|| {
+                    let field = compiler.types.get(self.name).unwrap().fields()[i].0;
+
+                    TyError::MissingField {
+                        field: compiler.symbols.resolve(field).unwrap().to_string(),
+                        span: self.span.clone(),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ty_info = TyInfo::try_from((
+            ty,
+            fields
+                .iter()
+                .map(|(_, value)| value.get_ty_info().return_ty.clone()),
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(StructLiteral {
+            name: self.name,
+            fields,
+            span: self.span,
+            ty_info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hir::expression::test_util::compiler_with_point;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            // Struct literals are triggered by idents
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Boolean::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::all_fields("Point { x: 1, y: true }", 2)]
+        #[case::trailing_comma("Point { x: 1, y: true, }", 2)]
+        #[case::out_of_order("Point { y: true, x: 1 }", 2)]
+        #[case::empty("Point { }", 0)]
+        fn success(parser: Parser, #[case] source: &str, #[case] field_count: usize) {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(&mut compiler, &mut Lexer::from(source), Precedence::Lowest)
+                .unwrap();
+
+            let Expression::StructLiteral(literal) = expression else {
+                panic!("expected struct literal");
+            };
+
+            assert_eq!(literal.name, idx);
+            assert_eq!(literal.fields.len(), field_count);
+        }
+
+        #[rstest]
+        fn unknown_struct_is_ident(parser: Parser) {
+            let (mut compiler, _) = compiler_with_point();
+
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut compiler,
+                    &mut Lexer::from("Other { x: 1 }"),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            assert!(matches!(expression, Expression::Ident(_)));
+        }
+
+        #[rstest]
+        #[case::missing_colon("Point { x 1 }")]
+        #[case::missing_comma("Point { x: 1 y: true }")]
+        #[case::unclosed("Point { x: 1")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            let (mut compiler, _) = compiler_with_point();
+
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut compiler,
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn literal(
+            compiler: &mut Compiler,
+            idx: StructIdx,
+            fields: Vec<(&str, Expression<UntypedAstMetadata>)>,
+        ) -> StructLiteral<UntypedAstMetadata> {
+            StructLiteral::new(
+                idx,
+                fields
+                    .into_iter()
+                    .map(|(field, value)| (compiler.symbols.get_or_intern(field), value))
+                    .collect(),
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        fn reorders_fields() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let literal = literal(
+                &mut compiler,
+                idx,
+                vec![
+                    ("y", Expression::boolean(true, Span::default())),
+                    ("x", Expression::integer(1, Span::default())),
+                ],
+            );
+
+            let literal = literal.solve(&mut compiler, &mut Scope::new()).unwrap();
+
+            assert_eq!(literal.ty_info.ty, Ty::Struct(idx));
+            assert!(matches!(literal.fields[0].1, Expression::Integer(_)));
+            assert!(matches!(literal.fields[1].1, Expression::Boolean(_)));
+        }
+
+        #[rstest]
+        fn missing_field() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let literal = literal(
+                &mut compiler,
+                idx,
+                vec![("x", Expression::integer(1, Span::default()))],
+            );
+
+            assert!(matches!(
+                literal.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::MissingField { field, .. }) if field == "y"
+            ));
+        }
+
+        #[rstest]
+        fn duplicate_field() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let literal = literal(
+                &mut compiler,
+                idx,
+                vec![
+                    ("x", Expression::integer(1, Span::default())),
+                    ("x", Expression::integer(2, Span::default())),
+                    ("y", Expression::boolean(true, Span::default())),
+                ],
+            );
+
+            assert!(matches!(
+                literal.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::DuplicateField { field, .. }) if field == "x"
+            ));
+        }
+
+        #[rstest]
+        fn unknown_field() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let literal = literal(
+                &mut compiler,
+                idx,
+                vec![
+                    ("x", Expression::integer(1, Span::default())),
+                    ("y", Expression::boolean(true, Span::default())),
+                    ("z", Expression::integer(2, Span::default())),
+                ],
+            );
+
+            assert!(matches!(
+                literal.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::NoField { ty, field, .. }) if ty == "Point" && field == "z"
+            ));
+        }
+
+        #[rstest]
+        fn field_mismatch() {
+            let (mut compiler, idx) = compiler_with_point();
+
+            let literal = literal(
+                &mut compiler,
+                idx,
+                vec![
+                    ("x", Expression::boolean(false, Span::default())),
+                    ("y", Expression::boolean(true, Span::default())),
+                ],
+            );
+
+            assert!(matches!(
+                literal.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "int" && found == "bool"
+            ));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+/// Produce a compiler with `struct Point { x: int, y: bool }` defined.
+pub fn compiler_with_point() -> (Compiler, StructIdx) {
+    let mut compiler = Compiler::default();
+
+    let point = compiler.symbols.get_or_intern("Point");
+    let x = compiler.symbols.get_or_intern("x");
+    let y = compiler.symbols.get_or_intern("y");
+
+    let idx = compiler.types.declare(point);
+    compiler
+        .types
+        .define(idx, vec![(x, Ty::Int), (y, Ty::Boolean)], Span::default());
+
+    (compiler, idx)
+}
+
+/// Produce a compiler with `enum Shape { Circle(int), Rect(int, bool), Empty }` defined.
+pub fn compiler_with_shape() -> (Compiler, EnumIdx) {
+    let mut compiler = Compiler::default();
+
+    let shape = compiler.symbols.get_or_intern("Shape");
+    let circle = compiler.symbols.get_or_intern("Circle");
+    let rect = compiler.symbols.get_or_intern("Rect");
+    let empty = compiler.symbols.get_or_intern("Empty");
+
+    let idx = compiler.types.declare_enum(shape);
+    compiler.types.define_enum(
+        idx,
+        vec![
+            (circle, vec![Ty::Int]),
+            (rect, vec![Ty::Int, Ty::Boolean]),
+            (empty, vec![]),
+        ],
+        Span::default(),
+    );
+
+    (compiler, idx)
+}
@@ -0,0 +1,370 @@
+use super::*;
+
+ast_node! {
+    Tuple<M> {
+        values: Vec<Expression<M>>,
+        span,
+        ty_info,
+    }
+}
+
+ast_node! {
+    TupleIndex<M> {
+        value: Box<Expression<M>>,
+        index: u32,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for Tuple<M> {
+    fn register(parser: &mut Parser) {
+        assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
+            Token::LeftParen,
+            |parser, compiler, lexer| {
+                let (_, start_span) = lexer.next_spanned().unwrap();
+
+                let mut values = Vec::new();
+                let mut is_tuple = false;
+                let end_span = loop {
+                    if let Some((Token::RightParen, _)) = lexer.peek_spanned() {
+                        break lexer.next_spanned().unwrap().1;
+                    }
+
+                    values.push(parser.parse(compiler, lexer, Precedence::Lowest)?);
+
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Comma, _) => is_tuple = true,
+                        (Token::RightParen, span) => break span,
+                        (token, span) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::Comma),
+                                found: Box::new(token),
+                                reason: "tuple values must be separated by a comma".to_string(),
+                                span,
+                            });
+                        }
+                    }
+                };
+
+                // A single value without a trailing comma is just grouped
+                if values.len() == 1 && !is_tuple {
+                    return Ok(values.pop().unwrap());
+                }
+
+                if values.is_empty() {
+                    return Err(ParseError::ExpectedToken {
+                        expected: Box::new(Token::RightParen),
+                        found: Box::new(Token::LeftParen),
+                        reason: "tuples must contain at least one value".to_string(),
+                        span: start_span.start..end_span.end,
+                    });
+                }
+
+                Ok(Expression::Tuple(Tuple {
+                    values,
+                    span: start_span.start..end_span.end,
+                    ty_info: None,
+                }))
+            }
+        ));
+    }
+}
+
+impl TupleIndex<UntypedAstMetadata> {
+    /// Produce the element access following a dot, which will be triggered by [`Field`] when it
+    /// encounters a numeric token instead of a field name. Chained accesses such as `t.0.1` are
+    /// lexed as a single float, so will be split back into each of their indexes.
+    pub fn from_token(
+        left: Expression<UntypedAstMetadata>,
+        token: Token,
+        span: Span,
+    ) -> Result<Expression<UntypedAstMetadata>, ParseError> {
+        let text = match &token {
+            Token::Integer(value) => value.to_string(),
+            Token::Float(value) => value.clone(),
+            _ => {
+                return Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::Integer(0)),
+                    found: Box::new(token),
+                    reason: "tuple access requires element index".to_string(),
+                    span,
+                });
+            }
+        };
+
+        let mut expression = left;
+        let mut end = span.start;
+        for index in text.split('.') {
+            end += index.len();
+
+            let index = index.parse().map_err(|_| ParseError::ExpectedToken {
+                expected: Box::new(Token::Integer(0)),
+                found: Box::new(token.clone()),
+                reason: "tuple access requires element index".to_string(),
+                span: span.clone(),
+            })?;
+
+            expression = Expression::TupleIndex(TupleIndex {
+                span: expression.span().start..end,
+                value: Box::new(expression),
+                index,
+                ty_info: None,
+            });
+
+            // Skip over the separating dot
+            end += 1;
+        }
+
+        Ok(expression)
+    }
+}
+
+impl SolveType for Tuple<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let values = self
+            .values
+            .into_iter()
+            .map(|value| value.solve(compiler, state))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ty_info = TyInfo::try_from((
+            Ty::Tuple(
+                values
+                    .iter()
+                    .map(|value| value.get_ty_info().ty.clone())
+                    .collect(),
+            ),
+            values
+                .iter()
+                .map(|value| value.get_ty_info().return_ty.clone()),
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(Tuple {
+            values,
+            span: self.span,
+            ty_info,
+        })
+    }
+}
+
+impl SolveType for TupleIndex<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let value = self.value.solve(compiler, state)?;
+        let value_ty_info = value.get_ty_info();
+
+        let ty = match &value_ty_info.ty {
+            Ty::Tuple(tys) => tys.get(self.index as usize).cloned(),
+            _ => None,
+        }
+        .ok_or_else(|| TyError::NoElement {
+            ty: value_ty_info.ty.name(compiler),
+            index: self.index,
+            span: self.span.clone(),
+        })?;
+
+        Ok(TupleIndex {
+            ty_info: TyInfo {
+                ty,
+                return_ty: value_ty_info.return_ty.clone(),
+            },
+            value: Box::new(value),
+            index: self.index,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            Tuple::<UntypedAstMetadata>::register(&mut parser);
+
+            // Element access is triggered by field access
+            Field::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Boolean::<UntypedAstMetadata>::register(&mut parser);
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+            Infix::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::pair("(1, true)", 2)]
+        #[case::single("(1,)", 1)]
+        #[case::trailing_comma("(1, 2, 3,)", 3)]
+        #[case::nested("((1, 2), 3)", 2)]
+        fn tuple(parser: Parser, #[case] source: &str, #[case] count: usize) {
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Tuple(tuple) = expression else {
+                panic!("expected tuple");
+            };
+
+            assert_eq!(tuple.values.len(), count);
+        }
+
+        #[rstest]
+        fn grouping(parser: Parser) {
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from("(1 + 2) * 3"),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Expression::Infix(Infix { left, .. }) = expression else {
+                panic!("expected infix expression");
+            };
+
+            assert!(matches!(*left, Expression::Infix(_)));
+        }
+
+        #[rstest]
+        #[case::single("a.0", &[0])]
+        #[case::chained("a.0.1", &[1, 0])]
+        #[case::mixed("a.1.b.2", &[2])]
+        fn index(parser: Parser, #[case] source: &str, #[case] indexes: &[u32]) {
+            let mut expression: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            for expected in indexes {
+                let Expression::TupleIndex(TupleIndex { value, index, .. }) = expression else {
+                    panic!("expected tuple index");
+                };
+
+                assert_eq!(index, *expected);
+                expression = *value;
+            }
+        }
+
+        #[rstest]
+        #[case::empty("()")]
+        #[case::unclosed("(1, 2")]
+        #[case::missing_comma("(1 2)")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        #[test]
+        fn tuple() {
+            let tuple = Tuple::<UntypedAstMetadata>::new(
+                vec![
+                    Expression::integer(1, Span::default()),
+                    Expression::boolean(true, Span::default()),
+                ],
+                Span::default(),
+                None,
+            );
+
+            assert_eq!(
+                tuple
+                    .solve(&mut Compiler::default(), &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                Ty::Tuple(vec![Ty::Int, Ty::Boolean])
+            );
+        }
+
+        fn index(compiler: &mut Compiler, index: u32) -> TupleIndex<UntypedAstMetadata> {
+            let value = compiler.symbols.get_or_intern("value");
+
+            TupleIndex::new(
+                Box::new(Expression::ident(value, Span::default())),
+                index,
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        #[case::first(0, Ty::Int)]
+        #[case::second(1, Ty::Boolean)]
+        fn element(#[case] i: u32, #[case] expected: Ty) {
+            let mut compiler = Compiler::default();
+
+            let mut scope = Scope::new();
+            scope.register(
+                compiler.symbols.get_or_intern("value"),
+                Ty::Tuple(vec![Ty::Int, Ty::Boolean]),
+                Span::default(),
+            );
+
+            let index = index(&mut compiler, i);
+
+            assert_eq!(
+                index.solve(&mut compiler, &mut scope).unwrap().ty_info.ty,
+                expected
+            );
+        }
+
+        #[rstest]
+        #[case::out_of_range(Ty::Tuple(vec![Ty::Int, Ty::Boolean]))]
+        #[case::not_a_tuple(Ty::Int)]
+        fn no_element(#[case] ty: Ty) {
+            let mut compiler = Compiler::default();
+
+            let mut scope = Scope::new();
+            scope.register(compiler.symbols.get_or_intern("value"), ty, Span::default());
+
+            let index = index(&mut compiler, 2);
+
+            assert!(matches!(
+                index.solve(&mut compiler, &mut scope),
+                Err(TyError::NoElement { index: 2, .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use super::*;
+
+ast_node! {
+    EnumVariant<M> {
+        name: EnumIdx,
+        variant: Symbol,
+        args: Vec<Expression<M>>,
+        span,
+        ty_info,
+    }
+}
+
+impl EnumVariant<UntypedAstMetadata> {
+    /// Parse the variant and payload of an enum value, following the name of the enum. Will be
+    /// triggered by [`Ident`] when it encounters a known enum name followed by a double colon.
+    pub fn parse_variant(
+        parser: &Parser,
+        compiler: &mut Compiler,
+        lexer: &mut Lexer<'_>,
+        name: EnumIdx,
+        name_span: Span,
+    ) -> Result<EnumVariant<UntypedAstMetadata>, ParseError> {
+        let (variant, mut span) = parse_variant_name(compiler, lexer, name_span)?;
+
+        // Payload values are optional
+        let mut args = Vec::new();
+        if matches!(lexer.peek_token(), Some(Token::LeftParen)) {
+            lexer.next_token();
+
+            let end_span = loop {
+                if let Some((Token::RightParen, _)) = lexer.peek_spanned() {
+                    break lexer.next_spanned().unwrap().1;
+                }
+
+                args.push(parser.parse(compiler, lexer, Precedence::Lowest)?);
+
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Comma, _) => (),
+                    (Token::RightParen, span) => break span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Comma),
+                            found: Box::new(token),
+                            reason: "variant values must be separated by a comma".to_string(),
+                            span,
+                        });
+                    }
+                }
+            };
+
+            span = span.start..end_span.end;
+        }
+
+        Ok(EnumVariant {
+            name,
+            variant,
+            args,
+            span,
+            ty_info: None,
+        })
+    }
+}
+
+/// Parse the `::Variant` following the name of an enum, producing the variant and the span from
+/// the start of the enum name to the end of the variant.
+pub fn parse_variant_name(
+    compiler: &mut Compiler,
+    lexer: &mut Lexer<'_>,
+    name_span: Span,
+) -> Result<(Symbol, Span), ParseError> {
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::DoubleColon, _) => (),
+        (token, span) => {
+            return Err(ParseError::ExpectedToken {
+                expected: Box::new(Token::DoubleColon),
+                found: Box::new(token),
+                reason: "enum name must be followed by a variant".to_string(),
+                span,
+            });
+        }
+    }
+
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Ident(variant), span) => Ok((
+            compiler.symbols.get_or_intern(variant),
+            name_span.start..span.end,
+        )),
+        (token, span) => Err(ParseError::ExpectedIdentifier {
+            found: Box::new(token),
+            reason: "variant must have identifier".to_string(),
+            span,
+        }),
+    }
+}
+
+impl SolveType for EnumVariant<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        let ty = Ty::Enum(self.name);
+
+        let args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.solve(compiler, state))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (_, payload) = compiler
+            .types
+            .get_enum(self.name)
+            .and_then(|registration| registration.variant(self.variant))
+            .ok_or_else(|| TyError::UnknownVariant {
+                ty: ty.name(compiler),
+                variant: compiler.symbols.resolve(self.variant).unwrap().to_string(),
+                span: self.span.clone(),
+            })?;
+
+        if payload.len() != args.len() {
+            return Err(TyError::VariantArity {
+                expected: payload.len(),
+                found: args.len(),
+                span: self.span,
+            });
+        }
+
+        // Each value must match the type declared in the payload
+        for (arg, expected) in args.iter().zip(payload) {
+            let found = &arg.get_ty_info().ty;
+            if !found.check(expected) {
+                return Err(TyError::Mismatch {
+                    expected: expected.name(compiler),
+                    found: found.name(compiler),
+                    span: arg.span().clone(),
+                    expected_span: None,
+                });
+            }
+        }
+
+        let ty_info = TyInfo::try_from((
+            ty,
+            args.iter().map(|arg| arg.get_ty_info().return_ty.clone()),
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(EnumVariant {
+            name: self.name,
+            variant: self.variant,
+            args,
+            span: self.span,
+            ty_info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hir::expression::test_util::compiler_with_shape;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            // Variants are triggered by idents
+            Ident::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Boolean::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::no_payload("Shape::Empty", 0)]
+        #[case::single_payload("Shape::Circle(1)", 1)]
+        #[case::multiple_payload("Shape::Rect(1, true)", 2)]
+        #[case::trailing_comma("Shape::Rect(1, true,)", 2)]
+        fn success(parser: Parser, #[case] source: &str, #[case] arg_count: usize) {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let expression: Expression<UntypedAstMetadata> = parser
+                .parse(&mut compiler, &mut Lexer::from(source), Precedence::Lowest)
+                .unwrap();
+
+            let Expression::EnumVariant(variant) = expression else {
+                panic!("expected enum variant");
+            };
+
+            assert_eq!(variant.name, idx);
+            assert_eq!(variant.args.len(), arg_count);
+        }
+
+        #[rstest]
+        #[case::missing_variant("Shape::")]
+        #[case::unclosed_payload("Shape::Circle(1")]
+        #[case::missing_comma("Shape::Rect(1 true)")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            let (mut compiler, _) = compiler_with_shape();
+
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut compiler,
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        fn variant(
+            compiler: &mut Compiler,
+            idx: EnumIdx,
+            variant: &str,
+            args: Vec<Expression<UntypedAstMetadata>>,
+        ) -> EnumVariant<UntypedAstMetadata> {
+            EnumVariant::new(
+                idx,
+                compiler.symbols.get_or_intern(variant),
+                args,
+                Span::default(),
+                None,
+            )
+        }
+
+        #[rstest]
+        #[case::no_payload("Empty", vec![])]
+        #[case::payload("Rect", vec![
+            Expression::integer(1, Span::default()),
+            Expression::boolean(true, Span::default()),
+        ])]
+        fn success(#[case] name: &str, #[case] args: Vec<Expression<UntypedAstMetadata>>) {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let variant = variant(&mut compiler, idx, name, args);
+
+            assert_eq!(
+                variant
+                    .solve(&mut compiler, &mut Scope::new())
+                    .unwrap()
+                    .ty_info
+                    .ty,
+                Ty::Enum(idx)
+            );
+        }
+
+        #[rstest]
+        fn unknown_variant() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let variant = variant(&mut compiler, idx, "Triangle", vec![]);
+
+            assert!(matches!(
+                variant.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::UnknownVariant { ty, variant, .. })
+                    if ty == "Shape" && variant == "Triangle"
+            ));
+        }
+
+        #[rstest]
+        fn wrong_arity() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let variant = variant(&mut compiler, idx, "Circle", vec![]);
+
+            assert!(matches!(
+                variant.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::VariantArity {
+                    expected: 1,
+                    found: 0,
+                    ..
+                })
+            ));
+        }
+
+        #[rstest]
+        fn payload_mismatch() {
+            let (mut compiler, idx) = compiler_with_shape();
+
+            let variant = variant(
+                &mut compiler,
+                idx,
+                "Circle",
+                vec![Expression::boolean(true, Span::default())],
+            );
+
+            assert!(matches!(
+                variant.solve(&mut compiler, &mut Scope::new()),
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "int" && found == "bool"
+            ));
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use super::*;
+
+ast_node! {
+    While<M> {
+        label: Option<Symbol>,
+        condition: Box<Expression<M>>,
+        body: Block<M>,
+        span,
+        ty_info,
+    }
+}
+
+impl<M: AstMetadata> Parsable for While<M> {
+    fn register(parser: &mut Parser) {
+        assert!(parser.register_prefix::<Expression<UntypedAstMetadata>>(
+            Token::While,
+            |parser, compiler, lexer| {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::While, span) => span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::While),
+                            found: Box::new(token),
+                            reason: "expected while statement".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                // Parse condition
+                let condition = parser.parse(compiler, lexer, Precedence::Lowest)?;
+
+                // Parse out the block
+                let body = match parser.parse::<Expression<UntypedAstMetadata>, _>(
+                    compiler,
+                    lexer,
+                    Precedence::Lowest,
+                )? {
+                    Expression::<UntypedAstMetadata>::Block(block) => block,
+                    expression => {
+                        return Err(ParseError::ExpectedBlock {
+                            span: expression.span().clone(),
+                        });
+                    }
+                };
+
+                Ok(Expression::While(While {
+                    label: None,
+                    span: start_span.start..body.span.end,
+                    condition: Box::new(condition),
+                    body,
+                    ty_info: None,
+                }))
+            }
+        ));
+    }
+}
+
+impl SolveType for While<UntypedAstMetadata> {
+    type State = Scope;
+
+    fn solve(
+        self,
+        compiler: &mut crate::compiler::Compiler,
+        state: &mut Self::State,
+    ) -> Result<Self::Typed, TyError> {
+        // Make sure the condition is correctly typed
+        let condition = self.condition.solve(compiler, state)?;
+        let condition_ty = condition.get_ty_info();
+        if !condition_ty.ty.check(&Ty::Boolean) {
+            return Err(TyError::Mismatch {
+                expected: Ty::Boolean.name(compiler),
+                found: condition_ty.ty.name(compiler),
+                span: condition.span().clone(),
+                expected_span: None,
+            });
+        }
+
+        // Type check the body, tracking any breaks out of it
+        state.enter_loop(self.label);
+        let body = self.body.solve(compiler, state);
+        let breaks = state.leave_loop();
+        let body = body?;
+
+        // The loop may finish without a break, so breaks cannot produce a value
+        if let Some((found, span)) = breaks.into_iter().find(|(ty, _)| !ty.check(&Ty::Unit)) {
+            return Err(TyError::Mismatch {
+                expected: Ty::Unit.name(compiler),
+                found: found.name(compiler),
+                span,
+                expected_span: None,
+            });
+        }
+
+        match body.ty_info.ty {
+            Ty::Unit | Ty::Never => (),
+            ref ty => {
+                return Err(TyError::Mismatch {
+                    expected: Ty::Unit.name(compiler),
+                    found: ty.name(compiler),
+                    span: body.span,
+                    expected_span: None,
+                });
+            }
+        };
+
+        // The condition may fail on the first check, so the loop is always unit
+        let ty_info = TyInfo::try_from((
+            Ty::Unit,
+            [
+                condition_ty.return_ty.clone(),
+                body.ty_info.return_ty.clone(),
+            ],
+        ))
+        .map_err(|e| e.at(compiler, self.span.clone()))?;
+
+        Ok(While {
+            label: self.label,
+            ty_info,
+            condition: Box::new(condition),
+            body,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    mod parse {
+        use crate::stage::parse::Lexer;
+
+        use super::*;
+
+        #[fixture]
+        fn parser() -> Parser {
+            let mut parser = Parser::new();
+
+            While::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            ExpressionStatement::<UntypedAstMetadata>::register(&mut parser);
+            Block::<UntypedAstMetadata>::register(&mut parser);
+            Boolean::<UntypedAstMetadata>::register(&mut parser);
+            Integer::<UntypedAstMetadata>::register(&mut parser);
+            Infix::<UntypedAstMetadata>::register(&mut parser);
+
+            parser
+        }
+
+        #[rstest]
+        #[case::empty("while true { }")]
+        #[case::infix_condition("while 1 < 2 { 1; }")]
+        #[case::nested_while("while true { while false { 1; } }")]
+        fn success(parser: Parser, #[case] source: &str) {
+            let l: Expression<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            assert!(matches!(l, Expression::While(_)));
+        }
+
+        #[rstest]
+        #[case::missing_condition("while { }")]
+        #[case::missing_block("while true")]
+        #[case::expression_without_block("while true 1")]
+        fn fail(parser: Parser, #[case] source: &str) {
+            assert!(parser
+                .parse::<Expression<UntypedAstMetadata>, _>(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest
+                )
+                .is_err());
+        }
+    }
+
+    mod ty {
+        use super::*;
+
+        #[rstest]
+        fn non_boolean_condition() {
+            let w = While::new(
+                None,
+                Box::new(Expression::integer(1, Span::default())),
+                Block::new(vec![], Span::default(), None),
+                Span::default(),
+                None,
+            );
+
+            assert!(matches!(
+                w.solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::Mismatch { expected, .. }) if expected == "bool"
+            ));
+        }
+
+        #[rstest]
+        fn break_with_value() {
+            let w = While::new(
+                None,
+                Box::new(Expression::boolean(true, Span::default())),
+                Block::new(
+                    vec![Statement::Break(Break::new(
+                        None,
+                        Some(Expression::integer(1, Span::default())),
+                        Span::default(),
+                        None,
+                    ))],
+                    Span::default(),
+                    None,
+                ),
+                Span::default(),
+                None,
+            );
+
+            assert!(matches!(
+                w.solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "()" && found == "int"
+            ));
+        }
+    }
+}
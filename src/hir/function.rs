@@ -3,9 +3,11 @@ use super::*;
 ast_node! {
     Function<M> {
         name: M::FnIdentifier,
-        parameters: Vec<(M::IdentIdentifier, Ty)>,
+        parameters: Vec<(M::IdentIdentifier, Ty, bool, M::Span)>,
         return_ty: Ty,
+        return_ty_span: M::Span,
         body: Block<M>,
+        docs: Vec<String>,
         span,
     }
 }
@@ -24,18 +26,49 @@ impl SolveType for Function<UntypedAstMetadata> {
             .expect("function must already be registered");
 
         // Create the scope for this function
-        let mut scope = Scope::new();
+        let mut scope =
+            Scope::for_function(idx, self.return_ty.clone(), self.return_ty_span.clone());
 
         // Add all of the function's parameters into the scope so they're accessible
-        let parameters = self
+        let parameters: Vec<_> = self
             .parameters
             .iter()
-            .map(|(symbol, ty)| (scope.register(*symbol, ty.clone()), ty.clone()))
+            .map(|(symbol, ty, mutable, span)| {
+                let binding = if *mutable {
+                    scope.register_mut(*symbol, ty.clone(), span.clone())
+                } else {
+                    scope.register(*symbol, ty.clone(), span.clone())
+                };
+
+                (binding, ty.clone(), *mutable, span.clone())
+            })
             .collect();
 
         // Type check the body, allowing it to use the function's scope
         let body = self.body.solve(compiler, &mut scope)?;
 
+        // Warn about any bindings whose values are never read
+        let warnings = scope
+            .unused()
+            .filter(|(_, symbol, _)| !compiler.is_silenced(*symbol))
+            .map(|(binding, symbol, span)| {
+                let name = compiler.symbols.resolve(symbol).unwrap().to_string();
+                let span = span.clone();
+
+                if parameters
+                    .iter()
+                    .any(|(parameter, _, _, _)| *parameter == binding)
+                {
+                    Warning::UnusedParameter { name, span }
+                } else {
+                    Warning::UnusedBinding { name, span }
+                }
+            })
+            .collect::<Vec<_>>();
+        warnings
+            .into_iter()
+            .for_each(|warning| compiler.warn(warning));
+
         // Access this function's registration
         let function = compiler
             .functions
@@ -47,24 +80,149 @@ impl SolveType for Function<UntypedAstMetadata> {
             .into_iter()
             .for_each(|(binding, symbol, ty)| function.register_binding(binding, symbol, ty));
 
-        // If the body contains any return statements, they must match the annotated return statement
-        if let Some(return_ty) = &body.ty_info.return_ty {
-            if !self.return_ty.check(return_ty) {
-                return Err(TyError::Mismatch(self.return_ty, return_ty.clone()));
-            }
+        // A body which can complete without producing a value has a path that doesn't return
+        if body.ty_info.ty == Ty::Unit && self.return_ty != Ty::Unit {
+            return Err(TyError::MissingReturn {
+                ty: self.return_ty.name(compiler),
+                // Point at the statement where control flow can fall out of the body
+                span: body
+                    .statements
+                    .last()
+                    .map(|statement| statement.span().clone())
+                    .unwrap_or(body.span),
+                return_ty_span: self.return_ty_span,
+            });
         }
 
         // Ensure inferred return types match
         if !body.ty_info.ty.check(&Ty::Unit) && !self.return_ty.check(&body.ty_info.ty) {
-            return Err(TyError::Mismatch(self.return_ty, body.ty_info.ty));
+            return Err(TyError::Mismatch {
+                expected: self.return_ty.name(compiler),
+                found: body.ty_info.ty.name(compiler),
+                // Point at the statement producing the implicit return
+                span: body
+                    .statements
+                    .last()
+                    .map(|statement| statement.span().clone())
+                    .unwrap_or(body.span),
+                expected_span: Some(self.return_ty_span),
+            });
         }
 
         Ok(Function {
             name: idx,
             parameters,
             return_ty: self.return_ty,
+            return_ty_span: self.return_ty_span,
             body,
+            docs: self.docs,
             span: self.span,
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compiler::CompilerError;
+    use rstest::*;
+
+    #[rstest]
+    #[case::implicit("fn main() -> int { 1 }")]
+    #[case::explicit("fn main() -> int { return 1; }")]
+    #[case::if_else("fn main() -> int { if true { return 1; } else { return 2; } }")]
+    #[case::if_else_terminated("fn main() -> int { if true { return 1; } else { return 2; }; }")]
+    #[case::infinite_loop("fn main() -> int { loop { let _a = 1; } }")]
+    #[case::loop_break_value("fn main() -> int { loop { break 1; } }")]
+    #[case::loop_returns("fn main() -> int { loop { if true { return 1; } } }")]
+    #[case::unreachable_tail("fn main() -> int { return 1; let _a = 2; }")]
+    #[case::empty_block("fn main() -> int { {}; 1 }")]
+    #[case::empty_branches("fn main() -> int { if true { } else { }; 1 }")]
+    #[case::let_diverges("fn main() -> int { let _x = loop { return 1; }; }")]
+    #[case::tuple_diverges("fn main() -> int { let _t = (1, loop { return 2; }); 3 }")]
+    #[case::else_diverges(
+        "fn main() -> int { let c = true; let x = if c { 1 } else { return 2; }; x }"
+    )]
+    #[case::literal_takes_return_ty("fn main() -> int { f() as int } fn f() -> u8 { return 200; }")]
+    fn returns(#[case] source: &str) {
+        assert!(Compiler::default().compile(source).is_ok());
+    }
+
+    #[rstest]
+    #[case::mutable(
+        "fn main() -> int { f(1) } fn f(mut a: int) -> int { a += 1; a }",
+        true
+    )]
+    #[case::immutable("fn main() -> int { f(1) } fn f(a: int) -> int { a += 1; a }", false)]
+    #[case::mutable_pattern(
+        "fn main() -> int { f((1, 2)) } fn f((mut a, b): (int, int)) -> int { a = b; a }",
+        true
+    )]
+    #[case::immutable_pattern(
+        "fn main() -> int { f((1, 2)) } fn f((a, mut b): (int, int)) -> int { a = b; a }",
+        false
+    )]
+    fn mutable_parameters(#[case] source: &str, #[case] success: bool) {
+        let result = Compiler::default().compile(source);
+
+        if success {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(
+                result,
+                Err(CompilerError::Ty(TyError::AssignToImmutable { .. }))
+            ));
+        }
+    }
+
+    #[rstest]
+    #[case::empty("fn main() -> int { }")]
+    #[case::if_without_else("fn main() -> int { if true { return 1; } }")]
+    #[case::loop_with_break("fn main() -> int { loop { if true { break; } } }")]
+    #[case::while_loop("fn main() -> int { while true { return 1; } }")]
+    #[case::terminated_value("fn main() -> int { 1; }")]
+    fn missing_return(#[case] source: &str) {
+        assert!(matches!(
+            Compiler::default().compile(source),
+            Err(CompilerError::Ty(TyError::MissingReturn { ty, .. })) if ty == "int"
+        ));
+    }
+
+    #[rstest]
+    #[case::single("fn main() -> int { return true; }", "return true;")]
+    #[case::after_valid(
+        "fn main() -> int { if true { return 1; }; return false; }",
+        "return false;"
+    )]
+    #[case::before_valid(
+        "fn main() -> int { if true { return false; }; return 1; }",
+        "return false;"
+    )]
+    fn return_mismatch(#[case] source: &str, #[case] statement: &str) {
+        let Err(CompilerError::Ty(TyError::Mismatch {
+            expected,
+            found,
+            span,
+            expected_span: Some(expected_span),
+        })) = Compiler::default().compile(source)
+        else {
+            panic!("expected return to mismatch");
+        };
+
+        assert_eq!(expected, "int");
+        assert_eq!(found, "bool");
+        assert_eq!(&source[span], statement);
+        assert_eq!(&source[expected_span], "int");
+    }
+
+    #[rstest]
+    #[case::break_in_body("fn main() -> int { break; }")]
+    #[case::continue_in_body("fn main() -> int { continue; }")]
+    #[case::break_in_branch("fn main() -> int { if true { break; }; 1 }")]
+    fn outside_loop(#[case] source: &str) {
+        assert!(matches!(
+            Compiler::default().compile(source),
+            Err(CompilerError::Ty(TyError::OutsideLoop { .. }))
+        ));
+    }
+}
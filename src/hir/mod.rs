@@ -5,13 +5,14 @@ mod statement;
 
 use crate::{
     ast_node,
-    compiler::Compiler,
+    compiler::{Compiler, Symbol, Warning},
     repr::{
         ast::{untyped::UntypedAstMetadata, AstMetadata},
+        identifier::{EnumIdx, StructIdx},
         token::Token,
     },
     stage::parse::{parser::Parser, Lexer, ParseError, Precedence},
-    ty::{Ty, TyError, TyInfo},
+    ty::{Ty, TyError, TyInfo, TyMismatch},
     util::{scope::Scope, span::Span},
 };
 
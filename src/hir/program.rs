@@ -18,9 +18,22 @@ impl SolveType for Program<UntypedAstMetadata> {
         compiler: &mut crate::compiler::Compiler,
         _state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
+        // Types which contain themselves can never be constructed or represented
+        if let Some((symbol, span)) = compiler.types.find_infinite() {
+            return Err(TyError::InfiniteSize {
+                name: compiler.symbols.resolve(symbol).unwrap().to_string(),
+                span: span.clone(),
+            });
+        }
+
         // Main function must return int
         if !self.main.return_ty.check(&Ty::Int) {
-            return Err(TyError::Mismatch(Ty::Int, self.main.return_ty));
+            return Err(TyError::Mismatch {
+                expected: Ty::Int.name(compiler),
+                found: self.main.return_ty.name(compiler),
+                span: self.main.return_ty_span,
+                expected_span: None,
+            });
         }
 
         compiler
@@ -43,6 +56,23 @@ impl SolveType for Program<UntypedAstMetadata> {
             .map(|function| function.solve(compiler, &mut ()))
             .collect::<Result<Vec<_>, _>>()?;
 
+        // Warn about any functions which can never be called from the entry point
+        let reachable = compiler.functions.reachable(main.name);
+        let warnings = functions
+            .iter()
+            .filter(|function| !reachable.contains(&function.name))
+            .filter_map(|function| {
+                let symbol = compiler.functions.symbol_for(function.name)?;
+                (!compiler.is_silenced(symbol)).then(|| Warning::UnusedFunction {
+                    name: compiler.symbols.resolve(symbol).unwrap().to_string(),
+                    span: function.span.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        warnings
+            .into_iter()
+            .for_each(|warning| compiler.warn(warning));
+
         Ok(Program {
             main,
             functions,
@@ -50,3 +80,64 @@ impl SolveType for Program<UntypedAstMetadata> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compiler::CompilerError;
+    use rstest::*;
+
+    #[rstest]
+    #[case::direct("struct Node { v: int, next: Node }", "Node")]
+    #[case::tuple("struct Node { next: (int, Node) }", "Node")]
+    #[case::array("struct Node { next: [Node; 2] }", "Node")]
+    #[case::mutual("struct A { b: B } struct B { a: A }", "A")]
+    #[case::enumeration("enum List { Cons(int, List), Nil }", "List")]
+    #[case::through_enum("struct Node { next: Link } enum Link { Next(Node), End }", "Node")]
+    fn infinite_size(#[case] types: &str, #[case] expected: &str) {
+        let source = format!("{types} fn main() -> int {{ 1 }}");
+
+        match Compiler::default().compile(&source) {
+            Err(CompilerError::Ty(TyError::InfiniteSize { name, span })) => {
+                assert_eq!(name, expected);
+                assert_eq!(&source[span], expected);
+            }
+            result => panic!("expected infinite size error, found {result:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::nested("struct A { b: B } struct B { v: int }")]
+    #[case::repeated("struct A { first: B, second: (B, B) } struct B { v: int }")]
+    #[case::shared("struct A { b: B } struct B { v: int } enum E { One(A), Two(A) }")]
+    fn finite_size(#[case] types: &str) {
+        let source = format!("{types} fn main() -> int {{ 1 }}");
+
+        assert!(Compiler::default().compile(source).is_ok());
+    }
+
+    #[rstest]
+    #[case::structure("let _p: Point = 1;", "Point", "int")]
+    #[case::enumeration("let _s: Shape = Point { x: 1 };", "Shape", "Point")]
+    #[case::array("let _a: [bool; 2] = (1, true);", "[bool; 2]", "(int, bool)")]
+    #[case::unit("let _u: () = 1.5;", "()", "f64")]
+    fn mismatch_names(
+        #[case] statement: &str,
+        #[case] expected_name: &str,
+        #[case] found_name: &str,
+    ) {
+        let source = format!(
+            "struct Point {{ x: int }} enum Shape {{ Dot }} fn main() -> int {{ {statement} 1 }}"
+        );
+
+        match Compiler::default().compile(&source) {
+            Err(CompilerError::Ty(TyError::Mismatch {
+                expected, found, ..
+            })) => {
+                assert_eq!(expected, expected_name);
+                assert_eq!(found, found_name);
+            }
+            result => panic!("expected mismatch, found {result:?}"),
+        }
+    }
+}
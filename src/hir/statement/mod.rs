@@ -4,10 +4,15 @@ mod s_expression;
 mod s_let;
 mod s_return;
 
+use crate::repr::ast::typed::TypedAstMetadata;
+
 use super::*;
 
 pub use self::{
-    s_break::Break, s_continue::Continue, s_expression::ExpressionStatement, s_let::Let,
+    s_break::Break,
+    s_continue::Continue,
+    s_expression::ExpressionStatement,
+    s_let::{BindingPattern, Let},
     s_return::Return,
 };
 
@@ -21,6 +26,19 @@ ast_node! {
     )
 }
 
+impl Statement<TypedAstMetadata> {
+    /// Whether control flow can never continue past this statement, such as a `return`.
+    pub fn diverges(&self) -> bool {
+        match self {
+            // Terminating an expression hides its type, so check the expression itself
+            Statement::ExpressionStatement(s) => s.expression.get_ty_info().ty == Ty::Never,
+            // Binding a value which never completes means the statement never completes
+            Statement::Let(s) => s.value.get_ty_info().ty == Ty::Never,
+            s => s.get_ty_info().ty == Ty::Never,
+        }
+    }
+}
+
 impl SolveType for Statement<UntypedAstMetadata> {
     type State = Scope;
 
@@ -57,7 +75,16 @@ impl<M: AstMetadata<TyInfo: Default>> Statement<M> {
     }
 
     pub fn _let(name: M::IdentIdentifier, value: Expression<M>, span: M::Span) -> Self {
-        Self::Let(Let::new(name, value, span, M::TyInfo::default()))
+        Self::Let(Let::new(
+            BindingPattern::Ident {
+                name,
+                mutable: false,
+                span: span.clone(),
+            },
+            value,
+            span,
+            M::TyInfo::default(),
+        ))
     }
 
     pub fn expression(expression: Expression<M>, terminated: bool, span: M::Span) -> Self {
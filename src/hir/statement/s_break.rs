@@ -2,6 +2,8 @@ use super::*;
 
 ast_node! {
     Break<M> {
+        label: Option<Symbol>,
+        value: Option<Expression<M>>,
         span,
         ty_info,
     }
@@ -11,31 +13,44 @@ impl<M: AstMetadata> Parsable for Break<M> {
     fn register(parser: &mut Parser) {
         assert!(parser.register_prefix::<Statement<UntypedAstMetadata>>(
             Token::Break,
-            |_, _, lexer| {
-                let break_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+            |parser, compiler, lexer| {
+                let break_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::Break, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Break),
                             found: Box::new(token),
                             reason: "expected break statement".to_string(),
+                            span,
                         });
                     }
                 };
 
-                // Parse out the semicolon
-                let semicolon_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
-                    (Token::SemiColon, span) => span,
-                    (token, _) => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::SemiColon),
-                            found: Box::new(token),
-                            reason: "expected break statement to finish with semicolon".to_string(),
-                        });
-                    }
+                // Parse out the label of the loop to target, if present
+                let label = if let Some(Token::Label(label)) = lexer.peek_token() {
+                    let label = compiler.symbols.get_or_intern(label);
+                    lexer.next_token();
+                    Some(label)
+                } else {
+                    None
+                };
+
+                // Parse out the value to break with, if present
+                let value = if matches!(lexer.peek_token(), Some(Token::SemiColon)) {
+                    None
+                } else {
+                    Some(parser.parse(compiler, lexer, Precedence::Lowest)?)
                 };
 
+                // Parse out the semicolon
+                let semicolon_span =
+                    lexer.expect_semicolon("expected break statement to finish with semicolon")?;
+
                 Ok(Statement::Break(Break {
+                    label,
+                    value,
                     span: break_span.start..semicolon_span.end,
                     ty_info: None,
                 }))
@@ -49,14 +64,42 @@ impl SolveType for Break<UntypedAstMetadata> {
 
     fn solve(
         self,
-        _compiler: &mut Compiler,
-        _state: &mut Self::State,
+        compiler: &mut Compiler,
+        state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
+        let value = self
+            .value
+            .map(|value| value.solve(compiler, state))
+            .transpose()?;
+
+        // Make sure that the targeted loop exists
+        let Some(breaks) = state.resolve_loop(self.label) else {
+            return Err(match self.label {
+                Some(label) => TyError::UnknownLabel {
+                    label: compiler.symbols.resolve(label).unwrap().to_string(),
+                    span: self.span,
+                },
+                None => TyError::OutsideLoop { span: self.span },
+            });
+        };
+
+        // The targeted loop will produce the value
+        breaks.push(
+            value
+                .as_ref()
+                .map(|value| (value.get_ty_info().ty.clone(), value.span().clone()))
+                .unwrap_or((Ty::Unit, self.span.clone())),
+        );
+
         Ok(Break {
             ty_info: TyInfo {
                 ty: Ty::Never,
-                return_ty: None,
+                return_ty: value
+                    .as_ref()
+                    .and_then(|value| value.get_ty_info().return_ty.clone()),
             },
+            label: self.label,
+            value,
             span: self.span,
         })
     }
@@ -76,12 +119,20 @@ mod test {
         fn parser() -> Parser {
             let mut parser = Parser::new();
             Break::<UntypedAstMetadata>::register(&mut parser);
+
+            // Helpers
+            Expression::<UntypedAstMetadata>::register(&mut parser);
+
             parser
         }
 
         #[rstest]
-        #[case("break;")]
-        fn success(parser: Parser, #[case] source: &str) {
+        #[case::no_value("break;", false)]
+        #[case::value("break 42;", true)]
+        #[case::expression_value("break 1 + 2;", true)]
+        #[case::labelled("break 'outer;", false)]
+        #[case::labelled_value("break 'outer 42;", true)]
+        fn success(parser: Parser, #[case] source: &str, #[case] has_value: bool) {
             let b: Statement<UntypedAstMetadata> = parser
                 .parse(
                     &mut Compiler::default(),
@@ -90,11 +141,16 @@ mod test {
                 )
                 .unwrap();
 
-            assert!(matches!(b, Statement::Break(_)));
+            let Statement::Break(b) = b else {
+                panic!("expected to parse break statement");
+            };
+
+            assert_eq!(b.value.is_some(), has_value);
         }
 
         #[rstest]
         #[case::missing_semicolon("break")]
+        #[case::value_missing_semicolon("break 42")]
         fn fail(parser: Parser, #[case] source: &str) {
             assert!(parser
                 .parse::<Statement<UntypedAstMetadata>, _>(
@@ -105,4 +161,56 @@ mod test {
                 .is_err());
         }
     }
+
+    mod ty {
+        use super::*;
+
+        #[rstest]
+        fn outside_loop() {
+            assert!(matches!(
+                Break::new(None, None, Span::default(), None)
+                    .solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::OutsideLoop { .. })
+            ));
+        }
+
+        #[rstest]
+        fn unknown_label() {
+            let mut compiler = Compiler::default();
+            let outer = compiler.symbols.get_or_intern("outer");
+            let inner = compiler.symbols.get_or_intern("inner");
+
+            let mut scope = Scope::new();
+            scope.enter_loop(Some(inner));
+
+            assert!(matches!(
+                Break::new(Some(outer), None, Span::default(), None)
+                    .solve(&mut compiler, &mut scope),
+                Err(TyError::UnknownLabel { label, .. }) if label == "outer"
+            ));
+        }
+
+        #[rstest]
+        fn labelled_break() {
+            let mut compiler = Compiler::default();
+            let outer = compiler.symbols.get_or_intern("outer");
+
+            let mut scope = Scope::new();
+            scope.enter_loop(Some(outer));
+            scope.enter_loop(None);
+
+            Break::new(
+                Some(outer),
+                Some(Expression::integer(1, Span::default())),
+                Span::default(),
+                None,
+            )
+            .solve(&mut compiler, &mut scope)
+            .unwrap();
+
+            // Only the labelled loop should be broken out of
+            assert!(scope.leave_loop().is_empty());
+            assert_eq!(scope.leave_loop()[0].0, Ty::Int);
+        }
+    }
 }
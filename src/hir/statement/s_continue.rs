@@ -2,6 +2,7 @@ use super::*;
 
 ast_node! {
     Continue<TyInfo> {
+        label: Option<Symbol>,
         span,
         ty_info,
     }
@@ -11,32 +12,36 @@ impl<M: AstMetadata> Parsable for Continue<M> {
     fn register(parser: &mut Parser) {
         assert!(parser.register_prefix::<Statement<UntypedAstMetadata>>(
             Token::Continue,
-            |_, _, lexer| {
-                let continue_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+            |_, compiler, lexer| {
+                let continue_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::Continue, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Continue),
                             found: Box::new(token),
                             reason: "expected continue statement".to_string(),
+                            span,
                         });
                     }
                 };
 
-                // Parse out the semicolon
-                let semicolon_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
-                    (Token::SemiColon, span) => span,
-                    (token, _) => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::SemiColon),
-                            found: Box::new(token),
-                            reason: "expected continue statement to finish with semicolon"
-                                .to_string(),
-                        });
-                    }
+                // Parse out the label of the loop to target, if present
+                let label = if let Some(Token::Label(label)) = lexer.peek_token() {
+                    let label = compiler.symbols.get_or_intern(label);
+                    lexer.next_token();
+                    Some(label)
+                } else {
+                    None
                 };
 
+                // Parse out the semicolon
+                let semicolon_span = lexer
+                    .expect_semicolon("expected continue statement to finish with semicolon")?;
+
                 Ok(Statement::Continue(Continue {
+                    label,
                     span: continue_span.start..semicolon_span.end,
                     ty_info: None,
                 }))
@@ -50,10 +55,22 @@ impl SolveType for Continue<UntypedAstMetadata> {
 
     fn solve(
         self,
-        _compiler: &mut Compiler,
-        _state: &mut Self::State,
+        compiler: &mut Compiler,
+        state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
+        // Make sure that the targeted loop exists
+        if state.resolve_loop(self.label).is_none() {
+            return Err(match self.label {
+                Some(label) => TyError::UnknownLabel {
+                    label: compiler.symbols.resolve(label).unwrap().to_string(),
+                    span: self.span,
+                },
+                None => TyError::OutsideLoop { span: self.span },
+            });
+        }
+
         Ok(Continue {
+            label: self.label,
             ty_info: TyInfo {
                 ty: Ty::Never,
                 return_ty: None,
@@ -81,7 +98,8 @@ mod test {
         }
 
         #[rstest]
-        #[case("continue;")]
+        #[case::unlabelled("continue;")]
+        #[case::labelled("continue 'outer;")]
         fn success(#[case] source: &str) {
             let mut parser = Parser::new();
             Continue::<UntypedAstMetadata>::register(&mut parser);
@@ -99,6 +117,7 @@ mod test {
 
         #[rstest]
         #[case::missing_semicolon("continue")]
+        #[case::label_missing_semicolon("continue 'outer")]
         fn fail(parser: Parser, #[case] source: &str) {
             assert!(parser
                 .parse::<Statement<UntypedAstMetadata>, _>(
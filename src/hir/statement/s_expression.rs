@@ -16,9 +16,10 @@ impl<M: AstMetadata> Parsable for ExpressionStatement<M> {
                 let expression: Expression<UntypedAstMetadata> =
                     parser.parse(compiler, lexer, Precedence::Lowest)?;
 
-                let terminating_span = lexer
-                    .next_if(|(token, _)| matches!(token, Ok(Token::SemiColon)))
-                    .map(|(_, span)| span);
+                let terminating_span = match lexer.peek_token() {
+                    Some(Token::SemiColon) => lexer.next_spanned().map(|(_, span)| span),
+                    _ => None,
+                };
 
                 let expression_span = expression.span().clone();
                 let span = expression_span.start
@@ -51,6 +52,14 @@ impl SolveType for ExpressionStatement<UntypedAstMetadata> {
         // Expression statement has same type as the underlying expression
         let ty_info = expression.get_ty_info().clone();
 
+        // Terminating an expression discards its value
+        if self.terminated && !matches!(ty_info.ty, Ty::Unit | Ty::Never) {
+            compiler.warn(Warning::UnusedResult {
+                ty: ty_info.ty.name(compiler),
+                span: expression.span().clone(),
+            });
+        }
+
         Ok(ExpressionStatement {
             ty_info: TyInfo {
                 ty: if self.terminated {
@@ -1,51 +1,187 @@
+use crate::{repr::ast::typed::TypedAstMetadata, ty::TySpanned};
+
 use super::*;
 
 ast_node! {
     Let<M> {
-        binding: M::IdentIdentifier,
+        binding: BindingPattern<M>,
         value: Expression<M>,
         span,
         ty_info,
     }
 }
 
+/// Pattern which a value is bound to, destructuring tuples into their elements.
+#[derive(Clone, Debug)]
+pub enum BindingPattern<M: AstMetadata> {
+    /// Bind the entire value to a name, which is only mutable if declared with `mut`.
+    Ident {
+        name: M::IdentIdentifier,
+        mutable: bool,
+        span: M::Span,
+    },
+    /// Discard the value.
+    Wildcard,
+    /// Bind each element of a tuple to a nested pattern.
+    Tuple(Vec<BindingPattern<M>>),
+}
+
+impl BindingPattern<UntypedAstMetadata> {
+    /// Parse a binding pattern, which is a name optionally preceded by `mut`, `_`, or a
+    /// parenthesised list of patterns.
+    pub fn parse(compiler: &mut Compiler, lexer: &mut Lexer<'_>) -> Result<Self, ParseError> {
+        match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::Ident(name), _) if name == "_" => Ok(BindingPattern::Wildcard),
+            (Token::Ident(name), span) => Ok(BindingPattern::Ident {
+                name: compiler.symbols.get_or_intern(name),
+                mutable: false,
+                span,
+            }),
+            (Token::Mut, _) => match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })? {
+                (Token::Ident(name), span) if name != "_" => Ok(BindingPattern::Ident {
+                    name: compiler.symbols.get_or_intern(name),
+                    mutable: true,
+                    span,
+                }),
+                (token, span) => Err(ParseError::ExpectedIdentifier {
+                    found: Box::new(token),
+                    reason: "expected identifier following mut".to_string(),
+                    span,
+                }),
+            },
+            (Token::LeftParen, _) => {
+                let mut patterns = Vec::new();
+                loop {
+                    if matches!(lexer.peek_token(), Some(Token::RightParen)) {
+                        lexer.next_token();
+                        break;
+                    }
+
+                    patterns.push(BindingPattern::parse(compiler, lexer)?);
+
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Comma, _) => (),
+                        (Token::RightParen, _) => break,
+                        (token, span) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::Comma),
+                                found: Box::new(token),
+                                reason: "patterns must be separated by a comma".to_string(),
+                                span,
+                            });
+                        }
+                    }
+                }
+
+                Ok(BindingPattern::Tuple(patterns))
+            }
+            (token, span) => Err(ParseError::ExpectedIdentifier {
+                found: Box::new(token),
+                reason: "expected identifier or pattern for binding".to_string(),
+                span,
+            }),
+        }
+    }
+
+    /// Register each of the names bound by this pattern against the corresponding part of the
+    /// provided type.
+    pub fn solve(
+        self,
+        compiler: &Compiler,
+        state: &mut Scope,
+        ty: &Ty,
+        span: &Span,
+    ) -> Result<BindingPattern<TypedAstMetadata>, TyError> {
+        Ok(match self {
+            BindingPattern::Ident {
+                name,
+                mutable,
+                span,
+            } => BindingPattern::Ident {
+                name: if mutable {
+                    state.register_mut(name, ty.clone(), span.clone())
+                } else {
+                    state.register(name, ty.clone(), span.clone())
+                },
+                mutable,
+                span,
+            },
+            BindingPattern::Wildcard => BindingPattern::Wildcard,
+            BindingPattern::Tuple(patterns) => {
+                let tys = match ty {
+                    Ty::Tuple(tys) if tys.len() == patterns.len() => tys.clone(),
+                    // Values which never produce anything can be bound to any pattern
+                    Ty::Never => vec![Ty::Never; patterns.len()],
+                    _ => {
+                        return Err(TyError::Destructure {
+                            ty: ty.name(compiler),
+                            span: span.clone(),
+                        });
+                    }
+                };
+
+                BindingPattern::Tuple(
+                    patterns
+                        .into_iter()
+                        .zip(&tys)
+                        .map(|(pattern, ty)| pattern.solve(compiler, state, ty, span))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        })
+    }
+}
+
 impl<M: AstMetadata> Parsable for Let<M> {
     fn register(parser: &mut Parser) {
         assert!(parser.register_prefix::<Statement<UntypedAstMetadata>>(
             Token::Let,
             |parser, compiler, lexer| {
                 // Parse out `let`
-                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let start_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::Let, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Let),
                             found: Box::new(token),
                             reason: "expected let token".to_string(),
+                            span,
                         });
                     }
                 };
 
                 // Parse out binding
-                let binding = match lexer.next_token().ok_or(ParseError::UnexpectedEOF)? {
-                    Token::Ident(ident) => compiler.symbols.get_or_intern(ident),
-                    token => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::Ident(String::new())),
-                            found: Box::new(token),
-                            reason: "expected identifier for let statement".to_string(),
-                        });
-                    }
+                let binding = BindingPattern::parse(compiler, lexer)?;
+
+                // Parse out the optional type annotation
+                let ty = if matches!(lexer.peek_token(), Some(Token::Colon)) {
+                    lexer.next_token();
+
+                    let TySpanned { ty, .. } = parser.parse(compiler, lexer, Precedence::Lowest)?;
+                    Some(ty)
+                } else {
+                    None
                 };
 
                 // Parse out equals sign
-                match lexer.next_token().ok_or(ParseError::UnexpectedEOF)? {
-                    Token::Eq => (),
-                    token => {
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Eq, _) => (),
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Eq),
                             found: Box::new(token),
                             reason: "expected assignment following binding".to_string(),
+                            span,
                         });
                     }
                 }
@@ -55,22 +191,14 @@ impl<M: AstMetadata> Parsable for Let<M> {
                     parser.parse(compiler, lexer, Precedence::Lowest)?;
 
                 // Parse out the semicolon
-                let semicolon_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
-                    (Token::SemiColon, span) => span,
-                    (token, _) => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::SemiColon),
-                            found: Box::new(token),
-                            reason: "expected let statement to finish with semicolon".to_string(),
-                        });
-                    }
-                };
+                let semicolon_span =
+                    lexer.expect_semicolon("expected let statement to finish with semicolon")?;
 
                 Ok(Statement::Let(Let {
                     binding,
                     span: start_span.start..semicolon_span.end,
                     value,
-                    ty_info: None,
+                    ty_info: ty,
                 }))
             }
         ));
@@ -85,19 +213,34 @@ impl SolveType for Let<UntypedAstMetadata> {
         compiler: &mut Compiler,
         state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
+        // Literals in the value should take on the annotated type
+        let mut value = self.value;
+        if let Some(ty) = &self.ty_info {
+            value.expect_ty(ty);
+        }
+
         // Work out what the type of the value is
-        let value = self.value.solve(compiler, state)?;
+        let value = value.solve(compiler, state)?;
 
         // Make sure the value type matches what the statement was annotated with
-        if let Some(ty) = self.ty_info {
-            let value_ty = value.get_ty_info();
-            if !ty.check(&value_ty.ty) {
-                return Err(TyError::Mismatch(ty, value_ty.ty.clone()));
+        let ty = match self.ty_info {
+            Some(ty) => {
+                let value_ty = &value.get_ty_info().ty;
+                if !ty.check(value_ty) {
+                    return Err(TyMismatch {
+                        expected: ty,
+                        found: value_ty.clone(),
+                    }
+                    .at(compiler, value.span().clone()));
+                }
+
+                ty
             }
-        }
+            None => value.get_ty_info().ty.clone(),
+        };
 
-        // Record the type
-        let binding = state.register(self.binding, value.get_ty_info().ty.clone());
+        // Record the type of each bound name
+        let binding = self.binding.solve(compiler, state, &ty, &self.span)?;
 
         Ok(Let {
             ty_info: TyInfo {
@@ -129,6 +272,7 @@ mod test {
 
             // Extra helpers
             Integer::<UntypedAstMetadata>::register(&mut parser);
+            TySpanned::register(&mut parser);
 
             parser
         }
@@ -142,7 +286,12 @@ mod test {
                 .parse(&mut compiler, &mut Lexer::from(source), Precedence::Lowest)
                 .unwrap();
 
-            let Statement::Let(Let { binding, value, .. }) = s else {
+            let Statement::Let(Let {
+                binding: BindingPattern::Ident { name: binding, .. },
+                value,
+                ..
+            }) = s
+            else {
                 panic!("expected to parse let statement");
             };
 
@@ -154,9 +303,94 @@ mod test {
             ));
         }
 
+        /// Collect the mutability of each name bound by a pattern, in order.
+        fn mutability_of(pattern: &BindingPattern<UntypedAstMetadata>) -> Vec<bool> {
+            match pattern {
+                BindingPattern::Ident { mutable, .. } => vec![*mutable],
+                BindingPattern::Wildcard => vec![],
+                BindingPattern::Tuple(patterns) => {
+                    patterns.iter().flat_map(mutability_of).collect()
+                }
+            }
+        }
+
+        #[rstest]
+        #[case::immutable("let a = 1;", &[false])]
+        #[case::mutable("let mut a = 1;", &[true])]
+        #[case::mutable_element("let (mut a, b) = 1;", &[true, false])]
+        #[case::nested("let ((a, mut b), _, mut c) = 1;", &[false, true, true])]
+        fn mutability(parser: Parser, #[case] source: &str, #[case] expected: &[bool]) {
+            let s: Statement<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Statement::Let(Let { binding, .. }) = s else {
+                panic!("expected to parse let statement");
+            };
+
+            assert_eq!(mutability_of(&binding), expected);
+        }
+
+        #[rstest]
+        #[case::tuple("let (a, b) = 1;", 2)]
+        #[case::trailing_comma("let (a,) = 1;", 1)]
+        #[case::wildcard("let (_, b) = 1;", 2)]
+        #[case::nested("let ((a, b), c) = 1;", 2)]
+        fn pattern(parser: Parser, #[case] source: &str, #[case] count: usize) {
+            let s: Statement<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Statement::Let(Let {
+                binding: BindingPattern::Tuple(patterns),
+                ..
+            }) = s
+            else {
+                panic!("expected to parse tuple pattern");
+            };
+
+            assert_eq!(patterns.len(), count);
+        }
+
+        #[rstest]
+        #[case::none("let a = 1;", None)]
+        #[case::primitive("let a: uint = 1;", Some(Ty::Uint))]
+        #[case::tuple("let (a, b): (int, bool) = 1;", Some(Ty::Tuple(vec![Ty::Int, Ty::Boolean])))]
+        #[case::array("let a: [u8; 2] = 1;", Some(Ty::Array { inner: Box::new(Ty::U8), size: 2 }))]
+        fn annotation(parser: Parser, #[case] source: &str, #[case] expected: Option<Ty>) {
+            let s: Statement<UntypedAstMetadata> = parser
+                .parse(
+                    &mut Compiler::default(),
+                    &mut Lexer::from(source),
+                    Precedence::Lowest,
+                )
+                .unwrap();
+
+            let Statement::Let(Let { ty_info, .. }) = s else {
+                panic!("expected to parse let statement");
+            };
+
+            assert_eq!(ty_info, expected);
+        }
+
         #[rstest]
         #[case::missing_binding("let = 1;")]
+        #[case::mut_without_binding("let mut = 1;")]
+        #[case::mut_pattern("let mut (a, b) = 1;")]
+        #[case::mut_wildcard("let mut _ = 1;")]
+        #[case::unclosed_pattern("let (a, b = 1;")]
+        #[case::invalid_pattern("let (1, b) = 1;")]
         #[case::missing_equals("let a 1;")]
+        #[case::missing_annotation("let a: = 1;")]
+        #[case::unknown_annotation("let a: nothing = 1;")]
         #[case::missing_value("let a =;")]
         #[case::missing_semicolon("let a = 1")]
         fn fail(parser: Parser, #[case] source: &str) {
@@ -199,5 +433,242 @@ mod test {
                 Ty::Int
             );
         }
+
+        #[rstest]
+        #[case::immutable(false)]
+        #[case::mutable(true)]
+        fn mutability(#[case] mutable: bool) {
+            // let a = 0; or let mut a = 0;
+            let s = Let::new(
+                BindingPattern::Ident {
+                    name: Symbol::try_from_usize(0).unwrap(),
+                    mutable,
+                    span: 4..5,
+                },
+                Expression::integer(0, 8..9),
+                0..10,
+                None,
+            );
+
+            let mut scope = Scope::new();
+
+            let Let {
+                binding: BindingPattern::Ident { name: binding, .. },
+                ..
+            } = s.solve(&mut Compiler::default(), &mut scope).unwrap()
+            else {
+                panic!("expected binding to be an identifier");
+            };
+
+            assert_eq!(scope.get_mutability(&binding), (mutable, &(4..5)));
+        }
+
+        #[test]
+        fn destructure() {
+            // let (a, _) = (0, true);
+            let a = Symbol::try_from_usize(0).unwrap();
+            let s = Statement::Let(Let::new(
+                BindingPattern::Tuple(vec![
+                    BindingPattern::Ident {
+                        name: a,
+                        mutable: false,
+                        span: Span::default(),
+                    },
+                    BindingPattern::Wildcard,
+                ]),
+                Expression::Tuple(Tuple::new(
+                    vec![
+                        Expression::integer(0, Span::default()),
+                        Expression::boolean(true, Span::default()),
+                    ],
+                    Span::default(),
+                    None,
+                )),
+                Span::default(),
+                None,
+            ));
+
+            let mut scope = Scope::new();
+
+            s.solve(&mut Compiler::default(), &mut scope).unwrap();
+
+            assert_eq!(scope.resolve(a).unwrap().1, Ty::Int);
+        }
+
+        fn annotated(
+            ty: Ty,
+            value: Expression<UntypedAstMetadata>,
+        ) -> (Scope, Result<Let<TypedAstMetadata>, TyError>) {
+            // let a: ty = value;
+            let s = Let::new(
+                BindingPattern::Ident {
+                    name: Symbol::try_from_usize(0).unwrap(),
+                    mutable: false,
+                    span: Span::default(),
+                },
+                value,
+                Span::default(),
+                Some(ty),
+            );
+
+            let mut scope = Scope::new();
+            let result = s.solve(&mut Compiler::default(), &mut scope);
+
+            (scope, result)
+        }
+
+        #[rstest]
+        #[case::unsigned(Ty::U8, Expression::integer(5, Span::default()))]
+        #[case::float(Ty::F32, Expression::Float(Float::new(1.5, Span::default(), None)))]
+        #[case::negative(
+            Ty::I8,
+            Expression::Prefix(Prefix::new(
+                PrefixOperation::Minus,
+                Box::new(Expression::integer(128, Span::default())),
+                Span::default(),
+                None,
+            ))
+        )]
+        #[case::infix(
+            Ty::U16,
+            Expression::infix(
+                Expression::integer(1, Span::default()),
+                InfixOperation::plus(),
+                Expression::integer(2, Span::default()),
+            )
+        )]
+        #[case::array(
+            Ty::Array { inner: Box::new(Ty::U32), size: 2 },
+            Expression::Array(Array::new(
+                vec![
+                    Expression::integer(1, Span::default()),
+                    Expression::integer(2, Span::default()),
+                ],
+                Span::default(),
+                None,
+            )),
+        )]
+        #[case::empty_array(
+            Ty::Array { inner: Box::new(Ty::Int), size: 0 },
+            Expression::Array(Array::new(vec![], Span::default(), None)),
+        )]
+        #[case::tuple(
+            Ty::Tuple(vec![Ty::Uint, Ty::Boolean]),
+            Expression::Tuple(Tuple::new(
+                vec![
+                    Expression::integer(1, Span::default()),
+                    Expression::boolean(true, Span::default()),
+                ],
+                Span::default(),
+                None,
+            )),
+        )]
+        fn annotation(#[case] ty: Ty, #[case] value: Expression<UntypedAstMetadata>) {
+            let (mut scope, result) = annotated(ty.clone(), value);
+
+            let s = result.unwrap();
+
+            assert_eq!(s.value.get_ty_info().ty, ty);
+            assert_eq!(
+                scope.resolve(Symbol::try_from_usize(0).unwrap()).unwrap().1,
+                ty
+            );
+        }
+
+        #[test]
+        fn annotation_mismatch() {
+            let (_, result) = annotated(Ty::Int, Expression::boolean(true, Span::default()));
+
+            assert!(matches!(
+                result,
+                Err(TyError::Mismatch {
+                    expected,
+                    found,
+                    ..
+                }) if expected == "int" && found == "bool"
+            ));
+        }
+
+        #[test]
+        fn annotation_length_mismatch() {
+            let (_, result) = annotated(
+                Ty::Array {
+                    inner: Box::new(Ty::Int),
+                    size: 3,
+                },
+                Expression::Array(Array::new(
+                    vec![
+                        Expression::integer(1, Span::default()),
+                        Expression::integer(2, Span::default()),
+                    ],
+                    Span::default(),
+                    None,
+                )),
+            );
+
+            assert!(matches!(
+                result,
+                Err(TyError::ArrayLength {
+                    expected: 3,
+                    found: 2,
+                    ..
+                })
+            ));
+        }
+
+        #[rstest]
+        #[case::unsigned(Ty::U8, 256)]
+        #[case::signed(Ty::I8, 128)]
+        fn annotation_out_of_range(#[case] ty: Ty, #[case] value: u64) {
+            let (_, result) = annotated(ty.clone(), Expression::integer(value, Span::default()));
+
+            assert!(matches!(
+                result,
+                Err(TyError::LiteralOutOfRange { ty: found, .. })
+                    if found == ty.name(&Compiler::default())
+            ));
+        }
+
+        #[rstest]
+        #[case::not_a_tuple(vec![Expression::integer(0, Span::default())], false)]
+        #[case::wrong_arity(vec![
+            Expression::integer(0, Span::default()),
+            Expression::integer(1, Span::default()),
+            Expression::integer(2, Span::default()),
+        ], true)]
+        fn destructure_mismatch(
+            #[case] values: Vec<Expression<UntypedAstMetadata>>,
+            #[case] tuple: bool,
+        ) {
+            // let (a, b) = ...;
+            let value = if tuple {
+                Expression::Tuple(Tuple::new(values, Span::default(), None))
+            } else {
+                values.into_iter().next().unwrap()
+            };
+
+            let s = Statement::Let(Let::new(
+                BindingPattern::Tuple(vec![
+                    BindingPattern::Ident {
+                        name: Symbol::try_from_usize(0).unwrap(),
+                        mutable: false,
+                        span: Span::default(),
+                    },
+                    BindingPattern::Ident {
+                        name: Symbol::try_from_usize(1).unwrap(),
+                        mutable: false,
+                        span: Span::default(),
+                    },
+                ]),
+                value,
+                Span::default(),
+                None,
+            ));
+
+            assert!(matches!(
+                s.solve(&mut Compiler::default(), &mut Scope::new()),
+                Err(TyError::Destructure { .. })
+            ));
+        }
     }
 }
@@ -14,13 +14,16 @@ impl<M: AstMetadata> Parsable for Return<M> {
             Token::Return,
             |parser, compiler, lexer| {
                 // Parse out the return keyword
-                let span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
+                let span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
                     (Token::Return, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::Return),
                             found: Box::new(token),
                             reason: "expected return keyword".to_string(),
+                            span,
                         });
                     }
                 };
@@ -30,17 +33,8 @@ impl<M: AstMetadata> Parsable for Return<M> {
                     parser.parse(compiler, lexer, Precedence::Lowest)?;
 
                 // Parse out the semicolon
-                let semicolon_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF)? {
-                    (Token::SemiColon, span) => span,
-                    (token, _) => {
-                        return Err(ParseError::ExpectedToken {
-                            expected: Box::new(Token::SemiColon),
-                            found: Box::new(token),
-                            reason: "expected return statement to finish with semicolon"
-                                .to_string(),
-                        });
-                    }
-                };
+                let semicolon_span =
+                    lexer.expect_semicolon("expected return statement to finish with semicolon")?;
 
                 Ok(Statement::Return(Return {
                     span: span.start..semicolon_span.end,
@@ -60,7 +54,27 @@ impl SolveType for Return<UntypedAstMetadata> {
         compiler: &mut Compiler,
         state: &mut Self::State,
     ) -> Result<Self::Typed, TyError> {
-        let value = self.value.solve(compiler, state)?;
+        let mut value = self.value;
+
+        // The value must match the return type of the enclosing function
+        let expected = state.return_ty().cloned();
+        if let Some((ty, _)) = &expected {
+            value.expect_ty(ty);
+        }
+
+        let value = value.solve(compiler, state)?;
+
+        if let Some((expected, expected_span)) = expected {
+            let found = &value.get_ty_info().ty;
+            if !expected.check(found) {
+                return Err(TyError::Mismatch {
+                    expected: expected.name(compiler),
+                    found: found.name(compiler),
+                    span: self.span,
+                    expected_span: Some(expected_span),
+                });
+            }
+        }
 
         Ok(Return {
             ty_info: TyInfo::try_from((
@@ -69,7 +83,8 @@ impl SolveType for Return<UntypedAstMetadata> {
                     Some(value.get_ty_info().ty.clone()),
                     value.get_ty_info().return_ty.clone(),
                 ],
-            ))?,
+            ))
+            .map_err(|e| e.at(compiler, self.span.clone()))?,
             value,
             span: self.span,
         })
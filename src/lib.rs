@@ -1,11 +1,14 @@
+use std::path::Path;
+
 use codegen::llvm::Module;
 use compiler::Compiler;
 use inkwell::{
     passes::PassBuilderOptions,
-    targets::{CodeModel, RelocMode, Target, TargetMachine},
+    targets::{CodeModel, FileType, RelocMode, Target, TargetMachine},
     values::FunctionValue,
     OptimizationLevel,
 };
+use repr::ir;
 
 pub mod codegen;
 pub mod compiler;
@@ -15,7 +18,7 @@ pub mod stage;
 mod ty;
 pub mod util;
 
-pub fn compile_and_run(source: &'static str, debug: bool) -> i64 {
+pub fn compile_and_run(source: &str, debug: bool) -> i64 {
     // Create compiler state
     let mut compiler = Compiler::default();
 
@@ -25,8 +28,29 @@ pub fn compile_and_run(source: &'static str, debug: bool) -> i64 {
     // Compile the source to produce all the functions
     let functions = compiler.compile(source).unwrap();
 
+    // Build the LLVM module, and find the main function
+    let (module, main) = codegen(&compiler, &llvm_ctx, &functions, "<source>", source);
+
+    if debug {
+        module.print_to_stderr();
+    } else {
+        optimise(&module);
+    }
+
+    jit(&module, main)
+}
+
+/// Generate an LLVM module from the functions compiled from the source within the provided file,
+/// returning it alongside the main function.
+pub fn codegen<'ink>(
+    compiler: &Compiler,
+    llvm_ctx: &'ink inkwell::context::Context,
+    functions: &[ir::Function],
+    file: &str,
+    source: &str,
+) -> (inkwell::module::Module<'ink>, FunctionValue<'ink>) {
     // Create an LLVM module from the compiler and an LLVM instance
-    let module = Module::new(&compiler, &llvm_ctx);
+    let module = Module::new(compiler, llvm_ctx, file, source);
 
     // Compile each of the functions into the LLVM module, and capture the corresponding value
     let functions = functions
@@ -43,38 +67,52 @@ pub fn compile_and_run(source: &'static str, debug: bool) -> i64 {
             .find(|(identifier, _)| {
                 compiler.functions.symbol_for(*identifier).unwrap() == main_symbol
             })
-            .map(|(_, function)| function)
+            .map(|(_, function)| *function)
             .unwrap()
     };
 
     // Pull out the inner LLVM module
-    let module = module.into_inner();
+    (module.into_inner(), main)
+}
 
-    if debug {
-        module.print_to_stderr();
-    } else {
-        run_passes(
-            &module,
-            &[
-                "instcombine",
-                "reassociate",
-                "gvn",
-                "simplifycfg",
-                "mem2reg",
-            ],
-        );
-    }
+/// Run the standard set of optimisation passes over the module.
+pub fn optimise(module: &inkwell::module::Module) {
+    run_passes(
+        module,
+        &[
+            "instcombine",
+            "reassociate",
+            "gvn",
+            "simplifycfg",
+            "mem2reg",
+        ],
+    );
+}
 
-    jit(&module, *main)
+/// Write the module out to an object file at the provided path.
+pub fn write_object(module: &inkwell::module::Module, path: &Path) -> Result<(), String> {
+    target_machine()
+        .write_to_file(module, FileType::Object, path)
+        .map_err(|e| e.to_string())
 }
 
-#[allow(unused)]
 fn run_passes(module: &inkwell::module::Module, passes: &[&str]) {
+    module
+        .run_passes(
+            passes.join(",").as_str(),
+            &target_machine(),
+            PassBuilderOptions::create(),
+        )
+        .unwrap();
+}
+
+/// Create a target machine for the host.
+fn target_machine() -> TargetMachine {
     Target::initialize_all(&Default::default());
 
     let target_triple = TargetMachine::get_default_triple();
     let target = Target::from_triple(&target_triple).unwrap();
-    let target_machine = target
+    target
         .create_target_machine(
             &target_triple,
             "generic",
@@ -83,18 +121,11 @@ fn run_passes(module: &inkwell::module::Module, passes: &[&str]) {
             RelocMode::PIC,
             CodeModel::Default,
         )
-        .unwrap();
-
-    module
-        .run_passes(
-            passes.join(",").as_str(),
-            &target_machine,
-            PassBuilderOptions::create(),
-        )
-        .unwrap();
+        .unwrap()
 }
 
-fn jit(module: &inkwell::module::Module, entry: FunctionValue) -> i64 {
+/// JIT compile the module, and run the provided entry function.
+pub fn jit(module: &inkwell::module::Module, entry: FunctionValue) -> i64 {
     let engine = module
         .create_jit_execution_engine(OptimizationLevel::None)
         .unwrap();
@@ -1,23 +1,245 @@
-use lumina::compile_and_run;
-
-fn main() {
-    let source = r#"
-        fn main() -> int {
-            let random = 1024;
-            let a = [1, 2, 3];
-
-            let i = 0;
-            loop {
-                if a[i] == 1024 {
-                    break;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use lumina::{
+    codegen,
+    compiler::{Compiler, CompilerError},
+    jit, optimise, stage,
+    util::diagnostic::Diagnostic,
+    write_object,
+};
+
+#[derive(Parser)]
+#[command(version, about = "Compiler for the Lumina language")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compile and run a program, printing the result of `main`.
+    Run {
+        /// Source file to compile.
+        file: PathBuf,
+
+        /// Print the generated LLVM IR instead of optimising it.
+        #[arg(long)]
+        debug: bool,
+
+        /// Skip checking array indexes against the length of the array at runtime.
+        #[arg(long)]
+        no_bounds_checks: bool,
+    },
+
+    /// Compile a program into an object file.
+    Build {
+        /// Source file to compile.
+        file: PathBuf,
+
+        /// Path to write the object file to.
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Skip checking array indexes against the length of the array at runtime.
+        #[arg(long)]
+        no_bounds_checks: bool,
+    },
+
+    /// Check a program for errors without generating any code.
+    Check {
+        /// Source file to compile.
+        file: PathBuf,
+    },
+
+    /// Print the representation of a program at some stage of the compiler.
+    Emit {
+        /// Compiler stage to emit.
+        stage: Stage,
+
+        /// Source file to compile.
+        file: PathBuf,
+
+        /// Skip checking array indexes against the length of the array at runtime.
+        #[arg(long)]
+        no_bounds_checks: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Stage {
+    /// Untyped AST produced from parsing.
+    Ast,
+    /// IR produced from lowering.
+    Ir,
+    /// LLVM IR produced from codegen.
+    Llvm,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            file,
+            debug,
+            no_bounds_checks,
+        } => {
+            let Some(source) = read_source(&file) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut compiler = Compiler::default();
+            compiler.options.bounds_checks = !no_bounds_checks;
+            let result = compiler.compile(&source);
+            report_warnings(&file, &source, &compiler);
+            let functions = match result {
+                Ok(functions) => functions,
+                Err(e) => return report(&file, &source, e),
+            };
+
+            let llvm_ctx = inkwell::context::Context::create();
+            let (module, main) = codegen(
+                &compiler,
+                &llvm_ctx,
+                &functions,
+                &file.display().to_string(),
+                &source,
+            );
+
+            if debug {
+                module.print_to_stderr();
+            } else {
+                optimise(&module);
+            }
+
+            let result = jit(&module, main);
+            println!("result: {result}");
+        }
+        Command::Build {
+            file,
+            output,
+            no_bounds_checks,
+        } => {
+            let Some(source) = read_source(&file) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut compiler = Compiler::default();
+            compiler.options.bounds_checks = !no_bounds_checks;
+            let result = compiler.compile(&source);
+            report_warnings(&file, &source, &compiler);
+            let functions = match result {
+                Ok(functions) => functions,
+                Err(e) => return report(&file, &source, e),
+            };
+
+            let llvm_ctx = inkwell::context::Context::create();
+            let (module, _) = codegen(
+                &compiler,
+                &llvm_ctx,
+                &functions,
+                &file.display().to_string(),
+                &source,
+            );
+            optimise(&module);
+
+            if let Err(e) = write_object(&module, &output) {
+                eprintln!("error: unable to write {}: {e}", output.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Check { file } => {
+            let Some(source) = read_source(&file) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut compiler = Compiler::default();
+            let result = compiler.compile(&source);
+            report_warnings(&file, &source, &compiler);
+
+            if let Err(e) = result {
+                return report(&file, &source, e);
+            }
+        }
+        Command::Emit {
+            stage,
+            file,
+            no_bounds_checks,
+        } => {
+            let Some(source) = read_source(&file) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut compiler = Compiler::default();
+            compiler.options.bounds_checks = !no_bounds_checks;
+
+            match stage {
+                Stage::Ast => match stage::parse::parse(&mut compiler, &source) {
+                    (Some(program), errors) if errors.is_empty() => println!("{program:#?}"),
+                    (_, errors) => return report(&file, &source, CompilerError::Parse(errors)),
+                },
+                Stage::Ir => {
+                    let result = compiler.compile(&source);
+                    report_warnings(&file, &source, &compiler);
+
+                    match result {
+                        Ok(functions) => println!("{functions:#?}"),
+                        Err(e) => return report(&file, &source, e),
+                    }
                 }
+                Stage::Llvm => {
+                    let result = compiler.compile(&source);
+                    report_warnings(&file, &source, &compiler);
+                    let functions = match result {
+                        Ok(functions) => functions,
+                        Err(e) => return report(&file, &source, e),
+                    };
 
-                i -= 1;
+                    let llvm_ctx = inkwell::context::Context::create();
+                    let (module, _) = codegen(
+                        &compiler,
+                        &llvm_ctx,
+                        &functions,
+                        &file.display().to_string(),
+                        &source,
+                    );
+                    print!("{}", module.print_to_string());
+                }
             }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Read the source file, reporting any failure.
+fn read_source(file: &Path) -> Option<String> {
+    fs::read_to_string(file)
+        .map_err(|e| eprintln!("error: unable to read {}: {e}", file.display()))
+        .ok()
+}
+
+/// Report a compiler error against the source it originated from, producing the exit code to use.
+fn report(file: &Path, source: &str, error: CompilerError) -> ExitCode {
+    let file = file.display().to_string();
+
+    for diagnostic in error.diagnostics() {
+        eprint!("{}", diagnostic.render(&file, source));
+    }
+
+    ExitCode::FAILURE
+}
 
-            return a[i];
-        }"#;
+/// Report any warnings produced whilst compiling against the source they originated from.
+fn report_warnings(file: &Path, source: &str, compiler: &Compiler) {
+    let file = file.display().to_string();
 
-    let result = compile_and_run(source, true);
-    println!("result: {result}");
+    for warning in &compiler.warnings {
+        eprint!("{}", Diagnostic::from(warning).render(&file, source));
+    }
 }
@@ -35,6 +35,7 @@ macro_rules! ast_node {
         }
 
         impl<$metadata: $crate::repr::ast::AstMetadata> $name<$metadata> {
+            #[allow(clippy::too_many_arguments)]
             pub fn new($($field: $ty,)*) -> Self {
                 Self { $($field,)* }
             }
@@ -87,26 +88,41 @@ macro_rules! generate_ast {
         use $crate::hir;
 
         // Re-export non-typed utilities
-        pub use hir::InfixOperation;
+        pub use hir::{InfixOperation, PrefixOperation};
 
         pub type Array = hir::Array<$metadata>;
+        pub type ArrayRepeat = hir::ArrayRepeat<$metadata>;
         pub type Block = hir::Block<$metadata>;
         pub type Boolean = hir::Boolean<$metadata>;
         pub type Call = hir::Call<$metadata>;
         pub type Ident = hir::Ident<$metadata>;
         pub type If = hir::If<$metadata>;
         pub type Loop = hir::Loop<$metadata>;
+        pub type While = hir::While<$metadata>;
+        pub type For = hir::For<$metadata>;
         pub type Index = hir::Index<$metadata>;
         pub type Infix = hir::Infix<$metadata>;
         pub type Integer = hir::Integer<$metadata>;
+        pub type Float = hir::Float<$metadata>;
         pub type Assign = hir::Assign<$metadata>;
+        pub type Projection = hir::Projection<$metadata>;
         pub type Cast = hir::Cast<$metadata>;
+        pub type Prefix = hir::Prefix<$metadata>;
+        pub type StructLiteral = hir::StructLiteral<$metadata>;
+        pub type Field = hir::Field<$metadata>;
+        pub type EnumVariant = hir::EnumVariant<$metadata>;
+        pub type Match = hir::Match<$metadata>;
+        pub type MatchArm = hir::MatchArm<$metadata>;
+        pub type Pattern = hir::Pattern<$metadata>;
+        pub type Tuple = hir::Tuple<$metadata>;
+        pub type TupleIndex = hir::TupleIndex<$metadata>;
         pub type Expression = hir::Expression<$metadata>;
         pub type Function = hir::Function<$metadata>;
         pub type Program = hir::Program<$metadata>;
         pub type Statement = hir::Statement<$metadata>;
         pub type ReturnStatement = hir::Return<$metadata>;
         pub type LetStatement = hir::Let<$metadata>;
+        pub type BindingPattern = hir::BindingPattern<$metadata>;
         pub type ExpressionStatement = hir::ExpressionStatement<$metadata>;
         pub type BreakStatement = hir::Break<$metadata>;
         pub type ContinueStatement = hir::Continue<$metadata>;
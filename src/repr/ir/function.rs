@@ -20,3 +20,46 @@ pub struct Function {
     pub basic_blocks: IndexVec<BasicBlockIdx, BasicBlock>,
     pub scope: HashSet<ScopedBinding>,
 }
+
+impl Function {
+    /// Every basic block which can be reached from the entry, ordered such that each block comes
+    /// after all of the blocks that dominate it (reverse post-order).
+    pub fn reverse_postorder(&self) -> Vec<BasicBlockIdx> {
+        let Some(entry) = self.basic_blocks.indices().next() else {
+            return Vec::new();
+        };
+
+        let mut visited = HashSet::from([entry]);
+        let mut postorder = Vec::new();
+
+        // Each block on the stack is paired with the successors which are yet to be visited
+        let mut stack = vec![(
+            entry,
+            self.basic_blocks[entry].terminator.successors().into_iter(),
+        )];
+        while let Some((bb, successors)) = stack.last_mut() {
+            let bb = *bb;
+
+            match successors.next() {
+                Some(successor) => {
+                    if visited.insert(successor) {
+                        stack.push((
+                            successor,
+                            self.basic_blocks[successor]
+                                .terminator
+                                .successors()
+                                .into_iter(),
+                        ));
+                    }
+                }
+                None => {
+                    postorder.push(bb);
+                    stack.pop();
+                }
+            }
+        }
+
+        postorder.reverse();
+        postorder
+    }
+}
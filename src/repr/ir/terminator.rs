@@ -1,3 +1,5 @@
+use crate::util::span::Span;
+
 use super::*;
 
 #[derive(Clone, Debug)]
@@ -11,4 +13,37 @@ pub enum Terminator {
         default: BasicBlockIdx,
         branches: Vec<(Value, BasicBlockIdx)>,
     },
+    /// Control flow can never reach the end of this block.
+    Unreachable,
+    /// Abort the program, reporting the reason to the user.
+    Panic(Panic),
+}
+
+impl Terminator {
+    /// Basic blocks that control flow may continue to.
+    pub fn successors(&self) -> Vec<BasicBlockIdx> {
+        match self {
+            Terminator::Jump(bb) => vec![*bb],
+            Terminator::Switch {
+                default, branches, ..
+            } => branches
+                .iter()
+                .map(|(_, bb)| *bb)
+                .chain(std::iter::once(*default))
+                .collect(),
+            Terminator::Return(_) | Terminator::Unreachable | Terminator::Panic(_) => Vec::new(),
+        }
+    }
+}
+
+/// Reasons that a program may abort at runtime.
+#[derive(Clone, Debug)]
+pub enum Panic {
+    /// An array was indexed outside of its bounds.
+    IndexOutOfBounds {
+        index: Value,
+        length: u32,
+        /// Location of the index expression within the source.
+        span: Span,
+    },
 }
@@ -1,6 +1,9 @@
 use index_vec::define_index_type;
 
-use crate::repr::identifier::{FunctionIdx, ScopedBinding};
+use crate::{
+    repr::identifier::{FunctionIdx, ScopedBinding},
+    ty::Ty,
+};
 
 use super::{BasicBlockIdx, Value};
 
@@ -10,38 +13,66 @@ mod ops;
 
 /// Each possible operation of the IR. The results of these operations (if applicable) can be
 /// referenced using the ID of the triple.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Triple {
     /// Standard binary operation.
     BinaryOp {
         lhs: Value,
         rhs: Value,
         op: BinaryOp,
+        /// Type of the operands, which determines the signedness of the operation.
+        ty: Ty,
     },
     /// Standard unary operation.
     UnaryOp { rhs: Value, op: UnaryOp },
     /// Copy the provided value.
     Copy(Value),
+    /// Convert a value from one numeric type to another.
+    Cast { value: Value, from: Ty, to: Ty },
     /// Call the corresponding function.
     Call(FunctionIdx, Vec<Value>),
     /// Assign some symbol to some value.
     Assign(ScopedBinding, Value),
     /// Loads a value from a scoped binding.
     Load(ScopedBinding),
-    /// Allocate an array of the provded size.
-    AllocArray(u32),
-    /// Index into the value.
-    Index { value: ScopedBinding, index: Value },
-    /// TODO: Must be removed
-    SetIndex {
-        array_ptr: TripleRef,
-        index: Value,
+    /// Fetch the element at an index within an array.
+    Index { value: Value, index: Value },
+    /// Build an aggregate of the provided type from each of its values, in order.
+    Aggregate { ty: Ty, values: Vec<Value> },
+    /// Build a value of an enum type, tagged with the provided variant and carrying its payload.
+    Variant {
+        ty: Ty,
+        variant: u32,
+        values: Vec<Value>,
+    },
+    /// Extract the value at an index within an aggregate.
+    Extract { value: Value, index: u32 },
+    /// Assign a value to a location nested within a binding, following each projection in the
+    /// path.
+    AssignPlace {
+        binding: ScopedBinding,
+        path: Vec<Projection>,
         value: Value,
     },
+    /// Load the value from a location nested within a binding, following each projection in the
+    /// path.
+    LoadPlace {
+        binding: ScopedBinding,
+        path: Vec<Projection>,
+    },
     /// Merge the listed values from their basic blocks into a single value.
     Phi(Vec<(Value, BasicBlockIdx)>),
 }
 
+/// A single step into an aggregate, used to address a location nested within a binding.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Projection {
+    /// Field at a constant position within a struct or tuple.
+    Field(u32),
+    /// Element at a dynamic index within an array.
+    Index(Value),
+}
+
 define_index_type! {
     /// Identifier for a triple within some basic block.
     pub struct TripleIdx = usize;
@@ -6,6 +6,12 @@ pub enum BinaryOp {
     Sub,
     Multiply,
     Divide,
+    Modulo,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
     Eq,
     NotEq,
     Greater,
@@ -23,6 +29,12 @@ impl From<&ast::InfixOperation> for BinaryOp {
             ast::InfixOperation::Minus => Self::Sub,
             ast::InfixOperation::Multiply => Self::Multiply,
             ast::InfixOperation::Divide => Self::Divide,
+            ast::InfixOperation::Modulo => Self::Modulo,
+            ast::InfixOperation::BitAnd => Self::BitAnd,
+            ast::InfixOperation::BitOr => Self::BitOr,
+            ast::InfixOperation::BitXor => Self::BitXor,
+            ast::InfixOperation::ShiftLeft => Self::ShiftLeft,
+            ast::InfixOperation::ShiftRight => Self::ShiftRight,
             ast::InfixOperation::Eq => Self::Eq,
             ast::InfixOperation::NotEq => Self::NotEq,
             ast::InfixOperation::Greater => Self::Greater,
@@ -46,3 +58,18 @@ pub enum UnaryOp {
     Minus,
     Not,
 }
+
+impl From<&ast::PrefixOperation> for UnaryOp {
+    fn from(value: &ast::PrefixOperation) -> Self {
+        match value {
+            ast::PrefixOperation::Minus => Self::Minus,
+            ast::PrefixOperation::Not => Self::Not,
+        }
+    }
+}
+
+impl From<ast::PrefixOperation> for UnaryOp {
+    fn from(value: ast::PrefixOperation) -> Self {
+        Self::from(&value)
+    }
+}
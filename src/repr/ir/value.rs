@@ -1,21 +1,57 @@
+use crate::ty::Ty;
+
 use super::TripleRef;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ConstantValue {
     Integer(i64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    Uint(u64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    F32(f32),
+    F64(f64),
     Boolean(bool),
 }
 
+impl ConstantValue {
+    /// Create an integer constant of the provided type. The value will be truncated if it does not
+    /// fit within the type.
+    pub fn integer(value: u64, ty: &Ty) -> Self {
+        match ty {
+            Ty::Int => Self::Integer(value as i64),
+            Ty::I8 => Self::I8(value as i8),
+            Ty::I16 => Self::I16(value as i16),
+            Ty::I32 => Self::I32(value as i32),
+            Ty::Uint => Self::Uint(value),
+            Ty::U8 => Self::U8(value as u8),
+            Ty::U16 => Self::U16(value as u16),
+            Ty::U32 => Self::U32(value as u32),
+            ty => unreachable!("cannot create integer constant of type {ty:?}"),
+        }
+    }
+
+    /// Create a floating point constant of the provided type.
+    pub fn float(value: f64, ty: &Ty) -> Self {
+        match ty {
+            Ty::F32 => Self::F32(value as f32),
+            Ty::F64 => Self::F64(value),
+            ty => unreachable!("cannot create float constant of type {ty:?}"),
+        }
+    }
+}
+
 /// Corresponds to the 'address' portion of a three-address code. Intended to transparently
 /// represent any possible source of a value.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Value {
     /// Constant value, potentially inserted from the compiler or originating from the source code.
     Constant(ConstantValue),
     /// Temporary value representing the result of some triple.
     Triple(TripleRef),
-    /// Pointer produced from some triple.
-    Pointer(TripleRef),
     /// A parameter passed to the current function.
     Parameter(usize),
     /// No value.
@@ -1,9 +1,19 @@
 use std::fmt::Display;
 
-use logos::{Lexer, Logos};
+use logos::{FilterResult, Lexer, Logos};
+
+/// Errors that can be produced by the lexer, beyond encountering unrecognised input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum LexError {
+    #[default]
+    InvalidToken,
+    UnterminatedComment,
+}
 
 #[derive(Clone, Debug, Logos, PartialEq, Eq, Hash)]
+#[logos(error = LexError)]
 #[logos(skip r"[ \t\r\n\f]+")]
+#[logos(skip r"//(//[^\n]*|[^/\n][^\n]*)?")]
 pub enum Token {
     /*
      * Arithmetic operations
@@ -16,6 +26,18 @@ pub enum Token {
     Asterix,
     #[token("/")]
     ForwardSlash,
+    #[token("%")]
+    Percent,
+    #[token("&")]
+    Ampersand,
+    #[token("|")]
+    Pipe,
+    #[token("^")]
+    Caret,
+    #[token("<<")]
+    DoubleLeftAngle,
+    #[token(">>")]
+    DoubleRightAngle,
     #[token("==")]
     DoubleEq,
     #[token("!=")]
@@ -32,6 +54,8 @@ pub enum Token {
     And,
     #[token("||")]
     Or,
+    #[token("!")]
+    Bang,
 
     /*
      * Language tokens
@@ -46,10 +70,32 @@ pub enum Token {
     MulAssign,
     #[token("/=")]
     DivAssign,
+    #[token("%=")]
+    ModAssign,
+    #[token("&=")]
+    BitAndAssign,
+    #[token("|=")]
+    BitOrAssign,
+    #[token("^=")]
+    BitXorAssign,
+    #[token("<<=")]
+    ShiftLeftAssign,
+    #[token(">>=")]
+    ShiftRightAssign,
     #[token("->")]
     ThinArrow,
+    #[token("=>")]
+    FatArrow,
+    #[token(".")]
+    Dot,
+    #[token("..")]
+    DoubleDot,
+    #[token("..=")]
+    DoubleDotEq,
     #[token(":")]
     Colon,
+    #[token("::")]
+    DoubleColon,
     #[token(";")]
     SemiColon,
     #[token(",")]
@@ -80,29 +126,68 @@ pub enum Token {
     Return,
     #[token("let")]
     Let,
+    #[token("mut")]
+    Mut,
     #[token("if")]
     If,
     #[token("else")]
     Else,
     #[token("loop")]
     Loop,
+    #[token("while")]
+    While,
+    #[token("for")]
+    For,
+    #[token("in")]
+    In,
     #[token("break")]
     Break,
     #[token("continue")]
     Continue,
     #[token("as")]
     As,
+    #[token("struct")]
+    Struct,
+    #[token("enum")]
+    Enum,
+    #[token("match")]
+    Match,
 
     /*
      * Primitive type
      */
     #[token("int")]
     Int,
+    #[token("i8")]
+    I8,
+    #[token("i16")]
+    I16,
+    #[token("i32")]
+    I32,
     #[token("uint")]
     Uint,
+    #[token("u8")]
+    U8,
+    #[token("u16")]
+    U16,
+    #[token("u32")]
+    U32,
+    #[token("f32")]
+    F32,
+    #[token("f64")]
+    F64,
     #[token("bool")]
     Bool,
 
+    /*
+     * Comments
+     */
+    #[regex(r"///([^/\n][^\n]*)?", Token::parse_doc_comment)]
+    DocComment(String),
+    /// Block comments are skipped entirely by the callback, so this token will never be produced.
+    #[token("/*", Token::skip_block_comment)]
+    BlockComment,
+
     /*
      * Literals
      */
@@ -115,10 +200,16 @@ pub enum Token {
     String(String),
 
     #[regex(r#"\d+"#, Token::parse_integer)]
-    Integer(i64),
+    Integer(u64),
+
+    #[regex(r#"\d+\.\d+"#, Token::parse_float)]
+    Float(String),
 
     #[regex(r#"[a-zA-Z_]\w*"#, Token::parse_ident, priority = 1)]
     Ident(String),
+
+    #[regex(r#"'[a-zA-Z_]\w*"#, Token::parse_label)]
+    Label(String),
 }
 
 impl Token {
@@ -126,13 +217,57 @@ impl Token {
         lex.slice().to_owned()
     }
 
-    fn parse_integer(lex: &mut Lexer<'_, Token>) -> i64 {
-        lex.slice().to_owned().parse().unwrap()
+    fn parse_integer(lex: &mut Lexer<'_, Token>) -> Option<u64> {
+        // Literals which are out of range will be treated as invalid tokens
+        lex.slice().parse().ok()
+    }
+
+    fn parse_float(lex: &mut Lexer<'_, Token>) -> String {
+        lex.slice().to_owned()
     }
 
     fn parse_ident(lex: &mut Lexer<'_, Token>) -> String {
         lex.slice().to_owned()
     }
+
+    fn parse_label(lex: &mut Lexer<'_, Token>) -> String {
+        lex.slice()["'".len()..].to_owned()
+    }
+
+    fn parse_doc_comment(lex: &mut Lexer<'_, Token>) -> String {
+        let doc = &lex.slice()["///".len()..];
+
+        // Remove the conventional space following the slashes
+        doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_owned()
+    }
+
+    /// Skip over a block comment, which may contain further nested block comments.
+    fn skip_block_comment(lex: &mut Lexer<'_, Token>) -> FilterResult<(), LexError> {
+        let remainder = lex.remainder().as_bytes();
+
+        let mut depth = 1;
+        let mut offset = 0;
+        while depth > 0 {
+            match remainder.get(offset..offset + 2) {
+                Some(b"/*") => {
+                    depth += 1;
+                    offset += 2;
+                }
+                Some(b"*/") => {
+                    depth -= 1;
+                    offset += 2;
+                }
+                Some(_) => offset += 1,
+                None => {
+                    lex.bump(remainder.len());
+                    return FilterResult::Error(LexError::UnterminatedComment);
+                }
+            }
+        }
+
+        lex.bump(offset);
+        FilterResult::Skip
+    }
 }
 
 impl Display for Token {
@@ -142,10 +277,17 @@ impl Display for Token {
             Token::Minus => write!(f, "-"),
             Token::Asterix => write!(f, "*"),
             Token::ForwardSlash => write!(f, "/"),
+            Token::Percent => write!(f, "%"),
+            Token::Ampersand => write!(f, "&"),
+            Token::Pipe => write!(f, "|"),
+            Token::Caret => write!(f, "^"),
+            Token::DoubleLeftAngle => write!(f, "<<"),
+            Token::DoubleRightAngle => write!(f, ">>"),
             Token::DoubleEq => write!(f, "=="),
             Token::NotEq => write!(f, "!="),
             Token::And => write!(f, "&&"),
             Token::Or => write!(f, "||"),
+            Token::Bang => write!(f, "!"),
             Token::LeftAngle => write!(f, "<"),
             Token::RightAngle => write!(f, ">"),
             Token::LeftAngleEq => write!(f, "<="),
@@ -155,8 +297,19 @@ impl Display for Token {
             Token::MinusAssign => write!(f, "-="),
             Token::MulAssign => write!(f, "*="),
             Token::DivAssign => write!(f, "/="),
+            Token::ModAssign => write!(f, "%="),
+            Token::BitAndAssign => write!(f, "&="),
+            Token::BitOrAssign => write!(f, "|="),
+            Token::BitXorAssign => write!(f, "^="),
+            Token::ShiftLeftAssign => write!(f, "<<="),
+            Token::ShiftRightAssign => write!(f, ">>="),
             Token::ThinArrow => write!(f, "->"),
+            Token::FatArrow => write!(f, "=>"),
+            Token::Dot => write!(f, "."),
+            Token::DoubleDot => write!(f, ".."),
+            Token::DoubleDotEq => write!(f, "..="),
             Token::Colon => write!(f, ":"),
+            Token::DoubleColon => write!(f, "::"),
             Token::SemiColon => write!(f, ";"),
             Token::Comma => write!(f, ","),
             Token::LeftParen => write!(f, ")"),
@@ -168,20 +321,39 @@ impl Display for Token {
             Token::Fn => write!(f, "fn"),
             Token::Return => write!(f, "return"),
             Token::Let => write!(f, "let"),
+            Token::Mut => write!(f, "mut"),
             Token::If => write!(f, "if"),
             Token::Else => write!(f, "else"),
             Token::Loop => write!(f, "loop"),
+            Token::While => write!(f, "while"),
+            Token::For => write!(f, "for"),
+            Token::In => write!(f, "in"),
+            Token::Struct => write!(f, "struct"),
+            Token::Enum => write!(f, "enum"),
+            Token::Match => write!(f, "match"),
             Token::Break => write!(f, "break"),
             Token::Continue => write!(f, "continue"),
             Token::As => write!(f, "as"),
             Token::Int => write!(f, "int"),
+            Token::I8 => write!(f, "i8"),
+            Token::I16 => write!(f, "i16"),
+            Token::I32 => write!(f, "i32"),
             Token::Uint => write!(f, "uint"),
+            Token::U8 => write!(f, "u8"),
+            Token::U16 => write!(f, "u16"),
+            Token::U32 => write!(f, "u32"),
+            Token::F32 => write!(f, "f32"),
+            Token::F64 => write!(f, "f64"),
             Token::Bool => write!(f, "bool"),
             Token::True => write!(f, "true"),
             Token::False => write!(f, "false"),
+            Token::DocComment(value) => write!(f, "///{value}"),
+            Token::BlockComment => write!(f, "/*"),
             Token::String(value) => write!(f, r#""{value}""#),
             Token::Integer(value) => write!(f, "{value}"),
+            Token::Float(value) => write!(f, "{value}"),
             Token::Ident(value) => write!(f, "{value}"),
+            Token::Label(value) => write!(f, "'{value}"),
         }
     }
 }
@@ -1,16 +1,42 @@
 use index_vec::IndexVec;
 
 use crate::{
-    compiler::Compiler,
+    compiler::{Compiler, Symbol},
     repr::{
         ast::typed as ast,
         identifier::{FunctionIdx, ScopedBinding},
         ir::{self, *},
     },
     ty::{FunctionSignature, Ty},
+    util::{diagnostic::Diagnostic, span::Span},
 };
 
-pub fn lower(compiler: &mut Compiler, program: ast::Program) -> Vec<Function> {
+#[derive(Debug, thiserror::Error)]
+pub enum LowerError {
+    #[error("basic block was terminated more than once")]
+    DuplicateTerminator { span: Span },
+
+    #[error("basic block was never terminated")]
+    MissingTerminator { span: Span },
+}
+
+impl LowerError {
+    pub fn span(&self) -> &Span {
+        match self {
+            LowerError::DuplicateTerminator { span } | LowerError::MissingTerminator { span } => {
+                span
+            }
+        }
+    }
+}
+
+impl From<&LowerError> for Diagnostic {
+    fn from(error: &LowerError) -> Self {
+        Diagnostic::new(error, error.span().clone())
+    }
+}
+
+pub fn lower(compiler: &mut Compiler, program: ast::Program) -> Result<Vec<Function>, LowerError> {
     [lower_function(compiler, program.main)]
         .into_iter()
         .chain(
@@ -22,6 +48,29 @@ pub fn lower(compiler: &mut Compiler, program: ast::Program) -> Vec<Function> {
         .collect()
 }
 
+/// Basic blocks that control flow within a loop can jump to.
+struct LoopTarget {
+    /// Label that the loop can be targeted by.
+    label: Option<Symbol>,
+    /// Block that `continue` will jump to.
+    start: ir::BasicBlockIdx,
+    /// Block that `break` will jump to.
+    end: ir::BasicBlockIdx,
+    /// Each value that the loop was broken with, and the block it was broken from.
+    breaks: Vec<(Value, ir::BasicBlockIdx)>,
+}
+
+impl LoopTarget {
+    fn new(label: Option<Symbol>, start: ir::BasicBlockIdx, end: ir::BasicBlockIdx) -> Self {
+        Self {
+            label,
+            start,
+            end,
+            breaks: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 struct BasicBlockBuilder {
     triples: IndexVec<ir::TripleIdx, Triple>,
@@ -31,12 +80,14 @@ struct BasicBlockBuilder {
 pub struct FunctionBuilder {
     idx: FunctionIdx,
     signature: FunctionSignature,
+    /// Location of the function, to report any problems building it against.
+    span: Span,
 
     basic_blocks: IndexVec<ir::BasicBlockIdx, BasicBlockBuilder>,
     current_basic_block: ir::BasicBlockIdx,
 
     /// Tracks the starting and ending basic block for any loops, so they can be jumped back to it
-    loop_stack: Vec<(ir::BasicBlockIdx, ir::BasicBlockIdx)>,
+    loop_stack: Vec<LoopTarget>,
 
     scope: Vec<(ScopedBinding, Ty)>,
 }
@@ -49,10 +100,15 @@ impl FunctionBuilder {
         Self {
             idx: function.name,
             signature: FunctionSignature::from(function),
+            span: function.span.clone(),
             basic_blocks,
             current_basic_block,
             loop_stack: Vec::new(),
-            scope: function.parameters.to_vec(),
+            scope: function
+                .parameters
+                .iter()
+                .map(|(binding, ty, _, _)| (*binding, ty.clone()))
+                .collect(),
         }
     }
 
@@ -60,6 +116,14 @@ impl FunctionBuilder {
         self.scope.push((ident, ty));
     }
 
+    /// Find the type of a binding within the function.
+    pub fn binding_ty(&self, binding: ScopedBinding) -> Option<&Ty> {
+        self.scope
+            .iter()
+            .find(|(test_binding, _)| *test_binding == binding)
+            .map(|(_, ty)| ty)
+    }
+
     pub fn add_triple(&mut self, triple: ir::Triple) -> ir::TripleRef {
         ir::TripleRef {
             basic_block: self.current_basic_block,
@@ -69,15 +133,31 @@ impl FunctionBuilder {
         }
     }
 
-    pub fn set_terminator(&mut self, terminator: ir::Terminator) {
+    pub fn set_terminator(&mut self, terminator: ir::Terminator) -> Result<(), LowerError> {
         let bb = &mut self.basic_blocks[self.current_basic_block];
 
-        assert!(
-            bb.terminator.is_none(),
-            "cannot set terminator if it's already been set"
-        );
+        // Cannot set terminator if it's already been set
+        if bb.terminator.is_some() {
+            return Err(LowerError::DuplicateTerminator {
+                span: self.span.clone(),
+            });
+        }
 
         bb.terminator = Some(terminator);
+
+        Ok(())
+    }
+
+    /// Find the loop with the provided label, or the innermost loop if there is no label.
+    fn loop_target(&mut self, label: Option<Symbol>) -> Option<&mut LoopTarget> {
+        match label {
+            Some(label) => self
+                .loop_stack
+                .iter_mut()
+                .rev()
+                .find(|target| target.label == Some(label)),
+            None => self.loop_stack.last_mut(),
+        }
     }
 
     pub fn current_bb(&self) -> ir::BasicBlockIdx {
@@ -100,24 +180,33 @@ impl FunctionBuilder {
         idx
     }
 
-    pub fn build(self) -> Function {
-        Function {
+    pub fn build(self) -> Result<Function, LowerError> {
+        let span = self.span;
+
+        Ok(Function {
             identifier: self.idx,
             signature: self.signature,
             basic_blocks: self
                 .basic_blocks
                 .into_iter()
-                .map(|builder| ir::BasicBlock {
-                    triples: builder.triples,
-                    terminator: builder.terminator.expect("terminator must be set"),
+                .map(|builder| {
+                    Ok(ir::BasicBlock {
+                        triples: builder.triples,
+                        terminator: builder
+                            .terminator
+                            .ok_or_else(|| LowerError::MissingTerminator { span: span.clone() })?,
+                    })
                 })
-                .collect(),
+                .collect::<Result<_, _>>()?,
             scope: self.scope.into_iter().map(|(symbol, _)| symbol).collect(),
-        }
+        })
     }
 }
 
-fn lower_function(compiler: &mut Compiler, function: ast::Function) -> Function {
+fn lower_function(
+    compiler: &mut Compiler,
+    function: ast::Function,
+) -> Result<Function, LowerError> {
     // Create a new function builder, which will already be positioned at the entry point.
     let mut builder = FunctionBuilder::new(&function);
 
@@ -126,123 +215,264 @@ fn lower_function(compiler: &mut Compiler, function: ast::Function) -> Function
         .parameters
         .iter()
         .enumerate()
-        .for_each(|(i, (binding, _ty))| {
+        .for_each(|(i, (binding, _ty, _mutable, _span))| {
             // Copy each parameter into its binding
             builder.add_triple(Triple::Assign(*binding, Value::Parameter(i)));
         });
 
     // Perform the lowering
-    let value = lower_block(compiler, &mut builder, &function.body);
+    let value = lower_block(compiler, &mut builder, &function.body)?.unwrap_or(Value::Unit);
 
     // If implicit return, add in a return statement
     if !matches!(value, Value::Unit) {
-        builder.set_terminator(Terminator::Return(value));
+        builder.set_terminator(Terminator::Return(value))?;
+    } else if matches!(function.body.ty_info.ty, Ty::Never)
+        && builder.basic_blocks[builder.current_bb()]
+            .terminator
+            .is_none()
+    {
+        // The end of a diverging body (such as an infinite loop) can never be reached
+        builder.set_terminator(Terminator::Unreachable)?;
     }
 
     // Consume the builder
     builder.build()
 }
 
-/// Lower an AST block into the current function context.
+/// Lower an AST block into the current function context, producing nothing if control flow never
+/// reaches the end of the block.
 fn lower_block(
     compiler: &mut Compiler,
     builder: &mut FunctionBuilder,
     block: &ast::Block,
-) -> Value {
-    assert!(
-        !block.statements.is_empty(),
-        "block must have statements within it"
-    );
-
-    // Determine the index of the last statement
-    let last_statement = block.statements.len() - 1;
+) -> Result<Option<Value>, LowerError> {
+    // Determine the index of the last statement, if there is one
+    let last_statement = block.statements.len().checked_sub(1);
 
     for (end, statement) in block
         .statements
         .iter()
         .enumerate()
-        .map(|(i, statement)| (i == last_statement, statement))
+        .map(|(i, statement)| (Some(i) == last_statement, statement))
     {
+        // Nothing following a statement that has already left the block can be reached
+        if builder.basic_blocks[builder.current_bb()]
+            .terminator
+            .is_some()
+        {
+            break;
+        }
+
         match statement {
             ast::Statement::Return(ast::ReturnStatement { value, .. }) => {
-                let value = lower_expression(compiler, builder, value).unwrap();
-                builder.set_terminator(Terminator::Return(value));
+                let Some(value) = lower_expression(compiler, builder, value)? else {
+                    return lower_diverged(builder);
+                };
+                builder.set_terminator(Terminator::Return(value))?;
             }
-            ast::Statement::Let(ast::LetStatement {
-                binding: name,
-                value,
-                ..
-            }) => {
-                builder.register_scoped(*name, value.get_ty_info().ty.clone());
-
-                let value = lower_expression(compiler, builder, value).unwrap();
-                builder.add_triple(Triple::Assign(*name, value));
+            ast::Statement::Let(ast::LetStatement { binding, value, .. }) => {
+                let ty = value.get_ty_info().ty.clone();
+                let Some(value) = lower_expression(compiler, builder, value)? else {
+                    return lower_diverged(builder);
+                };
+                lower_binding_pattern(builder, binding, value, &ty);
             }
-            ast::Statement::Break(ast::BreakStatement { .. }) => {
-                assert!(
-                    !builder.loop_stack.is_empty(),
-                    "can only break within a loop"
-                );
-
-                let (_, loop_end) = builder.loop_stack.last().unwrap();
-                builder.set_terminator(Terminator::Jump(*loop_end));
+            ast::Statement::Break(ast::BreakStatement { label, value, .. }) => {
+                let value = match value {
+                    Some(value) => match lower_expression(compiler, builder, value)? {
+                        Some(value) => value,
+                        None => return lower_diverged(builder),
+                    },
+                    None => Value::Unit,
+                };
+
+                // Record the value, so the loop can produce it
+                let bb = builder.current_bb();
+                let target = builder
+                    .loop_target(*label)
+                    .expect("type checking ensures `break` is within a loop");
+                target.breaks.push((value, bb));
+
+                let loop_end = target.end;
+                builder.set_terminator(Terminator::Jump(loop_end))?;
             }
-            ast::Statement::Continue(ast::ContinueStatement { .. }) => {
-                assert!(
-                    !builder.loop_stack.is_empty(),
-                    "can only continue within a loop"
-                );
-
-                let (loop_start, _) = builder.loop_stack.last().unwrap();
-                builder.set_terminator(Terminator::Jump(*loop_start));
+            ast::Statement::Continue(ast::ContinueStatement { label, .. }) => {
+                let loop_start = builder
+                    .loop_target(*label)
+                    .expect("type checking ensures `continue` is within a loop")
+                    .start;
+                builder.set_terminator(Terminator::Jump(loop_start))?;
             }
             ast::Statement::ExpressionStatement(ast::ExpressionStatement {
                 expression,
                 ty_info,
                 ..
             }) => {
-                let result = lower_expression(compiler, builder, expression);
+                let Some(result) = lower_expression(compiler, builder, expression)? else {
+                    return lower_diverged(builder);
+                };
 
                 // Implicit return
                 if end && !matches!(ty_info.ty, Ty::Never) {
-                    // WARN: If the type is `never`, what should be returned?
-                    return result.unwrap();
+                    return Ok(Some(result));
                 }
             }
         }
     }
 
-    Value::Unit
+    // Control flow which has already left the block produces no value
+    Ok(builder.basic_blocks[builder.current_bb()]
+        .terminator
+        .is_none()
+        .then_some(Value::Unit))
+}
+
+/// Finish lowering a block after a value which never completes. Nothing following the value can
+/// be reached, including the rest of the current basic block.
+fn lower_diverged(builder: &mut FunctionBuilder) -> Result<Option<Value>, LowerError> {
+    if builder.basic_blocks[builder.current_bb()]
+        .terminator
+        .is_none()
+    {
+        builder.set_terminator(Terminator::Unreachable)?;
+    }
+
+    Ok(None)
+}
+
+/// Ensure that an index lies within an array of the provided length, panicking at runtime if it
+/// doesn't. Nothing will be emitted if bounds checks have been disabled.
+fn lower_bounds_check(
+    compiler: &Compiler,
+    builder: &mut FunctionBuilder,
+    index: Value,
+    length: u32,
+    span: &Span,
+) -> Result<(), LowerError> {
+    if !compiler.options.bounds_checks {
+        return Ok(());
+    }
+
+    // Negative indexes become large when treated as unsigned, so only one comparison is needed
+    let in_bounds = builder.add_triple(Triple::BinaryOp {
+        lhs: index,
+        rhs: Value::Constant(ConstantValue::Uint(length as u64)),
+        op: BinaryOp::Less,
+        ty: Ty::Uint,
+    });
+    let original_bb = builder.current_bb();
+
+    let panic_bb = builder.push_bb();
+    builder.set_terminator(Terminator::Panic(Panic::IndexOutOfBounds {
+        index,
+        length,
+        span: span.clone(),
+    }))?;
+
+    let continue_bb = builder.push_bb();
+
+    builder.goto_bb(original_bb);
+    builder.set_terminator(Terminator::Switch {
+        value: Value::Triple(in_bounds),
+        default: continue_bb,
+        branches: vec![(Value::boolean(false), panic_bb)],
+    })?;
+
+    builder.goto_bb(continue_bb);
+
+    Ok(())
+}
+
+/// Bind a value to each of the names within a pattern, pulling out elements of any tuples.
+fn lower_binding_pattern(
+    builder: &mut FunctionBuilder,
+    pattern: &ast::BindingPattern,
+    value: Value,
+    ty: &Ty,
+) {
+    match pattern {
+        ast::BindingPattern::Ident { name: binding, .. } => {
+            builder.register_scoped(*binding, ty.clone());
+
+            // Unit values occupy no storage, so there is nothing to store
+            if !matches!(ty, Ty::Unit) {
+                builder.add_triple(Triple::Assign(*binding, value));
+            }
+        }
+        ast::BindingPattern::Wildcard => (),
+        ast::BindingPattern::Tuple(patterns) => {
+            // Values which never produce anything have nothing to bind
+            let Ty::Tuple(tys) = ty else {
+                return;
+            };
+
+            for (i, (pattern, ty)) in patterns.iter().zip(tys).enumerate() {
+                let element = builder.add_triple(Triple::Extract {
+                    value,
+                    index: i as u32,
+                });
+                lower_binding_pattern(builder, pattern, Value::Triple(element), ty);
+            }
+        }
+    }
 }
 
 fn lower_expression(
     compiler: &mut Compiler,
     builder: &mut FunctionBuilder,
     expression: &ast::Expression,
-) -> Option<Value> {
-    match expression {
+) -> Result<Option<Value>, LowerError> {
+    Ok(match expression {
         ast::Expression::Infix(ast::Infix {
             left,
             operation,
             right,
             ..
         }) => {
-            let lhs = lower_expression(compiler, builder, left).unwrap();
-            let rhs = lower_expression(compiler, builder, right).unwrap();
+            let Some(lhs) = lower_expression(compiler, builder, left)? else {
+                return Ok(None);
+            };
+            let Some(rhs) = lower_expression(compiler, builder, right)? else {
+                return Ok(None);
+            };
             let op = BinaryOp::from(operation);
 
             Some(Value::Triple(builder.add_triple(Triple::BinaryOp {
                 lhs,
                 rhs,
                 op,
+                ty: left.get_ty_info().ty.clone(),
             })))
         }
-        ast::Expression::Integer(integer) => Some(Value::integer(integer.value)),
+        ast::Expression::Prefix(ast::Prefix {
+            operation, right, ..
+        }) => {
+            let Some(rhs) = lower_expression(compiler, builder, right)? else {
+                return Ok(None);
+            };
+            let op = UnaryOp::from(operation);
+
+            Some(Value::Triple(
+                builder.add_triple(Triple::UnaryOp { rhs, op }),
+            ))
+        }
+        ast::Expression::Integer(integer) => Some(Value::Constant(ConstantValue::integer(
+            integer.value,
+            &integer.ty_info.ty,
+        ))),
+        ast::Expression::Float(float) => Some(Value::Constant(ConstantValue::float(
+            float.value,
+            &float.ty_info.ty,
+        ))),
         ast::Expression::Boolean(boolean) => Some(Value::boolean(boolean.value)),
         ast::Expression::Ident(ast::Ident { binding, .. }) => {
-            Some(Value::Triple(builder.add_triple(Triple::Load(*binding))))
+            if matches!(builder.binding_ty(*binding), Some(Ty::Unit)) {
+                Some(Value::Unit)
+            } else {
+                Some(Value::Triple(builder.add_triple(Triple::Load(*binding))))
+            }
         }
-        ast::Expression::Block(block) => Some(lower_block(compiler, builder, block)),
+        ast::Expression::Block(block) => lower_block(compiler, builder, block)?,
         ast::Expression::If(ast::If {
             condition,
             success,
@@ -250,7 +480,9 @@ fn lower_expression(
             ty_info,
             ..
         }) => {
-            let condition = lower_expression(compiler, builder, condition).unwrap();
+            let Some(condition) = lower_expression(compiler, builder, condition)? else {
+                return Ok(None);
+            };
 
             let original_bb = builder.current_bb();
 
@@ -263,31 +495,39 @@ fn lower_expression(
 
             // Lower success block into newly created basic block
             let success_bb = builder.push_bb();
-            let success_value = lower_block(compiler, builder, success);
+            let success_value = lower_block(compiler, builder, success)?;
+            let success_end_bb = builder.current_bb();
+
+            // Values must come from the block that the branch ends in, which may differ from the one it
+            // started in
+            let mut merge_values = Vec::new();
 
-            if let (Some(merge_bb), true) = (merge_bb, !matches!(success.ty_info.ty, Ty::Never)) {
+            // Branches which never complete can't return to the merge basic block
+            if let (Some(merge_bb), Some(success_value)) = (merge_bb, success_value) {
                 // Ensure the branch returns to the merge basic block
-                builder.set_terminator(Terminator::Jump(merge_bb));
-            }
+                builder.set_terminator(Terminator::Jump(merge_bb))?;
 
-            let mut merge_values = vec![(success_value, success_bb)];
+                merge_values.push((success_value, success_end_bb));
+            }
 
             // Lower the otherwise block, if it exists
             let branches = [otherwise
                 .as_ref()
                 .map(|otherwise| {
                     let otherwise_bb = builder.push_bb();
-                    let otherwise_value = lower_block(compiler, builder, otherwise);
+                    let otherwise_value = lower_block(compiler, builder, otherwise)?;
+                    let otherwise_end_bb = builder.current_bb();
 
-                    if let Some(merge_bb) = merge_bb {
+                    if let (Some(merge_bb), Some(otherwise_value)) = (merge_bb, otherwise_value) {
                         // Ensure the branch returns to the merge basic block
-                        builder.set_terminator(Terminator::Jump(merge_bb));
-                    }
+                        builder.set_terminator(Terminator::Jump(merge_bb))?;
 
-                    merge_values.push((otherwise_value, otherwise_bb));
+                        merge_values.push((otherwise_value, otherwise_end_bb));
+                    }
 
-                    otherwise_bb
+                    Ok(otherwise_bb)
                 })
+                .transpose()?
                 .or(merge_bb)
                 .map(|bb| (Value::boolean(false), bb))]
             .into_iter()
@@ -301,7 +541,7 @@ fn lower_expression(
                 value: condition,
                 default: success_bb,
                 branches,
-            });
+            })?;
 
             // Continue inserting triples from the merged location
             if let Some(merge_bb) = merge_bb {
@@ -325,18 +565,20 @@ fn lower_expression(
 
             // Jump from the previous block to the loop start
             builder.goto_bb(prev);
-            builder.set_terminator(Terminator::Jump(loop_start));
+            builder.set_terminator(Terminator::Jump(loop_start))?;
 
             // Prepare an ending basic block
             let loop_end = builder.push_bb();
 
             // Save the start and end locations (keeping track of how many previous loops there are)
-            builder.loop_stack.push((loop_start, loop_end));
+            builder
+                .loop_stack
+                .push(LoopTarget::new(e_loop.label, loop_start, loop_end));
             let loop_count = builder.loop_stack.len();
 
             // Lower the loop body
             builder.goto_bb(loop_start);
-            lower_block(compiler, builder, &e_loop.body);
+            lower_block(compiler, builder, &e_loop.body)?;
 
             // HACK: Should there be a better way of determining if the loop will never end (check for never?)
             if builder.basic_blocks[builder.current_bb()]
@@ -344,7 +586,206 @@ fn lower_expression(
                 .is_none()
             {
                 // Jump from the loop end back to the start
-                builder.set_terminator(Terminator::Jump(loop_start));
+                builder.set_terminator(Terminator::Jump(loop_start))?;
+            }
+
+            // Remove this loop from the stack
+            assert_eq!(
+                builder.loop_stack.len(),
+                loop_count,
+                "must be at same position in loop stack"
+            );
+            let target = builder.loop_stack.pop().unwrap();
+
+            // Continue on from where the loop ends
+            builder.goto_bb(loop_end);
+
+            match e_loop.ty_info.ty {
+                Ty::Unit => Some(Value::Unit),
+                Ty::Never => None,
+                // Merge each of the values that the loop was broken with
+                _ => Some(Value::Triple(
+                    builder.add_triple(Triple::Phi(target.breaks)),
+                )),
+            }
+        }
+        ast::Expression::While(ast::While {
+            label,
+            condition,
+            body,
+            ..
+        }) => {
+            let prev = builder.current_bb();
+
+            // Create a new basic block for the condition
+            let loop_start = builder.push_bb();
+
+            // Jump from the previous block to the loop start
+            builder.goto_bb(prev);
+            builder.set_terminator(Terminator::Jump(loop_start))?;
+
+            // Prepare an ending basic block
+            let loop_end = builder.push_bb();
+
+            // Continuing will re-evaluate the condition
+            builder
+                .loop_stack
+                .push(LoopTarget::new(*label, loop_start, loop_end));
+            let loop_count = builder.loop_stack.len();
+
+            // Lower the condition, tracking where it finishes in case it created blocks
+            builder.goto_bb(loop_start);
+            let Some(condition) = lower_expression(compiler, builder, condition)? else {
+                return Ok(None);
+            };
+            let condition_bb = builder.current_bb();
+
+            // Lower the loop body
+            let body_bb = builder.push_bb();
+            lower_block(compiler, builder, body)?;
+
+            if builder.basic_blocks[builder.current_bb()]
+                .terminator
+                .is_none()
+            {
+                // Jump from the body back to the condition
+                builder.set_terminator(Terminator::Jump(loop_start))?;
+            }
+
+            // Only enter the body whilst the condition holds
+            builder.goto_bb(condition_bb);
+            builder.set_terminator(Terminator::Switch {
+                value: condition,
+                default: body_bb,
+                branches: vec![(Value::boolean(false), loop_end)],
+            })?;
+
+            // Remove this loop from the stack
+            assert_eq!(
+                builder.loop_stack.len(),
+                loop_count,
+                "must be at same position in loop stack"
+            );
+            builder.loop_stack.pop();
+
+            // Continue on from where the loop ends
+            builder.goto_bb(loop_end);
+
+            Some(Value::Unit)
+        }
+        ast::Expression::For(ast::For {
+            label,
+            binding,
+            start,
+            end,
+            inclusive,
+            body,
+            ..
+        }) => {
+            let ty = start.get_ty_info().ty.clone();
+
+            // Range bounds are only evaluated once, before the loop begins
+            let Some(start) = lower_expression(compiler, builder, start)? else {
+                return Ok(None);
+            };
+            let Some(end) = lower_expression(compiler, builder, end)? else {
+                return Ok(None);
+            };
+
+            builder.register_scoped(*binding, ty.clone());
+            builder.add_triple(Triple::Assign(*binding, start));
+
+            let entry_bb = builder.current_bb();
+
+            // Prepare an ending basic block, and one to advance the binding
+            let loop_end = builder.push_bb();
+            let step_bb = builder.push_bb();
+
+            // Continuing will advance to the next item in the range
+            builder
+                .loop_stack
+                .push(LoopTarget::new(*label, step_bb, loop_end));
+            let loop_count = builder.loop_stack.len();
+
+            // Lower the loop body
+            let body_bb = builder.push_bb();
+            lower_block(compiler, builder, body)?;
+
+            if builder.basic_blocks[builder.current_bb()]
+                .terminator
+                .is_none()
+            {
+                // Jump from the body to the step
+                builder.set_terminator(Terminator::Jump(step_bb))?;
+            }
+
+            // Skip the loop entirely if the range is empty
+            builder.goto_bb(entry_bb);
+            let in_range = builder.add_triple(Triple::BinaryOp {
+                lhs: start,
+                rhs: end,
+                op: if *inclusive {
+                    BinaryOp::LessEq
+                } else {
+                    BinaryOp::Less
+                },
+                ty: ty.clone(),
+            });
+            builder.set_terminator(Terminator::Switch {
+                value: Value::Triple(in_range),
+                default: body_bb,
+                branches: vec![(Value::boolean(false), loop_end)],
+            })?;
+
+            builder.goto_bb(step_bb);
+            let current = Value::Triple(builder.add_triple(Triple::Load(*binding)));
+            let one = Value::Constant(ConstantValue::integer(1, &ty));
+
+            if *inclusive {
+                // Check before incrementing, so that the end of the range can't overflow
+                let not_done = builder.add_triple(Triple::BinaryOp {
+                    lhs: current,
+                    rhs: end,
+                    op: BinaryOp::NotEq,
+                    ty: ty.clone(),
+                });
+
+                let increment_bb = builder.push_bb();
+                let next = builder.add_triple(Triple::BinaryOp {
+                    lhs: current,
+                    rhs: one,
+                    op: BinaryOp::Add,
+                    ty,
+                });
+                builder.add_triple(Triple::Assign(*binding, Value::Triple(next)));
+                builder.set_terminator(Terminator::Jump(body_bb))?;
+
+                builder.goto_bb(step_bb);
+                builder.set_terminator(Terminator::Switch {
+                    value: Value::Triple(not_done),
+                    default: increment_bb,
+                    branches: vec![(Value::boolean(false), loop_end)],
+                })?;
+            } else {
+                let next = builder.add_triple(Triple::BinaryOp {
+                    lhs: current,
+                    rhs: one,
+                    op: BinaryOp::Add,
+                    ty: ty.clone(),
+                });
+                builder.add_triple(Triple::Assign(*binding, Value::Triple(next)));
+
+                let in_range = builder.add_triple(Triple::BinaryOp {
+                    lhs: Value::Triple(next),
+                    rhs: end,
+                    op: BinaryOp::Less,
+                    ty,
+                });
+                builder.set_terminator(Terminator::Switch {
+                    value: Value::Triple(in_range),
+                    default: body_bb,
+                    branches: vec![(Value::boolean(false), loop_end)],
+                })?;
             }
 
             // Remove this loop from the stack
@@ -362,47 +803,371 @@ fn lower_expression(
         }
         ast::Expression::Call(call) => {
             let idx = call.name;
-            let params = call
+            let Some(params) = call
                 .args
                 .iter()
-                .map(|e| lower_expression(compiler, builder, e).unwrap())
-                .collect();
+                .map(|e| lower_expression(compiler, builder, e))
+                .collect::<Result<Option<Vec<_>>, _>>()?
+            else {
+                return Ok(None);
+            };
             Some(Value::Triple(builder.add_triple(Triple::Call(idx, params))))
         }
         ast::Expression::Assign(assign) => {
-            let value = lower_expression(compiler, builder, &assign.value).unwrap();
-            builder.add_triple(Triple::Assign(assign.binding, value));
+            // Resolve the location before the value, so that each index is only evaluated once
+            let mut ty = builder
+                .binding_ty(assign.binding)
+                .expect("binding must be registered")
+                .clone();
+            let path = assign
+                .projections
+                .iter()
+                .map(|projection| {
+                    let (projection, projection_ty) = match projection {
+                        ast::Projection::Field(field) => {
+                            let (index, field_ty) = compiler
+                                .types
+                                .resolve_field(&ty, *field)
+                                .expect("field must exist on struct");
+
+                            (ir::Projection::Field(index as u32), field_ty.clone())
+                        }
+                        ast::Projection::Element(index) => {
+                            let Ty::Tuple(tys) = &ty else {
+                                unreachable!("element must be of a tuple");
+                            };
+
+                            (ir::Projection::Field(*index), tys[*index as usize].clone())
+                        }
+                        ast::Projection::Index(index_expression) => {
+                            let Ty::Array { inner, size } = &ty else {
+                                unreachable!("index must be of an array");
+                            };
+                            let (inner, size) = (*inner.clone(), *size);
+
+                            let Some(index) =
+                                lower_expression(compiler, builder, index_expression)?
+                            else {
+                                return Ok(None);
+                            };
+                            lower_bounds_check(
+                                compiler,
+                                builder,
+                                index,
+                                size,
+                                index_expression.span(),
+                            )?;
+
+                            (ir::Projection::Index(index), inner)
+                        }
+                    };
+                    ty = projection_ty;
+
+                    Ok(Some(projection))
+                })
+                .collect::<Result<Option<Vec<_>>, _>>()?;
+            let Some(path) = path else {
+                return Ok(None);
+            };
+
+            let Some(mut value) = lower_expression(compiler, builder, &assign.value)? else {
+                return Ok(None);
+            };
+
+            // Compound assignments combine the current value with the new value
+            if let Some(operation) = &assign.operation {
+                let current = if path.is_empty() {
+                    builder.add_triple(Triple::Load(assign.binding))
+                } else {
+                    builder.add_triple(Triple::LoadPlace {
+                        binding: assign.binding,
+                        path: path.clone(),
+                    })
+                };
+
+                value = Value::Triple(builder.add_triple(Triple::BinaryOp {
+                    lhs: Value::Triple(current),
+                    rhs: value,
+                    op: BinaryOp::from(operation),
+                    ty,
+                }));
+            }
+
+            if matches!(value, Value::Unit) {
+                // Nothing is stored for unit values
+            } else if path.is_empty() {
+                builder.add_triple(Triple::Assign(assign.binding, value));
+            } else {
+                builder.add_triple(Triple::AssignPlace {
+                    binding: assign.binding,
+                    path,
+                    value,
+                });
+            }
 
             Some(Value::Unit)
         }
-        ast::Expression::Cast(ast::Cast { value, .. }) => {
-            // Directly lower the inner expression, cast is only for the compiler
-            lower_expression(compiler, builder, value)
+        ast::Expression::Cast(ast::Cast {
+            value, target_ty, ..
+        }) => {
+            let from = value.get_ty_info().ty.clone();
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
+
+            Some(Value::Triple(builder.add_triple(Triple::Cast {
+                value,
+                from,
+                to: target_ty.clone(),
+            })))
+        }
+        ast::Expression::Index(ast::Index {
+            value,
+            index: index_expression,
+            ..
+        }) => {
+            let Ty::Array { size, .. } = value.get_ty_info().ty else {
+                unreachable!("can only index an array");
+            };
+
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
+            let Some(index) = lower_expression(compiler, builder, index_expression)? else {
+                return Ok(None);
+            };
+            lower_bounds_check(compiler, builder, index, size, index_expression.span())?;
+
+            Some(Value::Triple(
+                builder.add_triple(Triple::Index { value, index }),
+            ))
         }
-        ast::Expression::Index(ast::Index { value, index, .. }) => {
-            let index = lower_expression(compiler, builder, index)?;
+        ast::Expression::StructLiteral(ast::StructLiteral {
+            fields, ty_info, ..
+        }) => {
+            // Fields are already in declaration order
+            let Some(values) = fields
+                .iter()
+                .map(|(_, value)| lower_expression(compiler, builder, value))
+                .collect::<Result<Option<Vec<_>>, _>>()?
+            else {
+                return Ok(None);
+            };
 
-            Some(Value::Triple(builder.add_triple(Triple::Index {
-                value: *value,
-                index,
+            Some(Value::Triple(builder.add_triple(Triple::Aggregate {
+                ty: ty_info.ty.clone(),
+                values,
             })))
         }
-        ast::Expression::Array(ast::Array { init, .. }) => {
-            // Allocate the memory
-            let ptr = builder.add_triple(Triple::AllocArray(init.len() as u32));
+        ast::Expression::Field(ast::Field { value, field, .. }) => {
+            let (index, _) = compiler
+                .types
+                .resolve_field(&value.get_ty_info().ty, *field)
+                .expect("field must exist on struct");
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
 
-            // Initialise each of the items into the memory
-            init.iter().enumerate().for_each(|(i, expression)| {
-                let value = lower_expression(compiler, builder, expression).unwrap();
+            Some(Value::Triple(builder.add_triple(Triple::Extract {
+                value,
+                index: index as u32,
+            })))
+        }
+        ast::Expression::Tuple(ast::Tuple {
+            values, ty_info, ..
+        }) => {
+            let Some(values) = values
+                .iter()
+                .map(|value| lower_expression(compiler, builder, value))
+                .collect::<Result<Option<Vec<_>>, _>>()?
+            else {
+                return Ok(None);
+            };
 
-                builder.add_triple(Triple::SetIndex {
-                    array_ptr: ptr,
-                    index: Value::integer(i as i64),
-                    value,
-                });
-            });
+            Some(Value::Triple(builder.add_triple(Triple::Aggregate {
+                ty: ty_info.ty.clone(),
+                values,
+            })))
+        }
+        ast::Expression::TupleIndex(ast::TupleIndex { value, index, .. }) => {
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
 
-            Some(Value::Pointer(ptr))
+            Some(Value::Triple(builder.add_triple(Triple::Extract {
+                value,
+                index: *index,
+            })))
         }
-    }
+        ast::Expression::EnumVariant(ast::EnumVariant {
+            name,
+            variant,
+            args,
+            ty_info,
+            ..
+        }) => {
+            let (variant, _) = compiler
+                .types
+                .get_enum(*name)
+                .and_then(|registration| registration.variant(*variant))
+                .expect("variant must exist on enum");
+            let Some(values) = args
+                .iter()
+                .map(|arg| lower_expression(compiler, builder, arg))
+                .collect::<Result<Option<Vec<_>>, _>>()?
+            else {
+                return Ok(None);
+            };
+
+            Some(Value::Triple(builder.add_triple(Triple::Variant {
+                ty: ty_info.ty.clone(),
+                variant: variant as u32,
+                values,
+            })))
+        }
+        ast::Expression::Match(ast::Match {
+            value,
+            arms,
+            ty_info,
+            ..
+        }) => {
+            let Ty::Enum(enum_idx) = value.get_ty_info().ty else {
+                unreachable!("can only match on enums");
+            };
+            let registration = compiler
+                .types
+                .get_enum(enum_idx)
+                .expect("enum must be registered")
+                .clone();
+
+            // The discriminant is always stored first
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
+            let discriminant =
+                Value::Triple(builder.add_triple(Triple::Extract { value, index: 0 }));
+
+            let original_bb = builder.current_bb();
+
+            // Prepare a basic block to merge back in to
+            let merge_bb = (!matches!(ty_info.ty, Ty::Never)).then(|| builder.push_bb());
+
+            let mut merge_values = Vec::new();
+            let mut branches = Vec::new();
+            let mut default = None;
+
+            for arm in arms {
+                let arm_bb = builder.push_bb();
+
+                match &arm.pattern {
+                    ast::Pattern::Wildcard { .. } => {
+                        // Any variant not yet handled will end up here
+                        default.get_or_insert(arm_bb);
+                    }
+                    ast::Pattern::Variant {
+                        variant, bindings, ..
+                    } => {
+                        let (variant, payload) = registration
+                            .variant(*variant)
+                            .expect("variant must exist on enum");
+                        let offset = registration.payload_offset(variant);
+
+                        // Only the first arm for a variant can be reached
+                        if !branches
+                            .iter()
+                            .any(|(case, _)| *case == Value::integer(variant as i64))
+                        {
+                            branches.push((Value::integer(variant as i64), arm_bb));
+                        }
+
+                        // Pull each bound value out of the payload
+                        for (i, (binding, ty)) in bindings.iter().zip(payload).enumerate() {
+                            let Some(binding) = binding else {
+                                continue;
+                            };
+
+                            builder.register_scoped(*binding, ty.clone());
+                            let payload_value = builder.add_triple(Triple::Extract {
+                                value,
+                                index: (offset + i) as u32,
+                            });
+                            builder
+                                .add_triple(Triple::Assign(*binding, Value::Triple(payload_value)));
+                        }
+                    }
+                }
+
+                let arm_value = lower_expression(compiler, builder, &arm.body)?;
+
+                if let (Some(merge_bb), Some(arm_value)) = (merge_bb, arm_value) {
+                    if !matches!(arm.body.get_ty_info().ty, Ty::Never) {
+                        merge_values.push((arm_value, builder.current_bb()));
+
+                        // Ensure the arm returns to the merge basic block
+                        builder.set_terminator(Terminator::Jump(merge_bb))?;
+                    }
+                }
+            }
+
+            // Exhaustiveness guarantees that every discriminant is handled by an arm
+            let default = match default {
+                Some(default) => default,
+                None => {
+                    let unreachable_bb = builder.push_bb();
+                    builder.set_terminator(Terminator::Unreachable)?;
+
+                    unreachable_bb
+                }
+            };
+
+            // Revert back to original location
+            builder.goto_bb(original_bb);
+            builder.set_terminator(Terminator::Switch {
+                value: discriminant,
+                default,
+                branches,
+            })?;
+
+            // Continue inserting triples from the merged location
+            if let Some(merge_bb) = merge_bb {
+                builder.goto_bb(merge_bb);
+            }
+
+            match ty_info.ty {
+                Ty::Unit => Some(Value::Unit),
+                Ty::Never => None,
+                _ => Some(Value::Triple(builder.add_triple(Triple::Phi(merge_values)))),
+            }
+        }
+        ast::Expression::Array(ast::Array { init, ty_info, .. }) => {
+            let Some(values) = init
+                .iter()
+                .map(|value| lower_expression(compiler, builder, value))
+                .collect::<Result<Option<Vec<_>>, _>>()?
+            else {
+                return Ok(None);
+            };
+
+            Some(Value::Triple(builder.add_triple(Triple::Aggregate {
+                ty: ty_info.ty.clone(),
+                values,
+            })))
+        }
+        ast::Expression::ArrayRepeat(ast::ArrayRepeat {
+            value,
+            length,
+            ty_info,
+            ..
+        }) => {
+            // Only evaluate the value once, and then use it for every element
+            let Some(value) = lower_expression(compiler, builder, value)? else {
+                return Ok(None);
+            };
+
+            Some(Value::Triple(builder.add_triple(Triple::Aggregate {
+                ty: ty_info.ty.clone(),
+                values: vec![value; *length as usize],
+            })))
+        }
+    })
 }
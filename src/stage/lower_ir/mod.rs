@@ -1,3 +1,3 @@
-pub use lowering::lower;
+pub use lowering::{lower, LowerError};
 
 mod lowering;
@@ -0,0 +1,150 @@
+use crate::{
+    compiler::Symbol,
+    ty::{Ty, TySpanned},
+};
+
+use super::*;
+
+/// Parse an enum declaration, defining its variants within the compiler.
+pub fn parse_enum(
+    parser: &Parser,
+    compiler: &mut Compiler,
+    lexer: &mut Lexer<'_>,
+) -> Result<(), ParseError> {
+    // `enum` keyword
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Enum, _) => (),
+        (token, span) => {
+            return Err(ParseError::ExpectedToken {
+                expected: Box::new(Token::Enum),
+                found: Box::new(token),
+                reason: "enum declaration must begin with keyword".to_string(),
+                span,
+            });
+        }
+    }
+
+    // enum name
+    let (name, name_span) = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Ident(name), span) => (name, span),
+        (token, span) => {
+            return Err(ParseError::ExpectedIdentifier {
+                found: Box::new(token),
+                reason: "enum declaration requires identifier".to_string(),
+                span,
+            });
+        }
+    };
+
+    // opening brace for variant list
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::LeftBrace, _) => (),
+        (token, span) => {
+            return Err(ParseError::ExpectedToken {
+                expected: Box::new(Token::LeftBrace),
+                found: Box::new(token),
+                reason: "variant list must begin with opening brace".to_string(),
+                span,
+            });
+        }
+    }
+
+    // Parse each of the variants, which may have a trailing comma
+    let mut variants: Vec<(Symbol, Vec<Ty>)> = Vec::new();
+    loop {
+        let (variant, variant_span) =
+            match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })? {
+                (Token::RightBrace, _) => break,
+                (Token::Ident(variant), span) => (variant, span),
+                (token, span) => {
+                    return Err(ParseError::ExpectedIdentifier {
+                        found: Box::new(token),
+                        reason: "variant must have identifier".to_string(),
+                        span,
+                    });
+                }
+            };
+
+        let symbol = compiler.symbols.get_or_intern(&variant);
+        if variants
+            .iter()
+            .any(|(test_symbol, _)| *test_symbol == symbol)
+        {
+            return Err(ParseError::DuplicateVariant {
+                name: variant,
+                span: variant_span,
+            });
+        }
+
+        // Variants may optionally carry a payload
+        let mut payload = Vec::new();
+        if matches!(lexer.peek_token(), Some(Token::LeftParen)) {
+            lexer.next_token();
+
+            loop {
+                if matches!(lexer.peek_token(), Some(Token::RightParen)) {
+                    lexer.next_token();
+                    break;
+                }
+
+                let TySpanned { ty, .. } = parser.parse(compiler, lexer, Precedence::Lowest)?;
+                payload.push(ty);
+
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::Comma, _) => (),
+                    (Token::RightParen, _) => break,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Comma),
+                            found: Box::new(token),
+                            reason: "payload types must be separated by a comma".to_string(),
+                            span,
+                        });
+                    }
+                }
+            }
+        }
+
+        variants.push((symbol, payload));
+
+        // Variants are separated by commas, unless the list is finished
+        match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::Comma, _) => (),
+            (Token::RightBrace, _) => break,
+            (token, span) => {
+                return Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::Comma),
+                    found: Box::new(token),
+                    reason: "variants must be separated by a comma".to_string(),
+                    span,
+                });
+            }
+        }
+    }
+
+    // Enum names must not clash with any other type
+    let symbol = compiler.symbols.get_or_intern(&name);
+    let idx = compiler.types.declare_enum(symbol);
+    if compiler.types.get_idx(symbol).is_some()
+        || !compiler.types.define_enum(idx, variants, name_span.clone())
+    {
+        return Err(ParseError::DuplicateType {
+            name,
+            span: name_span,
+        });
+    }
+
+    Ok(())
+}
@@ -8,41 +8,50 @@ pub fn parse_function(
     parser: &Parser,
     compiler: &mut Compiler,
     lexer: &mut Lexer<'_>,
+    docs: Vec<String>,
 ) -> Result<Function, ParseError> {
     // `fn` keyword
-    let span_start = match lexer.next_spanned().unwrap() {
+    let span_start = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
         // `fn` keyword
         (Token::Fn, span) => span.start,
         // Some other token
-        (token, _) => {
+        (token, span) => {
             return Err(ParseError::ExpectedToken {
                 expected: Box::new(Token::Fn),
                 found: Box::new(token),
                 reason: "function declaration must begin with keyword".to_string(),
+                span,
             });
         }
     };
 
     // function name
-    let fn_name = match lexer.next_token().unwrap() {
-        Token::Ident(fn_name) => fn_name,
-        token => {
-            return Err(ParseError::ExpectedToken {
-                expected: Box::new(Token::Ident(String::new())),
+    let fn_name = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Ident(fn_name), _) => fn_name,
+        (token, span) => {
+            return Err(ParseError::ExpectedIdentifier {
                 found: Box::new(token),
                 reason: "function declaration requires identifier".to_string(),
+                span,
             });
         }
     };
 
     // opening paren for argument list
-    match lexer.next_token().unwrap() {
-        Token::LeftParen => (),
-        token => {
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::LeftParen, _) => (),
+        (token, span) => {
             return Err(ParseError::ExpectedToken {
                 expected: Box::new(Token::LeftParen),
                 found: Box::new(token),
                 reason: "argument list must begin with opening parenthesis".to_string(),
+                span,
             });
         }
     }
@@ -55,38 +64,110 @@ pub fn parse_function(
     }
     let mut parse_state = ParseState::Item;
 
+    // Parameters bound to a pattern are given a hidden name, and destructured within the body
+    let mut patterns = Vec::new();
+
     let parameters = iter::from_fn(|| {
         loop {
-            match (&parse_state, lexer.next_token().unwrap()) {
+            // Parameters may be declared as mutable
+            let mutable = matches!(parse_state, ParseState::Item)
+                && matches!(lexer.peek_token(), Some(Token::Mut));
+            if mutable {
+                lexer.next_token();
+            }
+
+            // Names within a pattern are declared mutable individually, rather than the pattern
+            if !mutable
+                && matches!(parse_state, ParseState::Item)
+                && matches!(lexer.peek_token(), Some(Token::LeftParen))
+            {
+                let start = lexer.peek_span().start;
+                let pattern = match BindingPattern::parse(compiler, lexer) {
+                    Ok(pattern) => pattern,
+                    Err(e) => return Some(Err(e)),
+                };
+                let span = start..lexer.peek_span().start;
+
+                // Ensure a colon follows it
+                match lexer.next_spanned() {
+                    Some((Token::Colon, _)) => (),
+                    None => {
+                        return Some(Err(ParseError::UnexpectedEOF {
+                            span: lexer.eof_span(),
+                        }));
+                    }
+                    Some((token, span)) => {
+                        return Some(Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::Colon),
+                            found: Box::new(token),
+                            reason: "param pattern and type must be separated by a colon"
+                                .to_string(),
+                            span,
+                        }));
+                    }
+                }
+
+                let TySpanned { ty, .. } = match parser.parse(compiler, lexer, Precedence::Lowest) {
+                    Ok(ty) => ty,
+                    Err(e) => {
+                        return Some(Err(e));
+                    }
+                };
+
+                // `#` can't appear in an identifier, so the name won't clash with the source
+                let ident = compiler
+                    .symbols
+                    .get_or_intern(format!("#param{}", patterns.len()));
+                patterns.push((ident, pattern, span.clone()));
+
+                parse_state = ParseState::Comma;
+
+                return Some(Ok((ident, ty, false, span)));
+            }
+
+            let Some(next) = lexer.next_spanned() else {
+                return Some(Err(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                }));
+            };
+
+            match (&parse_state, next) {
                 // Parameter list finished
-                (_, Token::RightParen) => {
+                (_, (Token::RightParen, _)) if !mutable => {
                     return None;
                 }
                 // Comma encountered when expected
-                (ParseState::Comma, Token::Comma) => {
+                (ParseState::Comma, (Token::Comma, _)) => {
                     parse_state = ParseState::Item;
                 }
-                (ParseState::Comma, token) => {
+                (ParseState::Comma, (token, span)) => {
                     return Some(Err(ParseError::ExpectedToken {
                         expected: Box::new(Token::Comma),
                         found: Box::new(token),
                         reason: "function arguments must be separated by a comma".to_string(),
+                        span,
                     }))
                 }
                 // Parameter item encountered
-                (ParseState::Item, Token::Ident(ident)) => {
+                (ParseState::Item, (Token::Ident(ident), span)) => {
                     // Intern the parameter identifier
                     let ident = compiler.symbols.get_or_intern(ident);
 
                     // Ensure a colon follows it
-                    match lexer.next_token().unwrap() {
-                        Token::Colon => (),
-                        token => {
+                    match lexer.next_spanned() {
+                        Some((Token::Colon, _)) => (),
+                        None => {
+                            return Some(Err(ParseError::UnexpectedEOF {
+                                span: lexer.eof_span(),
+                            }));
+                        }
+                        Some((token, span)) => {
                             return Some(Err(ParseError::ExpectedToken {
                                 expected: Box::new(Token::Colon),
                                 found: Box::new(token),
                                 reason: "param name and type must be separated by a colon"
                                     .to_string(),
+                                span,
                             }));
                         }
                     }
@@ -102,13 +183,13 @@ pub fn parse_function(
 
                     parse_state = ParseState::Comma;
 
-                    return Some(Ok((ident, ty)));
+                    return Some(Ok((ident, ty, mutable, span)));
                 }
-                (ParseState::Item, token) => {
-                    return Some(Err(ParseError::ExpectedToken {
-                        expected: Box::new(Token::Ident(String::new())),
+                (ParseState::Item, (token, span)) => {
+                    return Some(Err(ParseError::ExpectedIdentifier {
                         found: Box::new(token),
                         reason: "parameter must have identifier".to_string(),
+                        span,
                     }))
                 }
             }
@@ -117,13 +198,16 @@ pub fn parse_function(
     .collect::<Result<Vec<_>, _>>()?;
 
     // arrow for return type
-    match lexer.next_token().unwrap() {
-        Token::ThinArrow => (),
-        token => {
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::ThinArrow, _) => (),
+        (token, span) => {
             return Err(ParseError::ExpectedToken {
                 expected: Box::new(Token::ThinArrow),
                 found: Box::new(token),
                 reason: "thin arrow must preceed return type".to_string(),
+                span,
             });
         }
     }
@@ -132,12 +216,28 @@ pub fn parse_function(
     let ty: TySpanned = parser.parse(compiler, lexer, Precedence::Lowest)?;
 
     // Parse out the body
-    let Expression::<UntypedAstMetadata>::Block(body) =
-        parser.parse(compiler, lexer, Precedence::Lowest)?
-    else {
-        return Err(ParseError::ExpectedBlock);
+    let mut body = match parser.parse(compiler, lexer, Precedence::Lowest)? {
+        Expression::<UntypedAstMetadata>::Block(body) => body,
+        expression => {
+            return Err(ParseError::ExpectedBlock {
+                span: expression.span().clone(),
+            });
+        }
     };
 
+    // Destructure any pattern parameters before the rest of the body
+    body.statements.splice(
+        0..0,
+        patterns.into_iter().map(|(ident, pattern, span)| {
+            Statement::Let(LetStatement::new(
+                pattern,
+                Expression::ident(ident, span.clone()),
+                span,
+                None,
+            ))
+        }),
+    );
+
     // Construct the function span to the end of the body
     let span = span_start..body.span.end;
 
@@ -145,7 +245,9 @@ pub fn parse_function(
         compiler.symbols.get_or_intern(fn_name),
         parameters,
         ty.ty,
+        ty.span,
         body,
+        docs,
         span,
     ))
 }
@@ -1,11 +1,14 @@
+mod enumeration;
 mod function;
 pub mod parser;
+mod structure;
 
 use std::collections::HashMap;
 use std::iter::Peekable;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use itertools::Itertools;
 use logos::Logos;
 use parser::Parser;
 
@@ -13,47 +16,106 @@ use crate::compiler::Compiler;
 use crate::hir::{Expression, Parsable, Statement};
 use crate::repr::token::*;
 use crate::ty::TySpanned;
-use crate::util::span::*;
+use crate::util::{diagnostic::Diagnostic, span::*};
 
+use self::enumeration::*;
 use self::function::*;
+use self::structure::*;
 
 use crate::repr::ast::untyped::*;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
-    #[error("unexpected token encountered: '{0}")]
-    UnexpectedToken(Token),
+    #[error("unexpected token encountered: '{token}'")]
+    UnexpectedToken { token: Token, span: Span },
 
     #[error("expected token '{expected}' but found '{found}': {reason}")]
     ExpectedToken {
         expected: Box<Token>,
         found: Box<Token>,
         reason: String,
+        span: Span,
     },
 
-    #[error("invalid infix left hand side: {reason} ({found:?})")]
+    #[error("expected identifier but found '{found}': {reason}")]
+    ExpectedIdentifier {
+        found: Box<Token>,
+        reason: String,
+        span: Span,
+    },
+
+    #[error("invalid infix left hand side: {reason}")]
     InvalidInfixLhs {
         found: Box<Expression<UntypedAstMetadata>>,
         reason: String,
+        span: Span,
     },
 
     #[error("invalid literal, expected `{expected}`")]
-    InvalidLiteral { expected: String },
+    InvalidLiteral { expected: String, span: Span },
 
     #[error("the main function is missing and must be present")]
-    MissingMain,
-
-    #[error("the function must have a return statement")]
-    MissingReturn,
+    MissingMain { span: Span },
 
     #[error("expected to parse a block")]
-    ExpectedBlock,
+    ExpectedBlock { span: Span },
+
+    #[error("unknown type: {name}")]
+    UnknownType { name: String, span: Span },
+
+    #[error("type `{name}` has already been declared")]
+    DuplicateType { name: String, span: Span },
+
+    #[error("field `{name}` has already been declared")]
+    DuplicateField { name: String, span: Span },
+
+    #[error("variant `{name}` has already been declared")]
+    DuplicateVariant { name: String, span: Span },
+
+    #[error("expected a loop following the label")]
+    ExpectedLoop { span: Span },
+
+    #[error("invalid token encountered: '{text}'")]
+    InvalidToken { text: String, span: Span },
+
+    #[error("block comment is missing closing `*/`")]
+    UnterminatedComment { span: Span },
 
     #[error("unexpectedly encountered end of file")]
-    UnexpectedEOF,
+    UnexpectedEOF { span: Span },
+
+    #[error("no parsers registered for type: {ty}")]
+    NoRegisteredParsers { ty: String, span: Span },
+}
+
+impl ParseError {
+    /// The location in the source that this error originated from.
+    pub fn span(&self) -> &Span {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::ExpectedToken { span, .. }
+            | ParseError::ExpectedIdentifier { span, .. }
+            | ParseError::InvalidInfixLhs { span, .. }
+            | ParseError::InvalidLiteral { span, .. }
+            | ParseError::MissingMain { span }
+            | ParseError::ExpectedBlock { span }
+            | ParseError::ExpectedLoop { span }
+            | ParseError::UnknownType { span, .. }
+            | ParseError::DuplicateType { span, .. }
+            | ParseError::DuplicateField { span, .. }
+            | ParseError::DuplicateVariant { span, .. }
+            | ParseError::InvalidToken { span, .. }
+            | ParseError::UnterminatedComment { span }
+            | ParseError::UnexpectedEOF { span }
+            | ParseError::NoRegisteredParsers { span, .. } => span,
+        }
+    }
+}
 
-    #[error("no parsers registered for type: {0}")]
-    NoRegisteredParsers(String),
+impl From<&ParseError> for Diagnostic {
+    fn from(error: &ParseError) -> Self {
+        Diagnostic::new(error, error.span().clone())
+    }
 }
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -63,9 +125,14 @@ pub enum Precedence {
     Assign,
     Binary,
     Equality,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
     Sum,
     Multiply,
     Cast,
+    Prefix,
     Call,
 }
 
@@ -73,7 +140,11 @@ impl Precedence {
     pub fn of(token: &Token) -> Self {
         match token {
             Token::Minus | Token::Plus => Precedence::Sum,
-            Token::Asterix | Token::ForwardSlash => Precedence::Multiply,
+            Token::Asterix | Token::ForwardSlash | Token::Percent => Precedence::Multiply,
+            Token::Pipe => Precedence::BitOr,
+            Token::Caret => Precedence::BitXor,
+            Token::Ampersand => Precedence::BitAnd,
+            Token::DoubleLeftAngle | Token::DoubleRightAngle => Precedence::Shift,
             Token::And | Token::Or => Precedence::Binary,
             Token::DoubleEq
             | Token::NotEq
@@ -81,12 +152,18 @@ impl Precedence {
             | Token::RightAngle
             | Token::LeftAngleEq
             | Token::RightAngleEq => Precedence::Equality,
-            Token::LeftParen | Token::LeftSquare => Precedence::Call,
+            Token::LeftParen | Token::LeftSquare | Token::Dot => Precedence::Call,
             Token::Eq
             | Token::AddAssign
             | Token::MinusAssign
             | Token::DivAssign
-            | Token::MulAssign => Precedence::Assign,
+            | Token::MulAssign
+            | Token::ModAssign
+            | Token::BitAndAssign
+            | Token::BitOrAssign
+            | Token::BitXorAssign
+            | Token::ShiftLeftAssign
+            | Token::ShiftRightAssign => Precedence::Assign,
             Token::As => Precedence::Cast,
             _ => Precedence::Lowest,
         }
@@ -99,7 +176,11 @@ impl From<InfixOperation> for Precedence {
 
         match op {
             Minus | Plus => Precedence::Sum,
-            Multiply | Divide => Precedence::Multiply,
+            Multiply | Divide | Modulo => Precedence::Multiply,
+            BitOr => Precedence::BitOr,
+            BitXor => Precedence::BitXor,
+            BitAnd => Precedence::BitAnd,
+            ShiftLeft | ShiftRight => Precedence::Shift,
             And | Or => Precedence::Binary,
             Eq | NotEq | Greater | Less | GreaterEq | LessEq => Precedence::Equality,
         }
@@ -112,7 +193,10 @@ impl From<Token> for Precedence {
     }
 }
 
-pub fn parse(compiler: &mut Compiler, source: &str) -> Result<Program, ParseError> {
+/// Parse the provided source into a [`Program`]. Parsing will recover from errors where possible,
+/// so that as many errors as possible can be reported at once. The program will only be produced
+/// if a `main` function is present, however it may still be incomplete if any errors are returned.
+pub fn parse(compiler: &mut Compiler, source: &str) -> (Option<Program>, Vec<ParseError>) {
     let mut lexer: Lexer = source.into();
 
     let parser = {
@@ -128,44 +212,169 @@ pub fn parse(compiler: &mut Compiler, source: &str) -> Result<Program, ParseErro
     // WARN: wacky af
     let main = compiler.symbols.get_or_intern("main");
 
-    // Parse each expression which should be followed by a semicolon
-    let mut functions = std::iter::from_fn(|| {
-        Some(match lexer.peek_token()? {
-            Token::Fn => parse_function(&parser, compiler, &mut lexer)
-                .map(|function| (function.name, function)),
+    // Declare every type up front, so that they can be referred to before their declaration
+    Token::lexer(source)
+        .filter_map(Result::ok)
+        .tuple_windows()
+        .for_each(|(token, next)| match (token, next) {
+            (Token::Struct, Token::Ident(name)) => {
+                compiler.types.declare(compiler.symbols.get_or_intern(name));
+            }
+            (Token::Enum, Token::Ident(name)) => {
+                compiler
+                    .types
+                    .declare_enum(compiler.symbols.get_or_intern(name));
+            }
+            _ => (),
+        });
+
+    // Parse each declaration, skipping to the next declaration if an error is encountered
+    let mut functions = HashMap::new();
+    let mut docs = Vec::new();
+    while let Some((token, span)) = lexer.peek_spanned() {
+        let result = match token {
+            // Collect documentation for the following declaration
+            Token::DocComment(doc) => {
+                docs.push(doc.clone());
+                lexer.next_token();
+                continue;
+            }
+            Token::Fn => parse_function(&parser, compiler, &mut lexer, std::mem::take(&mut docs))
+                .map(|function| {
+                    functions.insert(function.name, function);
+                }),
+            Token::Struct => {
+                docs.clear();
+                parse_struct(&parser, compiler, &mut lexer)
+            }
+            Token::Enum => {
+                docs.clear();
+                parse_enum(&parser, compiler, &mut lexer)
+            }
             token => Err(ParseError::ExpectedToken {
                 expected: Box::new(Token::Fn),
                 found: Box::new(token.clone()),
-                reason: "only functions can be declared at top level".to_string(),
+                reason: "only functions and types can be declared at top level".to_string(),
+                span: span.clone(),
             }),
-        })
-    })
-    .collect::<Result<HashMap<_, _>, _>>()?;
+        };
+
+        if let Err(e) = result {
+            lexer.report(e);
+            docs.clear();
+
+            // Skip to the next declaration, or the documentation preceding it
+            while !matches!(
+                lexer.peek_token(),
+                None | Some(Token::Fn | Token::Struct | Token::Enum | Token::DocComment(_))
+            ) {
+                lexer.next_token();
+            }
+        }
+    }
 
     let Some(main) = functions.remove(&main) else {
-        return Err(ParseError::MissingMain);
+        lexer.report(ParseError::MissingMain { span: 0..0 });
+
+        return (None, lexer.take_errors());
     };
 
-    let program = Program::new(
-        functions.into_values().collect(),
-        main,
-        // WARN: Really should be something better
-        Span::default(),
-    );
+    let program = Program::new(functions.into_values().collect(), main, 0..source.len());
 
-    Ok(program)
+    (Some(program), lexer.take_errors())
 }
 
 pub struct Lexer<'source> {
+    source: &'source str,
     next: Option<(Token, Span)>,
     lexer: Peekable<logos::SpannedIter<'source, Token>>,
+
+    /// Empty span positioned at the end of the source.
+    eof: Span,
+
+    /// Errors which have been recovered from whilst parsing the source.
+    errors: Vec<ParseError>,
 }
 
 impl<'source> Lexer<'source> {
     fn new(lexer: logos::Lexer<'source, Token>) -> Self {
+        let source = lexer.source();
+        let end = source.len();
+
         Self {
+            source,
             lexer: lexer.spanned().peekable(),
             next: None,
+            eof: end..end,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Span representing the end of the source, used when the source ends unexpectedly.
+    pub fn eof_span(&self) -> Span {
+        self.eof.clone()
+    }
+
+    /// Span of the next token, or the end of the source if there are no more tokens.
+    pub fn peek_span(&mut self) -> Span {
+        let eof = self.eof_span();
+
+        self.peek_spanned()
+            .map(|(_, span)| span.clone())
+            .unwrap_or(eof)
+    }
+
+    /// Record an error which the parser has recovered from, so that parsing can continue.
+    pub fn report(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+
+    /// Take all of the errors which have been reported so far.
+    pub fn take_errors(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Skip tokens until a synchronisation point is reached, so that parsing can resume after an
+    /// error. A semicolon will be consumed, whilst a closing brace or declaration keyword will be
+    /// left for the caller. Any blocks encountered along the way will be skipped in their entirety.
+    pub fn synchronise(&mut self) {
+        let mut depth = 0usize;
+
+        while let Some(token) = self.peek_token() {
+            match token {
+                Token::Fn | Token::Struct | Token::Enum => return,
+                Token::RightBrace if depth == 0 => return,
+                Token::SemiColon if depth == 0 => {
+                    self.next_token();
+                    return;
+                }
+                Token::LeftBrace => depth += 1,
+                Token::RightBrace => depth -= 1,
+                _ => (),
+            }
+
+            self.next_token();
+        }
+    }
+
+    /// Consume the semicolon which terminates a statement, producing its span. Any other token is
+    /// left in place, so that a missing semicolon doesn't swallow the token following it.
+    pub fn expect_semicolon(&mut self, reason: &str) -> Result<Span, ParseError> {
+        match self.peek_token() {
+            Some(Token::SemiColon) => Ok(self.next_spanned().unwrap().1),
+            Some(token) => {
+                let found = Box::new(token.clone());
+
+                Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::SemiColon),
+                    found,
+                    reason: reason.to_string(),
+                    span: self.peek_span(),
+                })
+            }
+            None => Err(ParseError::UnexpectedEOF {
+                span: self.eof_span(),
+            }),
         }
     }
 
@@ -182,13 +391,17 @@ impl<'source> Lexer<'source> {
     }
 
     pub fn peek_spanned(&mut self) -> Option<(&Token, &Span)> {
+        if self.next.is_none() {
+            self.skip_invalid();
+        }
+
         self.next
             .as_ref()
             .map(|(token, span)| (token, span))
             .or_else(|| {
                 self.lexer
                     .peek()
-                    .map(|(result, span)| (result.as_ref().unwrap(), span))
+                    .and_then(|(result, span)| result.as_ref().ok().map(|token| (token, span)))
             })
     }
 
@@ -197,15 +410,64 @@ impl<'source> Lexer<'source> {
             self.next = self.next();
         }
 
+        // Make sure the token following the buffered one is valid
+        self.skip_invalid();
+
         self.lexer
             .peek()
-            .map(|(result, _)| result.as_ref().unwrap())
+            .and_then(|(result, _)| result.as_ref().ok())
+    }
+
+    /// Report any invalid tokens at the front of the source, leaving the next valid token in
+    /// place.
+    fn skip_invalid(&mut self) {
+        while let Some((Err(error), span)) = self.lexer.peek() {
+            let (error, span) = (error.clone(), span.clone());
+            self.lexer.next();
+            self.report_lex_error(error, span);
+        }
     }
 
+    /// Pull the next valid token out of the source, reporting any invalid tokens along the way.
     fn next(&mut self) -> Option<(Token, Span)> {
-        self.lexer
-            .next()
-            .map(|(result, span)| (result.unwrap(), span))
+        loop {
+            match self.lexer.next()? {
+                (Ok(token), span) => return Some((token, span)),
+                (Err(error), span) => self.report_lex_error(error, span),
+            }
+        }
+    }
+
+    /// Report an error encountered whilst lexing. Adjacent invalid tokens will be merged with the
+    /// previously reported invalid token, so that a run of invalid characters only produces a
+    /// single error.
+    fn report_lex_error(&mut self, error: LexError, span: Span) {
+        if error == LexError::UnterminatedComment {
+            // Only point to the opening of the comment, rather than the remainder of the source
+            self.report(ParseError::UnterminatedComment {
+                span: span.start..span.start + "/*".len(),
+            });
+
+            return;
+        }
+
+        if let Some(ParseError::InvalidToken {
+            text,
+            span: previous,
+        }) = self.errors.last_mut()
+        {
+            if previous.end == span.start {
+                previous.end = span.end;
+                *text = self.source[previous.clone()].to_string();
+
+                return;
+            }
+        }
+
+        self.report(ParseError::InvalidToken {
+            text: self.source[span.clone()].to_string(),
+            span,
+        });
     }
 
     #[allow(dead_code)]
@@ -239,3 +501,190 @@ impl<'source> DerefMut for Lexer<'source> {
         &mut self.lexer
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::valid("fn main() -> int { return 1; }", 0)]
+    #[case::statement("fn main() -> int { let = 1; return 1; }", 1)]
+    #[case::multiple_statements("fn main() -> int { let = 1; let a 2; return 1; }", 2)]
+    #[case::parameters("fn other(a) -> int { return 1; } fn main() -> int { return 1; }", 1)]
+    #[case::mut_without_parameter(
+        "fn other(mut) -> int { return 1; } fn main() -> int { return 1; }",
+        1
+    )]
+    #[case::top_level("let a = 1; fn main() -> int { return 1; }", 1)]
+    #[case::unclosed_block("fn other() -> int { return 1; fn main() -> int { return 1; }", 1)]
+    #[case::invalid_token("fn main() -> int { @ return 1; }", 1)]
+    #[case::across_functions(
+        "fn other() -> int { let = 1; return 1; } fn main() -> int { let = 2; return 1; }",
+        2
+    )]
+    #[case::return_without_semicolon("fn main() -> int { return 1 }", 1)]
+    #[case::let_without_semicolon("fn main() -> int { let a = 1 } fn other() -> int { 1 }", 1)]
+    #[case::break_without_semicolon("fn main() -> int { loop { break } }", 1)]
+    #[case::continue_without_semicolon("fn main() -> int { loop { continue } }", 1)]
+    fn recover(#[case] source: &str, #[case] errors: usize) {
+        let (program, parse_errors) = parse(&mut Compiler::default(), source);
+
+        assert!(program.is_some());
+        assert_eq!(parse_errors.len(), errors);
+    }
+
+    #[test]
+    fn missing_main() {
+        let (program, errors) = parse(&mut Compiler::default(), "fn other() -> int { let = 1; }");
+
+        assert!(program.is_none());
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                ParseError::ExpectedIdentifier { .. },
+                ParseError::MissingMain { .. }
+            ]
+        ));
+    }
+
+    #[rstest]
+    #[case::single("a @ b", &[("@", 2..3)])]
+    #[case::adjacent("a @$ b", &[("@$", 2..4)])]
+    #[case::separate("@ a $", &[("@", 0..1), ("$", 4..5)])]
+    #[case::integer_overflow("99999999999999999999", &[("99999999999999999999", 0..20)])]
+    fn invalid_token(#[case] source: &str, #[case] expected: &[(&str, Span)]) {
+        let mut lexer = Lexer::from(source);
+        while lexer.next_token().is_some() {}
+
+        let errors = lexer.take_errors();
+        assert_eq!(errors.len(), expected.len());
+
+        for (error, (expected_text, expected_span)) in errors.iter().zip(expected) {
+            let ParseError::InvalidToken { text, span } = error else {
+                panic!("expected invalid token error");
+            };
+
+            assert_eq!(text, expected_text);
+            assert_eq!(span, expected_span);
+        }
+    }
+
+    fn ident(name: &str) -> Token {
+        Token::Ident(name.to_string())
+    }
+
+    #[rstest]
+    #[case::line("a // b\nc", vec![ident("a"), ident("c")])]
+    #[case::line_end_of_source("a // b", vec![ident("a")])]
+    #[case::line_many_slashes("a //// b\nc", vec![ident("a"), ident("c")])]
+    #[case::block("a /* b */ c", vec![ident("a"), ident("c")])]
+    #[case::block_multiline("a /* b\n c */ d", vec![ident("a"), ident("d")])]
+    #[case::block_nested("a /* b /* c */ d */ e", vec![ident("a"), ident("e")])]
+    #[case::doc("/// a\nb", vec![Token::DocComment("a".to_string()), ident("b")])]
+    fn comments(#[case] source: &str, #[case] expected: Vec<Token>) {
+        let mut lexer = Lexer::from(source);
+        let tokens = std::iter::from_fn(|| lexer.next_token()).collect::<Vec<_>>();
+
+        assert_eq!(tokens, expected);
+        assert!(lexer.take_errors().is_empty());
+    }
+
+    #[test]
+    fn unterminated_comment() {
+        let mut lexer = Lexer::from("a /* b /* c */");
+        while lexer.next_token().is_some() {}
+
+        assert!(matches!(
+            lexer.take_errors().as_slice(),
+            [ParseError::UnterminatedComment { span }] if *span == (2..4)
+        ));
+    }
+
+    #[rstest]
+    #[case::struct_declaration("struct Point { x: int, y: bool } fn main() -> int { 1 }", 0)]
+    #[case::struct_trailing_comma("struct Point { x: int, } fn main() -> int { 1 }", 0)]
+    #[case::struct_used_before_declaration(
+        "fn main() -> int { let p = Point { x: 1 }; p.x } struct Point { x: int }",
+        0
+    )]
+    #[case::struct_nested("struct A { b: B } struct B { c: int } fn main() -> int { 1 }", 0)]
+    #[case::struct_unknown_field_ty("struct Point { x: Other } fn main() -> int { 1 }", 1)]
+    #[case::struct_missing_colon("struct Point { x int } fn main() -> int { 1 }", 1)]
+    #[case::struct_duplicate_field("struct Point { x: int, x: int } fn main() -> int { 1 }", 1)]
+    #[case::struct_duplicate(
+        "struct Point { x: int } struct Point { y: int } fn main() -> int { 1 }",
+        1
+    )]
+    fn structs(#[case] source: &str, #[case] errors: usize) {
+        let (program, parse_errors) = parse(&mut Compiler::default(), source);
+
+        assert!(program.is_some());
+        assert_eq!(parse_errors.len(), errors);
+    }
+
+    #[rstest]
+    #[case::enum_declaration(
+        "enum Shape { Circle(int), Rect(int, int), Empty } fn main() -> int { 1 }",
+        0
+    )]
+    #[case::enum_trailing_comma("enum Shape { Empty, } fn main() -> int { 1 }", 0)]
+    #[case::enum_used_before_declaration(
+        "fn main() -> int { let s = Shape::Empty; 1 } enum Shape { Empty }",
+        0
+    )]
+    #[case::enum_in_struct("struct A { s: Shape } enum Shape { Empty } fn main() -> int { 1 }", 0)]
+    #[case::enum_unknown_payload_ty("enum Shape { Circle(Other) } fn main() -> int { 1 }", 1)]
+    #[case::enum_duplicate_variant("enum Shape { Empty, Empty } fn main() -> int { 1 }", 1)]
+    #[case::enum_clashes_with_struct(
+        "struct Shape { } enum Shape { Empty } fn main() -> int { 1 }",
+        2
+    )]
+    fn enums(#[case] source: &str, #[case] errors: usize) {
+        let (program, parse_errors) = parse(&mut Compiler::default(), source);
+
+        assert!(program.is_some());
+        assert_eq!(parse_errors.len(), errors);
+    }
+
+    #[rstest]
+    #[case::tuple_param("fn f((a, b): (int, bool)) -> int { a } fn main() -> int { 1 }", 0)]
+    #[case::mixed_params(
+        "fn f(x: int, (a, _): (int, int)) -> int { a } fn main() -> int { 1 }",
+        0
+    )]
+    #[case::tuple_return("fn f() -> (int, bool) { (1, true) } fn main() -> int { f().0 }", 0)]
+    #[case::unit_ty("fn f(a: ()) -> int { 1 } fn main() -> int { 1 }", 0)]
+    #[case::param_missing_ty("fn f((a, b)) -> int { a } fn main() -> int { 1 }", 1)]
+    #[case::unclosed_ty("fn f() -> (int, bool { 1 } fn main() -> int { 1 }", 1)]
+    fn tuples(#[case] source: &str, #[case] errors: usize) {
+        let (program, parse_errors) = parse(&mut Compiler::default(), source);
+
+        assert!(program.is_some());
+        assert_eq!(parse_errors.len(), errors);
+    }
+
+    #[rstest]
+    #[case::array_param("fn f(a: [int; 3]) -> int { a[0] } fn main() -> int { 1 }", 0)]
+    #[case::array_return("fn f() -> [bool; 2] { [true; 2] } fn main() -> int { 1 }", 0)]
+    #[case::nested_ty("fn f(a: [[int; 2]; 3]) -> int { a[0][1] } fn main() -> int { 1 }", 0)]
+    #[case::missing_length("fn f(a: [int]) -> int { 1 } fn main() -> int { 1 }", 1)]
+    #[case::negative_length("fn f(a: [int; -1]) -> int { 1 } fn main() -> int { 1 }", 1)]
+    fn arrays(#[case] source: &str, #[case] errors: usize) {
+        let (program, parse_errors) = parse(&mut Compiler::default(), source);
+
+        assert!(program.is_some());
+        assert_eq!(parse_errors.len(), errors);
+    }
+
+    #[test]
+    fn function_docs() {
+        let source =
+            "/// First line.\n/// Second line.\nfn main() -> int {\n/// Ignored.\nreturn 1; }";
+
+        let (program, errors) = parse(&mut Compiler::default(), source);
+
+        assert!(errors.is_empty());
+        assert_eq!(program.unwrap().main.docs, ["First line.", "Second line."]);
+    }
+}
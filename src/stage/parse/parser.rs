@@ -137,12 +137,20 @@ impl<T> ParserRules<T> {
         compiler: &mut Compiler,
         lexer: &mut Lexer,
     ) -> Result<T, ParseError> {
-        let token = lexer.peek_token().ok_or(ParseError::UnexpectedEOF)?.clone();
+        let (token, span) = lexer
+            .peek_spanned()
+            .map(|(token, span)| (token.clone(), span.clone()))
+            .ok_or(ParseError::UnexpectedEOF {
+                span: lexer.eof_span(),
+            })?;
 
         let prefix_parser = self
             .get_prefix_parser(&token)
             .or(self.fallback.as_deref())
-            .ok_or_else(|| ParseError::UnexpectedToken(token.clone()))?;
+            .ok_or_else(|| ParseError::UnexpectedToken {
+                token: token.clone(),
+                span,
+            })?;
 
         prefix_parser(parser, compiler, lexer)
     }
@@ -205,7 +213,10 @@ impl Parser {
         precedence: P,
     ) -> Result<T, ParseError> {
         self.get::<T>()
-            .ok_or_else(|| ParseError::NoRegisteredParsers(std::any::type_name::<T>().to_string()))?
+            .ok_or_else(|| ParseError::NoRegisteredParsers {
+                ty: std::any::type_name::<T>().to_string(),
+                span: lexer.peek_span(),
+            })?
             .parse(self, compiler, lexer, precedence)
     }
 
@@ -352,7 +363,10 @@ mod test {
             Precedence::Lowest,
         );
 
-        assert!(matches!(result, Err(ParseError::NoRegisteredParsers(_))));
+        assert!(matches!(
+            result,
+            Err(ParseError::NoRegisteredParsers { .. })
+        ));
     }
 
     mod delimited {
@@ -438,11 +452,12 @@ mod test {
             parser.register_prefix(Token::True, |_, _, lexer| {
                 let span = match lexer.next_spanned().unwrap() {
                     (Token::True, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::True),
                             found: Box::new(token),
                             reason: "expected true".to_string(),
+                            span,
                         });
                     }
                 };
@@ -457,11 +472,12 @@ mod test {
             parser.register_prefix(Token::False, |_, _, lexer| {
                 let span = match lexer.next_spanned().unwrap() {
                     (Token::False, span) => span,
-                    (token, _) => {
+                    (token, span) => {
                         return Err(ParseError::ExpectedToken {
                             expected: Box::new(Token::False),
                             found: Box::new(token),
                             reason: "expected false".to_string(),
+                            span,
                         });
                     }
                 };
@@ -478,11 +494,12 @@ mod test {
                 |_, _, lexer| {
                     let (value, span) = match lexer.next_spanned().unwrap() {
                         (Token::Integer(value), span) => (value, span),
-                        (token, _) => {
+                        (token, span) => {
                             return Err(ParseError::ExpectedToken {
                                 expected: Box::new(Token::Integer(1)),
                                 found: Box::new(token),
                                 reason: "expected integer".to_string(),
+                                span,
                             });
                         }
                     };
@@ -0,0 +1,132 @@
+use crate::{
+    compiler::Symbol,
+    ty::{Ty, TySpanned},
+};
+
+use super::*;
+
+/// Parse a struct declaration, defining its fields within the compiler. The struct must have
+/// already been declared with the compiler.
+pub fn parse_struct(
+    parser: &Parser,
+    compiler: &mut Compiler,
+    lexer: &mut Lexer<'_>,
+) -> Result<(), ParseError> {
+    // `struct` keyword
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Struct, _) => (),
+        (token, span) => {
+            return Err(ParseError::ExpectedToken {
+                expected: Box::new(Token::Struct),
+                found: Box::new(token),
+                reason: "struct declaration must begin with keyword".to_string(),
+                span,
+            });
+        }
+    }
+
+    // struct name
+    let (name, name_span) = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Ident(name), span) => (name, span),
+        (token, span) => {
+            return Err(ParseError::ExpectedIdentifier {
+                found: Box::new(token),
+                reason: "struct declaration requires identifier".to_string(),
+                span,
+            });
+        }
+    };
+
+    // opening brace for field list
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::LeftBrace, _) => (),
+        (token, span) => {
+            return Err(ParseError::ExpectedToken {
+                expected: Box::new(Token::LeftBrace),
+                found: Box::new(token),
+                reason: "field list must begin with opening brace".to_string(),
+                span,
+            });
+        }
+    }
+
+    // Parse each of the fields, which may have a trailing comma
+    let mut fields: Vec<(Symbol, Ty)> = Vec::new();
+    loop {
+        let (field, field_span) = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::RightBrace, _) => break,
+            (Token::Ident(field), span) => (field, span),
+            (token, span) => {
+                return Err(ParseError::ExpectedIdentifier {
+                    found: Box::new(token),
+                    reason: "field must have identifier".to_string(),
+                    span,
+                });
+            }
+        };
+
+        let symbol = compiler.symbols.get_or_intern(&field);
+        if fields.iter().any(|(test_symbol, _)| *test_symbol == symbol) {
+            return Err(ParseError::DuplicateField {
+                name: field,
+                span: field_span,
+            });
+        }
+
+        // Ensure a colon follows it
+        match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::Colon, _) => (),
+            (token, span) => {
+                return Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::Colon),
+                    found: Box::new(token),
+                    reason: "field name and type must be separated by a colon".to_string(),
+                    span,
+                });
+            }
+        }
+
+        let TySpanned { ty, .. } = parser.parse(compiler, lexer, Precedence::Lowest)?;
+        fields.push((symbol, ty));
+
+        // Fields are separated by commas, unless the list is finished
+        match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+            span: lexer.eof_span(),
+        })? {
+            (Token::Comma, _) => (),
+            (Token::RightBrace, _) => break,
+            (token, span) => {
+                return Err(ParseError::ExpectedToken {
+                    expected: Box::new(Token::Comma),
+                    found: Box::new(token),
+                    reason: "fields must be separated by a comma".to_string(),
+                    span,
+                });
+            }
+        }
+    }
+
+    // Struct names must not clash with any other type
+    let symbol = compiler.symbols.get_or_intern(&name);
+    let idx = compiler.types.declare(symbol);
+    if compiler.types.get_enum_idx(symbol).is_some()
+        || !compiler.types.define(idx, fields, name_span.clone())
+    {
+        return Err(ParseError::DuplicateType {
+            name,
+            span: name_span,
+        });
+    }
+
+    Ok(())
+}
@@ -1,24 +1,216 @@
-use crate::compiler::Symbol;
+use crate::{
+    compiler::Compiler,
+    util::{diagnostic::Diagnostic, span::Span},
+};
 
 use super::*;
 
 #[derive(Debug, thiserror::Error)]
 pub enum TyError {
-    #[error("mismatched types: {0:?} and {1:?}")]
-    Mismatch(Ty, Ty),
+    #[error("mismatched types: expected {expected} but found {found}")]
+    Mismatch {
+        expected: String,
+        found: String,
+        span: Span,
+        /// Location that the expected type originated from, if known.
+        expected_span: Option<Span>,
+    },
+
+    #[error("not all paths return a value of type {ty}")]
+    MissingReturn {
+        ty: String,
+        span: Span,
+        /// Location of the return type that requires a value.
+        return_ty_span: Span,
+    },
 
-    #[error("invalid return type, expected {expected:?} but found {found:?}")]
-    Return {
-        expected: Option<Ty>,
-        found: Option<Ty>,
+    #[error("cannot cast {from} to {to}")]
+    Cast {
+        from: String,
+        to: String,
+        span: Span,
     },
 
-    #[error("cannot cast {0:?} to {1:?}")]
-    Cast(Ty, Ty),
+    #[error("cannot perform index on {ty}")]
+    Index { ty: String, span: Span },
+
+    #[error("index out of bounds: the length is {length} but the index is {index}")]
+    IndexOutOfBounds { index: i64, length: u32, span: Span },
+
+    #[error("mismatched array lengths: expected {expected} elements but found {found}")]
+    ArrayLength {
+        expected: u32,
+        found: u32,
+        span: Span,
+        /// Location that the expected length originated from, if known.
+        expected_span: Option<Span>,
+    },
+
+    #[error("symbol not found: {name}")]
+    SymbolNotFound { name: String, span: Span },
+
+    #[error("literal out of range for {ty}")]
+    LiteralOutOfRange { ty: String, span: Span },
+
+    #[error("use of undeclared label '{label}")]
+    UnknownLabel { label: String, span: Span },
+
+    #[error("cannot assign to immutable binding {binding}")]
+    AssignToImmutable {
+        binding: String,
+        span: Span,
+        /// Location that the binding was declared.
+        declaration_span: Span,
+    },
+
+    #[error("`break` and `continue` can only be used within a loop")]
+    OutsideLoop { span: Span },
+
+    #[error("{ty} has no field {field}")]
+    NoField {
+        ty: String,
+        field: String,
+        span: Span,
+    },
+
+    #[error("missing field {field} in struct literal")]
+    MissingField { field: String, span: Span },
+
+    #[error("field {field} specified more than once")]
+    DuplicateField { field: String, span: Span },
+
+    #[error("{ty} has no variant {variant}")]
+    UnknownVariant {
+        ty: String,
+        variant: String,
+        span: Span,
+    },
+
+    #[error("variant expects {expected} values but found {found}")]
+    VariantArity {
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    #[error("{ty} has no element {index}")]
+    NoElement { ty: String, index: u32, span: Span },
+
+    #[error("cannot destructure {ty} with this pattern")]
+    Destructure { ty: String, span: Span },
+
+    #[error("cannot match on {ty}")]
+    Match { ty: String, span: Span },
+
+    #[error("match is not exhaustive, missing variants {}", .missing.join(", "))]
+    NonExhaustive { missing: Vec<String>, span: Span },
+
+    #[error("recursive type {name} has infinite size")]
+    InfiniteSize { name: String, span: Span },
+}
+
+impl TyError {
+    /// The location in the source that this error originated from.
+    pub fn span(&self) -> &Span {
+        match self {
+            TyError::Mismatch { span, .. }
+            | TyError::MissingReturn { span, .. }
+            | TyError::Cast { span, .. }
+            | TyError::Index { span, .. }
+            | TyError::IndexOutOfBounds { span, .. }
+            | TyError::ArrayLength { span, .. }
+            | TyError::SymbolNotFound { span, .. }
+            | TyError::LiteralOutOfRange { span, .. }
+            | TyError::UnknownLabel { span, .. }
+            | TyError::AssignToImmutable { span, .. }
+            | TyError::NoField { span, .. }
+            | TyError::MissingField { span, .. }
+            | TyError::DuplicateField { span, .. }
+            | TyError::UnknownVariant { span, .. }
+            | TyError::VariantArity { span, .. }
+            | TyError::NoElement { span, .. }
+            | TyError::Destructure { span, .. }
+            | TyError::Match { span, .. }
+            | TyError::NonExhaustive { span, .. }
+            | TyError::InfiniteSize { span, .. }
+            | TyError::OutsideLoop { span } => span,
+        }
+    }
+}
+
+impl From<&TyError> for Diagnostic {
+    fn from(error: &TyError) -> Self {
+        let diagnostic = Diagnostic::new(error, error.span().clone());
+
+        match error {
+            TyError::Mismatch {
+                expected_span: Some(expected_span),
+                ..
+            } => diagnostic.with_note("expected type declared here", expected_span.clone()),
+            TyError::MissingReturn { return_ty_span, .. } => diagnostic.with_note(
+                "expected because of this return type",
+                return_ty_span.clone(),
+            ),
+            TyError::AssignToImmutable {
+                declaration_span, ..
+            } => diagnostic.with_note(
+                "binding declared here, consider making it `mut`",
+                declaration_span.clone(),
+            ),
+            TyError::ArrayLength {
+                expected_span: Some(expected_span),
+                ..
+            } => diagnostic.with_note("expected length declared here", expected_span.clone()),
+            _ => diagnostic,
+        }
+    }
+}
+
+/// Two types which were expected to be the same, before the mismatch has been attributed to a
+/// location in the source.
+#[derive(Debug)]
+pub struct TyMismatch {
+    pub expected: Ty,
+    pub found: Ty,
+}
 
-    #[error("cannot perform index on {0:?}")]
-    Index(Ty),
+impl TyMismatch {
+    /// Attribute this mismatch to a location in the source, naming the types through the compiler.
+    pub fn at(self, compiler: &Compiler, span: Span) -> TyError {
+        self.at_with_expected(compiler, span, None)
+    }
 
-    #[error("symbol not found: {0:?}")]
-    SymbolNotFound(Symbol),
+    /// Attribute this mismatch to a location in the source, along with the location that the
+    /// expected type originated from. Arrays which only differ in their length will produce a
+    /// dedicated error, as the element types are otherwise compatible.
+    pub fn at_with_expected(
+        self,
+        compiler: &Compiler,
+        span: Span,
+        expected_span: Option<Span>,
+    ) -> TyError {
+        match (&self.expected, &self.found) {
+            (
+                Ty::Array {
+                    inner: expected_inner,
+                    size: expected,
+                },
+                Ty::Array {
+                    inner: found_inner,
+                    size: found,
+                },
+            ) if expected_inner == found_inner => TyError::ArrayLength {
+                expected: *expected,
+                found: *found,
+                span,
+                expected_span,
+            },
+            _ => TyError::Mismatch {
+                expected: self.expected.name(compiler),
+                found: self.found.name(compiler),
+                span,
+                expected_span,
+            },
+        }
+    }
 }
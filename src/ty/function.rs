@@ -14,7 +14,7 @@ impl<M: AstMetadata> From<&hir::Function<M>> for FunctionSignature {
             arguments: function
                 .parameters
                 .iter()
-                .map(|(_, ty)| ty.clone())
+                .map(|(_, ty, _, _)| ty.clone())
                 .collect(),
             return_ty: function.return_ty.clone(),
         }
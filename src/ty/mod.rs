@@ -4,18 +4,43 @@ mod ty_info;
 
 use std::ops::Range;
 
-use crate::{hir::Parsable, repr::token::Token, stage::parse::parser::Parser};
+use itertools::Itertools;
 
-pub use self::{error::TyError, function::FunctionSignature, ty_info::TyInfo};
+use crate::{
+    compiler::Compiler,
+    hir::Parsable,
+    repr::{
+        identifier::{EnumIdx, StructIdx},
+        token::Token,
+    },
+    stage::parse::{parser::Parser, Lexer, ParseError, Precedence},
+};
+
+pub use self::{
+    error::{TyError, TyMismatch},
+    function::FunctionSignature,
+    ty_info::TyInfo,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Ty {
     Int,
+    I8,
+    I16,
+    I32,
     Uint,
+    U8,
+    U16,
+    U32,
+    F32,
+    F64,
     Boolean,
     Unit,
     Never,
     Array { inner: Box<Ty>, size: u32 },
+    Struct(StructIdx),
+    Enum(EnumIdx),
+    Tuple(Vec<Ty>),
 }
 
 impl Ty {
@@ -26,6 +51,142 @@ impl Ty {
             _ => false,
         }
     }
+
+    /// Whether this type is a signed integer, which determines how operations upon it behave.
+    pub fn is_signed(&self) -> bool {
+        matches!(self, Ty::Int | Ty::I8 | Ty::I16 | Ty::I32)
+    }
+
+    /// Whether this type is an integer of any size or signedness.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Ty::Int | Ty::I8 | Ty::I16 | Ty::I32 | Ty::Uint | Ty::U8 | Ty::U16 | Ty::U32
+        )
+    }
+
+    /// Whether this type is a floating point number.
+    pub fn is_float(&self) -> bool {
+        matches!(self, Ty::F32 | Ty::F64)
+    }
+
+    /// Whether this type is any kind of number.
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    /// Whether this type is made up of other values, rather than being a single scalar.
+    pub fn is_aggregate(&self) -> bool {
+        matches!(
+            self,
+            Ty::Array { .. } | Ty::Struct(_) | Ty::Enum(_) | Ty::Tuple(_)
+        )
+    }
+
+    /// Number of bits used to represent a numeric type.
+    pub fn bits(&self) -> Option<u32> {
+        match self {
+            Ty::I8 | Ty::U8 => Some(8),
+            Ty::I16 | Ty::U16 => Some(16),
+            Ty::I32 | Ty::U32 | Ty::F32 => Some(32),
+            Ty::Int | Ty::Uint | Ty::F64 => Some(64),
+            _ => None,
+        }
+    }
+
+    /// Determine whether an integer literal can be represented by this type. As literals are
+    /// always positive, a `negated` literal of a signed type may also be the magnitude of its
+    /// minimum value.
+    pub fn fits_literal(&self, value: u64, negated: bool) -> bool {
+        let Some(bits) = self.bits().filter(|_| self.is_integer()) else {
+            return false;
+        };
+
+        let max = if self.is_signed() {
+            (1u64 << (bits - 1)) - u64::from(!negated)
+        } else {
+            u64::MAX >> (64 - bits)
+        };
+
+        value <= max
+    }
+
+    /// Produce the name of this type as it would be written in the source, resolving the names of
+    /// any user-defined types through the compiler.
+    pub fn name(&self, compiler: &Compiler) -> String {
+        let resolve = |symbol| compiler.symbols.resolve(symbol).unwrap().to_string();
+
+        match self {
+            Ty::Int => "int".to_string(),
+            Ty::I8 => "i8".to_string(),
+            Ty::I16 => "i16".to_string(),
+            Ty::I32 => "i32".to_string(),
+            Ty::Uint => "uint".to_string(),
+            Ty::U8 => "u8".to_string(),
+            Ty::U16 => "u16".to_string(),
+            Ty::U32 => "u32".to_string(),
+            Ty::F32 => "f32".to_string(),
+            Ty::F64 => "f64".to_string(),
+            Ty::Boolean => "bool".to_string(),
+            Ty::Unit => "()".to_string(),
+            Ty::Never => "!".to_string(),
+            Ty::Array { inner, size } => format!("[{}; {size}]", inner.name(compiler)),
+            Ty::Tuple(tys) => format!("({})", tys.iter().map(|ty| ty.name(compiler)).join(", ")),
+            Ty::Struct(idx) => resolve(
+                compiler
+                    .types
+                    .get(*idx)
+                    .expect("struct must be registered")
+                    .symbol(),
+            ),
+            Ty::Enum(idx) => resolve(
+                compiler
+                    .types
+                    .get_enum(*idx)
+                    .expect("enum must be registered")
+                    .symbol(),
+            ),
+        }
+    }
+
+    /// Find the primitive type which corresponds to a token.
+    pub fn from_token(token: &Token) -> Option<Ty> {
+        Some(match token {
+            Token::Int => Ty::Int,
+            Token::I8 => Ty::I8,
+            Token::I16 => Ty::I16,
+            Token::I32 => Ty::I32,
+            Token::Uint => Ty::Uint,
+            Token::U8 => Ty::U8,
+            Token::U16 => Ty::U16,
+            Token::U32 => Ty::U32,
+            Token::F32 => Ty::F32,
+            Token::F64 => Ty::F64,
+            Token::Bool => Ty::Boolean,
+            _ => return None,
+        })
+    }
+}
+
+/// Parse the length of an array, which must be written as an integer literal that fits within a
+/// `u32`.
+pub fn parse_array_length(lexer: &mut Lexer) -> Result<u32, ParseError> {
+    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+        span: lexer.eof_span(),
+    })? {
+        (Token::Integer(length), span) => {
+            u32::try_from(length).map_err(|_| ParseError::InvalidLiteral {
+                expected: "array length".to_string(),
+                span,
+            })
+        }
+        (token, span) => Err(ParseError::ExpectedToken {
+            expected: Box::new(Token::Integer(0)),
+            found: Box::new(token),
+            reason: "array length must be an integer literal".to_string(),
+            span,
+        }),
+    }
 }
 
 pub struct TySpanned {
@@ -33,14 +194,44 @@ pub struct TySpanned {
     pub span: Range<usize>,
 }
 
+impl TySpanned {
+    /// Parse a numeric type suffix directly following a literal which ends at the provided offset.
+    /// The suffix must not be separated from the literal by any whitespace.
+    pub fn parse_suffix(lexer: &mut Lexer, literal_end: usize) -> Option<TySpanned> {
+        let (token, span) = lexer.peek_spanned()?;
+
+        let ty = Ty::from_token(token).filter(|ty| ty.is_numeric())?;
+        if span.start != literal_end {
+            return None;
+        }
+
+        let span = span.clone();
+        lexer.next_token();
+
+        Some(TySpanned { ty, span })
+    }
+}
+
 impl Parsable for TySpanned {
     fn register(parser: &mut Parser) {
         [
-            (Token::Int, Ty::Int),
-            (Token::Uint, Ty::Uint),
-            (Token::Bool, Ty::Boolean),
+            Token::Int,
+            Token::I8,
+            Token::I16,
+            Token::I32,
+            Token::Uint,
+            Token::U8,
+            Token::U16,
+            Token::U32,
+            Token::F32,
+            Token::F64,
+            Token::Bool,
         ]
         .into_iter()
+        .map(|token| {
+            let ty = Ty::from_token(&token).expect("token must be a primitive type");
+            (token, ty)
+        })
         .for_each(|(token, ty)| {
             assert!(parser.register_prefix(token, move |_, _, lexer| {
                 let (_, span) = lexer.next_spanned().unwrap();
@@ -51,5 +242,129 @@ impl Parsable for TySpanned {
                 })
             }));
         });
+
+        // Parenthesised types, where a comma signifies a tuple
+        assert!(parser.register_prefix::<TySpanned>(
+            Token::LeftParen,
+            |parser, compiler, lexer| {
+                let (_, start_span) = lexer.next_spanned().unwrap();
+
+                let mut tys = Vec::new();
+                let mut is_tuple = false;
+                let end_span = loop {
+                    if let Some((Token::RightParen, _)) = lexer.peek_spanned() {
+                        break lexer.next_spanned().unwrap().1;
+                    }
+
+                    let TySpanned { ty, .. } = parser.parse(compiler, lexer, Precedence::Lowest)?;
+                    tys.push(ty);
+
+                    match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                        span: lexer.eof_span(),
+                    })? {
+                        (Token::Comma, _) => is_tuple = true,
+                        (Token::RightParen, span) => break span,
+                        (token, span) => {
+                            return Err(ParseError::ExpectedToken {
+                                expected: Box::new(Token::Comma),
+                                found: Box::new(token),
+                                reason: "tuple element types must be separated by a comma"
+                                    .to_string(),
+                                span,
+                            });
+                        }
+                    }
+                };
+
+                // `()` is unit, and `(T)` is just `T`
+                let ty = match tys.len() {
+                    0 => Ty::Unit,
+                    1 if !is_tuple => tys.pop().unwrap(),
+                    _ => Ty::Tuple(tys),
+                };
+
+                Ok(TySpanned {
+                    ty,
+                    span: start_span.start..end_span.end,
+                })
+            }
+        ));
+
+        // Arrays are written with their element type and length, such as `[int; 4]`
+        assert!(parser.register_prefix::<TySpanned>(
+            Token::LeftSquare,
+            |parser, compiler, lexer| {
+                let (_, start_span) = lexer.next_spanned().unwrap();
+
+                let TySpanned { ty: inner, .. } =
+                    parser.parse(compiler, lexer, Precedence::Lowest)?;
+
+                match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::SemiColon, _) => (),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::SemiColon),
+                            found: Box::new(token),
+                            reason: "array type requires a length".to_string(),
+                            span,
+                        });
+                    }
+                }
+
+                let size = parse_array_length(lexer)?;
+
+                let end_span = match lexer.next_spanned().ok_or(ParseError::UnexpectedEOF {
+                    span: lexer.eof_span(),
+                })? {
+                    (Token::RightSquare, span) => span,
+                    (token, span) => {
+                        return Err(ParseError::ExpectedToken {
+                            expected: Box::new(Token::RightSquare),
+                            found: Box::new(token),
+                            reason: "expected closing bracket for array type".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                Ok(TySpanned {
+                    ty: Ty::Array {
+                        inner: Box::new(inner),
+                        size,
+                    },
+                    span: start_span.start..end_span.end,
+                })
+            }
+        ));
+
+        // User-defined types are referred to by name
+        parser.register_prefix_test::<TySpanned>(
+            |token| matches!(token, Token::Ident(_)),
+            |_, compiler, lexer| {
+                let (name, span) = match lexer.next_spanned().unwrap() {
+                    (Token::Ident(name), span) => (name, span),
+                    (token, span) => {
+                        return Err(ParseError::ExpectedIdentifier {
+                            found: Box::new(token),
+                            reason: "expected type name".to_string(),
+                            span,
+                        });
+                    }
+                };
+
+                let symbol = compiler.symbols.get_or_intern(&name);
+                let ty = if let Some(idx) = compiler.types.get_idx(symbol) {
+                    Ty::Struct(idx)
+                } else if let Some(idx) = compiler.types.get_enum_idx(symbol) {
+                    Ty::Enum(idx)
+                } else {
+                    return Err(ParseError::UnknownType { name, span });
+                };
+
+                Ok(TySpanned { ty, span })
+            },
+        );
     }
 }
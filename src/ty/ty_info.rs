@@ -9,13 +9,13 @@ pub struct TyInfo {
 }
 
 impl TyInfo {
-    fn collapse(iter: impl Iterator<Item = Ty>) -> Result<Option<Ty>, TyError> {
+    fn collapse(iter: impl Iterator<Item = Ty>) -> Result<Option<Ty>, TyMismatch> {
         // Filter out all instances of `never` type, as it could be any type
         iter.filter(|ty| !matches!(ty, Ty::Never))
             .all_equal_value()
             .map(Some)
             .or_else(|e| match e {
-                Some((ty1, ty2)) => Err(TyError::Mismatch(ty1, ty2)),
+                Some((expected, found)) => Err(TyMismatch { expected, found }),
                 None => Ok(None),
             })
     }
@@ -26,7 +26,7 @@ where
     TyIter: IntoIterator<Item = Ty>,
     RetTyIter: IntoIterator<Item = Option<Ty>>,
 {
-    type Error = TyError;
+    type Error = TyMismatch;
 
     fn try_from((ty_iter, return_ty_iter): (TyIter, RetTyIter)) -> Result<Self, Self::Error> {
         Ok(Self {
@@ -41,7 +41,7 @@ impl<RetTyIter> TryFrom<(Ty, RetTyIter)> for TyInfo
 where
     RetTyIter: IntoIterator<Item = Option<Ty>>,
 {
-    type Error = TyError;
+    type Error = TyMismatch;
 
     fn try_from((ty, return_ty_iter): (Ty, RetTyIter)) -> Result<Self, Self::Error> {
         Ok(Self {
@@ -51,7 +51,7 @@ where
     }
 }
 
-impl FromIterator<TyInfo> for Result<TyInfo, TyError> {
+impl FromIterator<TyInfo> for Result<TyInfo, TyMismatch> {
     fn from_iter<T: IntoIterator<Item = TyInfo>>(iter: T) -> Self {
         let (ty_iter, return_ty_iter): (Vec<_>, Vec<_>) = iter
             .into_iter()
@@ -0,0 +1,208 @@
+use std::fmt::{self, Display, Write};
+
+use super::span::Span;
+
+/// A message attached to some location in the source, which can be rendered for the user.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    /// How severe the problem is.
+    pub level: Level,
+
+    /// Primary message describing the problem.
+    pub message: String,
+
+    /// Location of the problem.
+    pub span: Span,
+
+    /// Additional information relating to other locations in the source.
+    pub notes: Vec<Note>,
+}
+
+/// Severity of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// A problem which prevents compilation from continuing.
+    Error,
+    /// A potential problem, which does not prevent compilation.
+    Warning,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Additional information attached to a [`Diagnostic`], pointing to a related location.
+#[derive(Clone, Debug)]
+pub struct Note {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Diagnostic {
+    /// Create a new error diagnostic without any notes.
+    pub fn new(message: impl ToString, span: Span) -> Self {
+        Self {
+            level: Level::Error,
+            message: message.to_string(),
+            span,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Create a new warning diagnostic without any notes.
+    pub fn warning(message: impl ToString, span: Span) -> Self {
+        Self {
+            level: Level::Warning,
+            ..Self::new(message, span)
+        }
+    }
+
+    /// Attach a note pointing to some related location.
+    pub fn with_note(mut self, message: impl ToString, span: Span) -> Self {
+        self.notes.push(Note {
+            message: message.to_string(),
+            span,
+        });
+
+        self
+    }
+
+    /// Render the diagnostic against the source it originated from, including the location and a
+    /// snippet of each of the referenced lines.
+    pub fn render(&self, file: &str, source: &str) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "{}: {}", self.level, self.message).unwrap();
+        render_snippet(&mut output, file, source, &self.span);
+
+        for note in &self.notes {
+            writeln!(output, "note: {}", note.message).unwrap();
+            render_snippet(&mut output, file, source, &note.span);
+        }
+
+        output
+    }
+}
+
+/// Write the location of the span, followed by the source line it begins on with the span
+/// underlined.
+fn render_snippet(output: &mut String, file: &str, source: &str, span: &Span) {
+    let (line, column) = location(source, span.start);
+
+    // Pull out the line that the span starts on
+    let line_start = span.start.min(source.len()) - (column - 1);
+    let line_source = source[line_start..]
+        .split('\n')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('\r');
+
+    // Only underline the portion of the span that is present on this line
+    let underline_length = source
+        .get(span.start..span.end.min(line_start + line_source.len()))
+        .map(|underlined| underlined.chars().count())
+        .unwrap_or_default()
+        .max(1);
+
+    let gutter = " ".repeat(line.to_string().len());
+
+    writeln!(output, "{gutter}--> {file}:{line}:{column}").unwrap();
+    writeln!(output, "{gutter} |").unwrap();
+    writeln!(output, "{line} | {line_source}").unwrap();
+    writeln!(
+        output,
+        "{gutter} | {}{}",
+        " ".repeat(line_source[..column - 1].chars().count()),
+        "^".repeat(underline_length)
+    )
+    .unwrap();
+}
+
+/// Determine the line and column (both starting from 1) of a byte offset within the source. The
+/// column is measured in bytes from the start of the line.
+pub fn location(source: &str, offset: usize) -> (usize, usize) {
+    let preceding = &source[..offset.min(source.len())];
+
+    let line = preceding.matches('\n').count() + 1;
+    let column = preceding
+        .rfind('\n')
+        .map(|newline| preceding.len() - newline)
+        .unwrap_or(preceding.len() + 1);
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::start("fn main", 0, (1, 1))]
+    #[case::same_line("fn main", 3, (1, 4))]
+    #[case::second_line("fn\nmain", 3, (2, 1))]
+    #[case::second_line_offset("fn\nmain", 5, (2, 3))]
+    #[case::end_of_source("fn\n", 3, (2, 1))]
+    fn location_of_offset(
+        #[case] source: &str,
+        #[case] offset: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        assert_eq!(location(source, offset), expected);
+    }
+
+    #[test]
+    fn render_error() {
+        let source = "fn main() -> int {\n    return true;\n}";
+
+        let rendered = Diagnostic::new("mismatched types", 30..34).render("main.lum", source);
+
+        assert_eq!(
+            rendered,
+            [
+                "error: mismatched types",
+                " --> main.lum:2:12",
+                "  |",
+                "2 |     return true;",
+                "  |            ^^^^",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn render_warning() {
+        let source = "fn main() -> int {\n    let a = 1;\n    return 1;\n}";
+
+        let rendered = Diagnostic::warning("unused binding: a", 23..33).render("main.lum", source);
+
+        assert!(rendered.starts_with("warning: unused binding: a\n --> main.lum:2:5\n"));
+    }
+
+    #[test]
+    fn render_note() {
+        let source = "fn main() -> int {\n    return true;\n}";
+
+        let rendered = Diagnostic::new("mismatched types", 30..34)
+            .with_note("expected type declared here", 13..16)
+            .render("main.lum", source);
+
+        assert!(rendered.ends_with(
+            &[
+                "note: expected type declared here",
+                " --> main.lum:1:14",
+                "  |",
+                "1 | fn main() -> int {",
+                "  |              ^^^",
+                "",
+            ]
+            .join("\n")
+        ));
+    }
+}
@@ -2,14 +2,29 @@ use std::vec;
 
 use index_vec::IndexVec;
 
-use crate::{compiler::Symbol, repr::identifier::*, ty::Ty};
+use crate::{compiler::Symbol, repr::identifier::*, ty::Ty, util::span::Span};
+
+/// A name which has been declared within a scope.
+struct Binding {
+    symbol: Symbol,
+    ty: Ty,
+
+    /// Whether the binding may be assigned to after it has been declared.
+    mutable: bool,
+
+    /// Location that the binding was declared.
+    span: Span,
+
+    /// Whether the value of the binding has been read.
+    used: bool,
+}
 
 pub struct ScopePart {
     /// Indicates that this scope (and potentially a descendant) is active.
     active: bool,
 
     /// All of the bindings present within this scope.
-    bindings: IndexVec<BindingIdx, (Symbol, Ty)>,
+    bindings: IndexVec<BindingIdx, Binding>,
 }
 
 impl ScopePart {
@@ -25,8 +40,14 @@ impl ScopePart {
         self.active = false;
     }
 
-    pub fn add(&mut self, symbol: Symbol, ty: Ty) -> BindingIdx {
-        self.bindings.push((symbol, ty))
+    pub fn add(&mut self, symbol: Symbol, ty: Ty, mutable: bool, span: Span) -> BindingIdx {
+        self.bindings.push(Binding {
+            symbol,
+            ty,
+            mutable,
+            span,
+            used: false,
+        })
     }
 }
 
@@ -36,8 +57,20 @@ impl Default for ScopePart {
     }
 }
 
+/// Type and location of every `break` that targets a loop.
+pub type LoopBreaks = Vec<(Ty, Span)>;
+
 pub struct Scope {
     scopes: IndexVec<ScopeIdx, ScopePart>,
+
+    /// Label of each of the enclosing loops (innermost last), along with the breaks targeting it.
+    loops: Vec<(Option<Symbol>, LoopBreaks)>,
+
+    /// Function that the scope belongs to, if any.
+    function: Option<FunctionIdx>,
+
+    /// Return type of the function that the scope belongs to, and where it was declared.
+    return_ty: Option<(Ty, Span)>,
 }
 
 impl Scope {
@@ -45,6 +78,9 @@ impl Scope {
     pub fn new() -> Self {
         let mut scope = Self {
             scopes: IndexVec::new(),
+            loops: Vec::new(),
+            function: None,
+            return_ty: None,
         };
 
         // Automatically enter the first scope
@@ -53,6 +89,27 @@ impl Scope {
         scope
     }
 
+    /// Create a new instance for the body of the provided function, which returns the provided
+    /// type.
+    pub fn for_function(function: FunctionIdx, return_ty: Ty, return_ty_span: Span) -> Self {
+        Self {
+            function: Some(function),
+            return_ty: Some((return_ty, return_ty_span)),
+            ..Self::new()
+        }
+    }
+
+    /// The function that this scope belongs to, if any.
+    pub fn function(&self) -> Option<FunctionIdx> {
+        self.function
+    }
+
+    /// The return type of the function that this scope belongs to, along with where it was
+    /// declared.
+    pub fn return_ty(&self) -> Option<&(Ty, Span)> {
+        self.return_ty.as_ref()
+    }
+
     /// Enter a new scope.
     pub fn enter(&mut self) -> ScopeIdx {
         self.scopes.push(ScopePart::new())
@@ -70,14 +127,57 @@ impl Scope {
         active
     }
 
-    /// Register a symbol and associated type, and produce a unique binding for it.
-    pub fn register(&mut self, symbol: Symbol, ty: Ty) -> ScopedBinding {
+    /// Enter the body of a loop, which `break` and `continue` can target.
+    pub fn enter_loop(&mut self, label: Option<Symbol>) {
+        self.loops.push((label, Vec::new()));
+    }
+
+    /// Exit the innermost loop, producing the type and location of every `break` that targeted it.
+    pub fn leave_loop(&mut self) -> LoopBreaks {
+        self.loops
+            .pop()
+            .map(|(_, breaks)| breaks)
+            .expect("can only leave a loop once entered")
+    }
+
+    /// Find the breaks of the loop with the provided label, or the innermost loop if there is no
+    /// label. Will only search loops which enclose the current location.
+    pub fn resolve_loop(&mut self, label: Option<Symbol>) -> Option<&mut LoopBreaks> {
+        match label {
+            Some(label) => self
+                .loops
+                .iter_mut()
+                .rev()
+                .find(|(test_label, _)| *test_label == Some(label)),
+            None => self.loops.last_mut(),
+        }
+        .map(|(_, breaks)| breaks)
+    }
+
+    /// Register an immutable symbol and associated type, and produce a unique binding for it.
+    pub fn register(&mut self, symbol: Symbol, ty: Ty, span: Span) -> ScopedBinding {
+        self.register_binding(symbol, ty, false, span)
+    }
+
+    /// Register a symbol and associated type which may be assigned to, and produce a unique
+    /// binding for it.
+    pub fn register_mut(&mut self, symbol: Symbol, ty: Ty, span: Span) -> ScopedBinding {
+        self.register_binding(symbol, ty, true, span)
+    }
+
+    fn register_binding(
+        &mut self,
+        symbol: Symbol,
+        ty: Ty,
+        mutable: bool,
+        span: Span,
+    ) -> ScopedBinding {
         // Fetch the currently active scope
         let active_scope_idx = self.active_scope();
         let active_scope = &mut self.scopes[active_scope_idx];
 
         // Register the binding type and symbol
-        let binding_idx = active_scope.add(symbol, ty);
+        let binding_idx = active_scope.add(symbol, ty, mutable, span);
 
         ScopedBinding(active_scope_idx, binding_idx)
     }
@@ -95,10 +195,10 @@ impl Scope {
                     .bindings
                     .iter_enumerated()
                     // Only consider bindings that match the symbol
-                    .filter(|(_, (test_symbol, _))| *test_symbol == symbol)
+                    .filter(|(_, binding)| binding.symbol == symbol)
                     // Generate the scoped binding representation to track which scope the binding originated from
-                    .map(move |(binding_idx, (_, ty))| {
-                        (ScopedBinding(scope_idx, binding_idx), ty.clone())
+                    .map(move |(binding_idx, binding)| {
+                        (ScopedBinding(scope_idx, binding_idx), binding.ty.clone())
                     })
             })
             .next_back()
@@ -106,7 +206,42 @@ impl Scope {
 
     /// Get the information for a binding.
     pub fn get_binding(&self, binding: &ScopedBinding) -> (Symbol, Ty) {
-        self.scopes[binding.0].bindings[binding.1].clone()
+        let binding = &self.scopes[binding.0].bindings[binding.1];
+
+        (binding.symbol, binding.ty.clone())
+    }
+
+    /// Determine whether a binding may be assigned to, along with the location it was declared.
+    pub fn get_mutability(&self, binding: &ScopedBinding) -> (bool, &Span) {
+        let binding = &self.scopes[binding.0].bindings[binding.1];
+
+        (binding.mutable, &binding.span)
+    }
+
+    /// Record that the value of a binding has been read.
+    pub fn mark_used(&mut self, binding: &ScopedBinding) {
+        self.scopes[binding.0].bindings[binding.1].used = true;
+    }
+
+    /// Produce every binding that has been declared but never read, along with where it was
+    /// declared. Shadowed bindings are tracked individually, so will be included if only the
+    /// binding shadowing them was read.
+    pub fn unused(&self) -> impl Iterator<Item = (ScopedBinding, Symbol, &Span)> {
+        self.scopes
+            .iter_enumerated()
+            .flat_map(|(scope_idx, scope)| {
+                scope
+                    .bindings
+                    .iter_enumerated()
+                    .filter(|(_, binding)| !binding.used)
+                    .map(move |(binding_idx, binding)| {
+                        (
+                            ScopedBinding(scope_idx, binding_idx),
+                            binding.symbol,
+                            &binding.span,
+                        )
+                    })
+            })
     }
 
     /// Find the currently activated scope identifier.
@@ -132,8 +267,12 @@ impl IntoIterator for Scope {
                 scope
                     .bindings
                     .into_iter_enumerated()
-                    .map(move |(binding_idx, (symbol, ty))| {
-                        (ScopedBinding(scope_idx, binding_idx), symbol, ty)
+                    .map(move |(binding_idx, binding)| {
+                        (
+                            ScopedBinding(scope_idx, binding_idx),
+                            binding.symbol,
+                            binding.ty,
+                        )
                     })
             })
             .collect::<Vec<_>>()
@@ -169,7 +308,7 @@ mod test {
         // Register all of the variables and capture the bindings
         let bindings = variables
             .iter()
-            .map(|(symbol, ty)| scope.register(*symbol, ty.clone()))
+            .map(|(symbol, ty)| scope.register(*symbol, ty.clone(), Span::default()))
             .collect::<Vec<_>>();
 
         // Resolve each of the bindings and verify they match
@@ -178,6 +317,40 @@ mod test {
         }
     }
 
+    #[test]
+    fn mutability() {
+        let symbol_a = Symbol::try_from_usize(0).unwrap();
+        let symbol_b = Symbol::try_from_usize(1).unwrap();
+
+        let mut scope = Scope::new();
+
+        let immutable = scope.register(symbol_a, Ty::Int, 0..1);
+        let mutable = scope.register_mut(symbol_b, Ty::Int, 2..3);
+
+        assert_eq!(scope.get_mutability(&immutable), (false, &(0..1)));
+        assert_eq!(scope.get_mutability(&mutable), (true, &(2..3)));
+    }
+
+    #[test]
+    fn unused_shadowed() {
+        let symbol = Symbol::try_from_usize(0).unwrap();
+
+        let mut scope = Scope::new();
+
+        let shadowed = scope.register(symbol, Ty::Int, 0..1);
+        let binding = scope.register(symbol, Ty::Int, 2..3);
+
+        // Reading the symbol will only use the most recent binding
+        let (resolved, _) = scope.resolve(symbol).unwrap();
+        scope.mark_used(&resolved);
+
+        assert_eq!(resolved, binding);
+        assert_eq!(
+            scope.unused().collect::<Vec<_>>(),
+            [(shadowed, symbol, &(0..1))]
+        );
+    }
+
     #[test]
     fn shadowing() {
         let symbol = Symbol::try_from_usize(0).unwrap();
@@ -185,10 +358,10 @@ mod test {
         let mut scope = Scope::new();
 
         // Register the underlying variable
-        let int_binding = scope.register(symbol, Ty::Int);
+        let int_binding = scope.register(symbol, Ty::Int, Span::default());
 
         // Register the shadowed variable
-        let boolean_binding = scope.register(symbol, Ty::Boolean);
+        let boolean_binding = scope.register(symbol, Ty::Boolean, Span::default());
 
         // Make sure that the bindings are unique
         assert_ne!(int_binding, boolean_binding);
@@ -208,13 +381,13 @@ mod test {
         let mut scope = Scope::new();
 
         // Register the first variable in the base scope
-        let binding_a = scope.register(symbol_a, Ty::Int);
+        let binding_a = scope.register(symbol_a, Ty::Int, Span::default());
 
         // Enter a new scope
         let new_scope = scope.enter();
 
         // Register the second variable in the nested scope
-        let binding_b = scope.register(symbol_b, Ty::Boolean);
+        let binding_b = scope.register(symbol_b, Ty::Boolean, Span::default());
 
         // Ensure the variables are placed in the correct locations
         assert_ne!(binding_a.0, new_scope, "first variable not in nested scope");
@@ -246,13 +419,13 @@ mod test {
         let mut scope = Scope::new();
 
         // Register the first variable in the base scope
-        let binding_int = scope.register(symbol, Ty::Int);
+        let binding_int = scope.register(symbol, Ty::Int, Span::default());
 
         // Enter a new scope
         let new_scope = scope.enter();
 
         // Shadow the variable
-        let binding_boolean = scope.register(symbol, Ty::Boolean);
+        let binding_boolean = scope.register(symbol, Ty::Boolean, Span::default());
 
         // Ensure the variables are placed in the correct locations
         assert_ne!(
@@ -42,6 +42,15 @@ fn main() -> int {
     };
 }"#
 )]
+#[case::empty_block(
+    1,
+    r#"
+fn main() -> int {
+    {};
+    if true { };
+    1
+}"#
+)]
 #[case::block_with_statements(
     82,
     r#"
@@ -104,8 +113,544 @@ fn main() -> int {
         return fib(19);
     }"#
 )]
+#[case::prefix_operators(
+    -3,
+    r#"
+fn main() -> int {
+    let a = -5;
+    let positive = a > 0;
+
+    if !positive {
+        return a - -2;
+    }
+
+    return 0;
+}"#
+)]
+#[case::bitwise_operators(
+    10,
+    r#"
+fn main() -> int {
+    let mut a = 7 % 4;
+    a <<= 2;
+    a |= 1;
+    a ^= 1;
+
+    let b = -8 >> 2;
+
+    return a + b & 15;
+}"#
+)]
+#[case::oversized_shifts(
+    62,
+    r#"
+fn main() -> int {
+    let amount = 70;
+    let wide = 1 << amount;
+    let small = 1u8 << 9u8;
+    let negative = -16 >> 66;
+
+    return wide + small as int + negative;
+}"#
+)]
+#[case::unsigned_arithmetic(
+    13,
+    r#"
+fn main() -> int {
+    let max = -1 as uint;
+    let two = 2 as uint;
+
+    if max < two {
+        return 0;
+    }
+
+    let half = max / two >> 60 as uint;
+    let top = max >> 63 as uint;
+    let remainder = max % 10 as uint;
+
+    return half as int + top as int + remainder as int;
+}"#
+)]
+#[case::sized_and_float_types(
+    52,
+    r#"
+fn main() -> int {
+    let small = 200u8;
+    let wrapped = small + 100u8;
+
+    let product = 1.5f32 * 4.0f32;
+    let half = 7 as f64 / 2.0;
+    let negative = -1i8 as int;
+
+    return wrapped as int + product as int + half as int + negative;
+}"#
+)]
+#[case::while_loop(
+    55,
+    r#"
+fn main() -> int {
+    let mut i = 0;
+    let mut total = 0;
+
+    while i < 10 {
+        i += 1;
+        total += i;
+    }
+
+    return total;
+}"#
+)]
+#[case::for_loops(
+    22,
+    r#"
+fn main() -> int {
+    let mut total = 0;
+
+    for i in 0..5 {
+        if i == 1 {
+            continue;
+        }
+
+        total += i;
+    }
+
+    for i in 0..=255u8 {
+        if i == 10u8 {
+            break;
+        }
+
+        total += 1;
+    }
+
+    for i in 5..5 {
+        total += 100;
+    }
+
+    for i in 3..=3 {
+        total += i;
+    }
+
+    return total;
+}"#
+)]
+#[case::loop_break_value(
+    64,
+    r#"
+fn main() -> int {
+    let mut value = 1;
+
+    let result = loop {
+        if value > 50 {
+            break value;
+        }
+
+        value *= 2;
+    };
+
+    return result;
+}"#
+)]
+#[case::labelled_loops(
+    42,
+    r#"
+fn main() -> int {
+    let mut first = 0;
+    'search: for i in 1..10 {
+        for j in 1..10 {
+            if i * j == 12 {
+                first = i;
+                break 'search;
+            }
+        }
+    }
+
+    let mut count = first - 2;
+    'outer: while count < 100 {
+        let mut j = 0;
+
+        loop {
+            j += 1;
+            count += 1;
+
+            if count >= 42 {
+                break 'outer;
+            }
+
+            if j == 7 {
+                continue 'outer;
+            }
+        }
+    }
+
+    return count;
+}"#
+)]
+#[case::structs(
+    34,
+    r#"
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+struct Point {
+    x: int,
+    y: int,
+}
+
+fn length_squared(line: Line) -> int {
+    let dx = line.end.x - line.start.x;
+    let dy = line.end.y - line.start.y;
+    return dx * dx + dy * dy;
+}
+
+fn translate(mut point: Point, by: int) -> Point {
+    point.x += by;
+    point.y = point.y + by;
+    return point;
+}
+
+fn main() -> int {
+    let mut line = Line { start: Point { x: 1, y: 2 }, end: Point { y: 6, x: 4 } };
+    line.end = translate(line.end, 1);
+    line.start.x = 2;
+    return length_squared(line);
+}"#
+)]
+#[case::enums_and_match(
+    47,
+    r#"
+enum Shape {
+    Circle(int),
+    Rect(int, int),
+    Empty,
+}
+
+fn area(shape: Shape) -> int {
+    match shape {
+        Shape::Circle(r) => 3 * r * r,
+        Shape::Rect(w, h) => w * h,
+        Shape::Empty => 0,
+    }
+}
+
+fn is_round(shape: Shape) -> bool {
+    match shape {
+        Shape::Circle(_) => true,
+        _ => false,
+    }
+}
+
+fn main() -> int {
+    let mut total = area(Shape::Circle(2)) + area(Shape::Rect(5, 7)) + area(Shape::Empty);
+
+    if is_round(Shape::Rect(1, 1)) {
+        return 0;
+    }
+
+    match Shape::Circle(0) {
+        Shape::Circle(_) => {
+            total = total;
+        }
+        _ => {
+            return 1;
+        }
+    }
+
+    return total;
+}"#
+)]
+#[case::tuples(
+    21,
+    r#"
+fn div_mod(a: int, b: int) -> (int, int) {
+    (a / b, a % b)
+}
+
+fn sum((a, b): (int, int), c: int) -> int {
+    a + b + c
+}
+
+fn main() -> int {
+    let (q, r) = div_mod(17, 5);
+    let pair = ((q, true), r);
+    let (_, flag) = pair.0;
+
+    if flag {
+        return sum(div_mod(20, 3), pair.0.0) + (q + r) * 2;
+    }
+
+    return 0;
+}"#
+)]
+#[case::indexing(
+    29,
+    r#"
+fn main() -> int {
+    let grid = [[1, 2, 3], [4, 5, 6]];
+    let flags = [true, false, true];
+    let pair = ([1, 2], true);
+    let mut total = 0;
+
+    for i in 0..2 {
+        for j in 0..3 {
+            if flags[j] {
+                total += grid[i][j];
+            }
+        }
+    }
+
+    return total + pair.0[1] * 3 + [[7, 8], [9, 10]][1][0];
+}"#
+)]
+#[case::index_in_branch(
+    17,
+    r#"
+enum Pick {
+    First,
+    Offset(int),
+}
+
+fn choose(a: [int; 3], c: bool) -> int {
+    if c { a[1] } else { 1 }
+}
+
+fn pick(a: [int; 3], p: Pick) -> int {
+    match p {
+        Pick::First => a[0],
+        Pick::Offset(i) => a[i] + 10,
+    }
+}
+
+fn main() -> int {
+    let a = [3, 5, 7];
+    let x = if true { a[2] } else { 0 };
+
+    return choose(a, true) + choose(a, false) + pick(a, Pick::First) + pick(a, Pick::Offset(1)) - x;
+}"#
+)]
+#[case::place_assignment(
+    26,
+    r#"
+struct Counter {
+    counts: (int, int),
+}
+
+fn main() -> int {
+    let mut grid = [[0, 0, 0], [0, 0, 0]];
+    let mut c = Counter { counts: (0, 0) };
+    let mut next = 0;
+
+    for i in 0..2 {
+        for j in 0..3 {
+            next += 1;
+            grid[i][j] = next;
+        }
+    }
+
+    grid[1][next - 5] *= 2;
+    c.counts.1 = grid[1][1];
+    c.counts.0 += grid[0][2];
+
+    return c.counts.0 + c.counts.1 + grid[1][2] * 2 + grid[0][0];
+}"#
+)]
+#[case::array_values(
+    40,
+    r#"
+fn sum(values: [int; 4]) -> int {
+    let mut total = 0;
+    for i in 0..4 {
+        total += values[i];
+    }
+
+    return total;
+}
+
+fn squares() -> [int; 4] {
+    let mut values = [0; 4];
+    for i in 0..4 {
+        values[i] = i * i;
+    }
+
+    return values;
+}
+
+fn main() -> int {
+    let mut grid = [[1; 3]; 2];
+    grid[1][2] = 5;
+
+    return sum(squares()) + sum([1, 2, 3, 4]) + grid[0][0] + grid[1][2] * 3 + sum([0; 4]);
+}"#
+)]
+#[case::annotations(
+    13,
+    r#"
+fn main() -> int {
+    let small: u8 = 200;
+    let wide: uint = 3;
+    let offsets: [i16; 3] = [1, -2, 3];
+    let _empty: [int; 0] = [];
+    let (a, b): (i32, bool) = (7, true);
+
+    let total: int = (small / 40u8) as int + wide as int + offsets[2] as int + a as int;
+    if b {
+        return total - 5;
+    }
+
+    return 0;
+}"#
+)]
+#[case::diverging_values(
+    17,
+    r#"
+fn in_let() -> int {
+    let _x = loop {
+        return 1;
+    };
+}
+
+fn in_tuple() -> int {
+    let _pair = (1, loop {
+        return 2;
+    });
+
+    return 0;
+}
+
+fn in_else(c: bool) -> int {
+    let x = if c {
+        11
+    } else {
+        return 3;
+    };
+
+    return x;
+}
+
+fn main() -> int {
+    return in_let() + in_tuple() + in_else(true) + in_else(false);
+}"#
+)]
+#[case::unit_bindings(
+    4,
+    r#"
+fn main() -> int {
+    let mut count = 0;
+    let x = loop {
+        count += 1;
+        if count == 3 {
+            break;
+        }
+    };
+    let mut u = {};
+    u = x;
+    let _v = u;
+
+    return count + 1;
+}"#
+)]
+#[case::literal_limits(
+    3,
+    r#"
+fn main() -> int {
+    let mut count = 0;
+
+    let min = -9223372036854775808;
+    if min < 0 {
+        count += 1;
+    }
+
+    let max = 18446744073709551615uint;
+    if max > 9223372036854775807uint {
+        count += 1;
+    }
+
+    if max + 1uint == 0uint {
+        count += 1;
+    }
+
+    return count;
+}"#
+)]
 fn programs(#[case] expected: i64, #[case] source: &'static str) {
     let result = compile_and_run(source, false);
 
     assert_eq!(result, expected);
 }
+
+/// Run a command of the command line interface against a program, capturing its output.
+fn cli(command: &[&str], name: &str, source: &str, args: &[&str]) -> std::process::Output {
+    let path = std::env::temp_dir().join(format!("lumina_{name}_{}.lum", std::process::id()));
+    std::fs::write(&path, source).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_lumina"))
+        .args(command)
+        .arg(&path)
+        .args(args)
+        .output()
+        .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    output
+}
+
+/// Run a program through the command line interface, capturing the exit code and standard error.
+fn run_cli(name: &str, source: &str, args: &[&str]) -> (Option<i32>, String) {
+    let output = cli(&["run"], name, source, args);
+
+    (
+        output.status.code(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[rstest]
+#[case::read("read", "let a = [1, 2, 3]; return a[i];")]
+#[case::write("write", "let mut a = [1, 2, 3]; a[i] = 4; return 0;")]
+#[case::negative("negative", "let a = [1, 2, 3]; return a[i - 4];")]
+fn index_out_of_bounds(#[case] name: &str, #[case] body: &str) {
+    let source = format!("fn main() -> int {{ let i = 3; {body} }}");
+
+    let (code, stderr) = run_cli(name, &source, &[]);
+
+    assert_eq!(code, Some(101));
+    assert!(stderr.contains("index out of bounds: the length is 3"));
+    assert!(stderr.contains(&format!(".lum:1:{}", source.find("[i").unwrap() + 2)));
+}
+
+#[rstest]
+#[case::checked("checked", &[], true)]
+#[case::unchecked("unchecked", &["--no-bounds-checks"], false)]
+fn bounds_checks(#[case] name: &str, #[case] args: &[&str], #[case] checked: bool) {
+    let source = "fn main() -> int { let i = 2; let a = [1, 2, 3]; return a[i]; }";
+
+    let output = cli(&["emit", "ir"], name, source, args);
+    let ir = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(ir.contains("IndexOutOfBounds"), checked);
+}
+
+#[rstest]
+fn assign_to_immutable() {
+    let source = "fn main() -> int {\n    let a = 1;\n    a = 2;\n    return a;\n}";
+
+    let (code, stderr) = run_cli("assign_to_immutable", source, &[]);
+
+    assert_eq!(code, Some(1));
+    assert!(stderr.contains("cannot assign to immutable binding"));
+    assert!(stderr.contains("note: binding declared here"));
+}
+
+#[rstest]
+fn warnings() {
+    let source = "fn main() -> int {\n    let a = 1;\n    return 2;\n}";
+
+    let (code, stderr) = run_cli("warnings", source, &[]);
+
+    assert_eq!(code, Some(0));
+    assert!(stderr.contains("warning: unused binding `a`"));
+    assert!(stderr.contains("prefix it with an underscore: `_a`"));
+}